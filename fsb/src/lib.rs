@@ -10,6 +10,11 @@
 //! * `FSB-384`
 //! * `FSB-512`
 //!
+//! The matrices of all versions are derived from the same table of up to
+//! 266 KiB. Only the part used by a given version is referenced by its
+//! hasher, so, for example, a binary using only `FSB-160` contains 164 KiB
+//! of it.
+//!
 //! # Examples
 //!
 //! Output size of FSB-256 is fixed, so its functionality is usually
@@ -52,8 +57,35 @@ mod macros;
 use core::fmt;
 pub use digest::{self, Digest};
 
-// Double check this contains all values in the reference implementation
-static PI: &[u8; 272384] = include_bytes!("pi.bin");
+// The matrices of all variants are built from prefixes of different lengths
+// of the same data. It is split at these lengths, so that every hasher only
+// references the segments it needs and the unused ones can be removed by the
+// linker. The comments list the variants whose data ends with each segment.
+static PI_0: &[u8; 167936] = include_bytes!("pi/0.bin"); // FSB-160
+static PI_1: &[u8; 22528] = include_bytes!("pi/1.bin"); // FSB-384
+static PI_2: &[u8; 43008] = include_bytes!("pi/2.bin"); // FSB-224
+static PI_3: &[u8; 21504] = include_bytes!("pi/3.bin"); // FSB-512
+static PI_4: &[u8; 17408] = include_bytes!("pi/4.bin"); // FSB-256
+
+/// Fill `out` with the matrix data starting at `pos`, where the data is the
+/// concatenation of `segments`.
+fn read_pi(segments: &[&[u8]], mut pos: usize, out: &mut [u8]) {
+    let mut filled = 0;
+    for segment in segments {
+        if pos >= segment.len() {
+            pos -= segment.len();
+            continue;
+        }
+        let n = core::cmp::min(segment.len() - pos, out.len() - filled);
+        out[filled..filled + n].copy_from_slice(&segment[pos..pos + n]);
+        filled += n;
+        if filled == out.len() {
+            return;
+        }
+        pos = 0;
+    }
+    unreachable!("matrix data is too short");
+}
 
 use digest::{
     block_buffer::Eager,
@@ -77,6 +109,7 @@ fsb_impl!(
     640,
     653,
     1120,
+    [PI_0],
     "FSB-160 hasher state",
     "Core FSB-160 hasher state",
 );
@@ -93,6 +126,7 @@ fsb_impl!(
     896,
     907,
     1568,
+    [PI_0, PI_1, PI_2],
     "FSB-224 hasher state",
     "Core FSB-224 hasher state",
 );
@@ -109,6 +143,7 @@ fsb_impl!(
     1024,
     1061,
     1792,
+    [PI_0, PI_1, PI_2, PI_3, PI_4],
     "FSB-256 hasher state",
     "Core FSB-256 hasher state",
 );
@@ -125,6 +160,7 @@ fsb_impl!(
     1472,
     1483,
     2392,
+    [PI_0, PI_1],
     "FSB-384 hasher state",
     "Core FSB-384 hasher state",
 );
@@ -141,6 +177,7 @@ fsb_impl!(
     1984,
     1987,
     3224,
+    [PI_0, PI_1, PI_2, PI_3],
     "FSB-512 hasher state",
     "Core FSB-512 hasher state",
 );
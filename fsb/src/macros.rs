@@ -1,7 +1,7 @@
 macro_rules! fsb_impl {
     (
         $full_state:ident, $state:ident, $state_num:expr, $blocksize:ident, $outputsize:ident, $n:expr, $w:expr,
-        $r:expr, $p:expr, $s:expr, $full_doc:expr, $doc:expr,
+        $r:expr, $p:expr, $s:expr, [$($pi:ident),+], $full_doc:expr, $doc:expr,
     ) => {
         use digest::consts::{$blocksize, $outputsize};
 
@@ -91,8 +91,10 @@ macro_rules! fsb_impl {
 
             fn define_iv(index: usize) -> [u8; Self::SIZE_VECTORS] {
                 let mut subset_pi: [u8; Self::SIZE_VECTORS] = [0u8; Self::SIZE_VECTORS];
-                subset_pi.copy_from_slice(
-                    &PI[index * Self::SIZE_VECTORS..(index + 1) * Self::SIZE_VECTORS],
+                read_pi(
+                    &[$(&$pi[..]),+],
+                    index * Self::SIZE_VECTORS,
+                    &mut subset_pi,
                 );
 
                 // Now we change the last byte of the vector. We shift right and left, basically to
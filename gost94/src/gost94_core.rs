@@ -1,16 +1,19 @@
 #![allow(clippy::many_single_char_names)]
 use core::{convert::TryInto, fmt};
 use digest::{
-    block_buffer::Eager,
+    block_buffer::{BlockBuffer, Eager},
     core_api::{
         AlgorithmName, Block as TBlock, BlockSizeUser, Buffer, BufferKindUser, FixedOutputCore,
         OutputSizeUser, Reset, UpdateCore,
     },
+    generic_array::GenericArray,
     typenum::{Unsigned, U32},
     HashMarker, Output,
 };
 
-use crate::params::{Block, Gost94Params, SBox};
+use crate::params::{Block, CryptoProParam, Gost94Params, SBox};
+#[cfg(feature = "oid")]
+use digest::const_oid::{AssociatedOid, ObjectIdentifier};
 
 const C: Block = [
     0x00, 0xff, 0x00, 0xff, 0x00, 0xff, 0x00, 0xff, 0xff, 0x00, 0xff, 0x00, 0xff, 0x00, 0xff, 0x00,
@@ -124,16 +127,22 @@ fn adc(a: &mut u64, b: u64, carry: &mut u64) {
     *carry = (ret >> 64) as u64;
 }
 
-/// Core GOST94 algorithm generic over parameters.
 #[derive(Clone)]
-pub struct Gost94Core<P: Gost94Params> {
+struct State {
     h: Block,
     n: [u64; 4],
     sigma: [u64; 4],
-    _m: core::marker::PhantomData<P>,
 }
 
-impl<P: Gost94Params> Gost94Core<P> {
+impl State {
+    fn new(h0: Block) -> Self {
+        Self {
+            h: h0,
+            n: Default::default(),
+            sigma: Default::default(),
+        }
+    }
+
     fn shuffle(&mut self, m: &Block, s: &Block) {
         let mut res = Block::default();
         res.copy_from_slice(s);
@@ -148,27 +157,27 @@ impl<P: Gost94Params> Gost94Core<P> {
         }
     }
 
-    fn f(&mut self, m: &Block) {
+    fn f(&mut self, m: &Block, sbox: &SBox) {
         let mut s = Block::default();
         s.copy_from_slice(&self.h);
         let k = p(x(&self.h, m));
-        encrypt(&mut s[0..8], k, &P::S_BOX);
+        encrypt(&mut s[0..8], k, sbox);
 
         let u = a(self.h);
         let v = a(a(*m));
         let k = p(x(&u, &v));
-        encrypt(&mut s[8..16], k, &P::S_BOX);
+        encrypt(&mut s[8..16], k, sbox);
 
         let mut u = a(u);
         x_mut(&mut u, &C);
         let v = a(a(v));
         let k = p(x(&u, &v));
-        encrypt(&mut s[16..24], k, &P::S_BOX);
+        encrypt(&mut s[16..24], k, sbox);
 
         let u = a(u);
         let v = a(a(v));
         let k = p(x(&u, &v));
-        encrypt(&mut s[24..32], k, &P::S_BOX);
+        encrypt(&mut s[24..32], k, sbox);
 
         self.shuffle(m, &s);
     }
@@ -190,10 +199,45 @@ impl<P: Gost94Params> Gost94Core<P> {
     }
 
     #[inline(always)]
-    fn compress(&mut self, block: &[u8; 32]) {
-        self.f(block);
+    fn compress(&mut self, block: &[u8; 32], sbox: &SBox) {
+        self.f(block, sbox);
         self.update_sigma(block);
     }
+
+    #[inline(always)]
+    fn update_blocks(&mut self, blocks: &[GenericArray<u8, U32>], sbox: &SBox) {
+        let len = U32::USIZE * blocks.len();
+        self.update_n(len);
+        blocks.iter().for_each(|b| self.compress(b.as_ref(), sbox));
+    }
+
+    #[inline(always)]
+    fn finalize(&mut self, buffer: &mut BlockBuffer<U32, Eager>, out: &mut Block, sbox: &SBox) {
+        if buffer.get_pos() != 0 {
+            self.update_n(buffer.get_pos());
+            self.compress(buffer.pad_with_zeros().as_ref(), sbox);
+        }
+
+        let mut buf = Block::default();
+        for (o, v) in buf.chunks_exact_mut(8).zip(self.n.iter()) {
+            o.copy_from_slice(&v.to_le_bytes());
+        }
+        self.f(&buf, sbox);
+
+        for (o, v) in buf.chunks_exact_mut(8).zip(self.sigma.iter()) {
+            o.copy_from_slice(&v.to_le_bytes());
+        }
+        self.f(&buf, sbox);
+
+        out.copy_from_slice(&self.h);
+    }
+}
+
+/// Core GOST94 algorithm generic over parameters.
+#[derive(Clone)]
+pub struct Gost94Core<P: Gost94Params> {
+    state: State,
+    _m: core::marker::PhantomData<P>,
 }
 
 impl<P: Gost94Params> HashMarker for Gost94Core<P> {}
@@ -213,32 +257,16 @@ impl<P: Gost94Params> OutputSizeUser for Gost94Core<P> {
 impl<P: Gost94Params> UpdateCore for Gost94Core<P> {
     #[inline]
     fn update_blocks(&mut self, blocks: &[TBlock<Self>]) {
-        let len = Self::BlockSize::USIZE * blocks.len();
-        self.update_n(len);
-        blocks.iter().for_each(|b| self.compress(b.as_ref()));
+        self.state.update_blocks(blocks, &P::S_BOX);
     }
 }
 
 impl<P: Gost94Params> FixedOutputCore for Gost94Core<P> {
     #[inline]
     fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
-        if buffer.get_pos() != 0 {
-            self.update_n(buffer.get_pos());
-            self.compress(buffer.pad_with_zeros().as_ref());
-        }
-
-        let mut buf = Block::default();
-        for (o, v) in buf.chunks_exact_mut(8).zip(self.n.iter()) {
-            o.copy_from_slice(&v.to_le_bytes());
-        }
-        self.f(&buf);
-
-        for (o, v) in buf.chunks_exact_mut(8).zip(self.sigma.iter()) {
-            o.copy_from_slice(&v.to_le_bytes());
-        }
-        self.f(&buf);
-
-        out.copy_from_slice(&self.h);
+        let mut res = Block::default();
+        self.state.finalize(buffer, &mut res, &P::S_BOX);
+        out.copy_from_slice(&res);
     }
 }
 
@@ -246,9 +274,7 @@ impl<P: Gost94Params> Default for Gost94Core<P> {
     #[inline]
     fn default() -> Self {
         Self {
-            h: P::H0,
-            n: Default::default(),
-            sigma: Default::default(),
+            state: State::new(P::H0),
             _m: Default::default(),
         }
     }
@@ -273,3 +299,111 @@ impl<P: Gost94Params> fmt::Debug for Gost94Core<P> {
         f.write_str("Core { .. }")
     }
 }
+
+/// Core GOST94 algorithm with S-box and initialization vector supplied
+/// at runtime.
+///
+/// The default instance uses the CryptoPro parameters.
+#[derive(Clone)]
+pub struct Gost94DynCore {
+    state: State,
+    s_box: SBox,
+    h0: Block,
+}
+
+impl Gost94DynCore {
+    /// Create new hasher state with the given S-box and initialization vector.
+    ///
+    /// Each row of the S-box should be a permutation of the numbers `0..16`.
+    #[inline]
+    pub fn new(s_box: &SBox, h0: &[u8; 32]) -> Self {
+        Self {
+            state: State::new(*h0),
+            s_box: *s_box,
+            h0: *h0,
+        }
+    }
+
+    /// Create new hasher state with the parameters `P`.
+    #[inline]
+    pub fn from_params<P: Gost94Params>() -> Self {
+        Self::new(&P::S_BOX, &P::H0)
+    }
+
+    /// Create new hasher state with the parameter set identified by `oid`.
+    ///
+    /// Supports the CryptoPro (1.2.643.2.2.30.1) and test (1.2.643.2.2.30.0)
+    /// parameter sets defined in RFC 4357 and the UAPKI GOST 34.311-95
+    /// parameters (1.2.804.2.1.1.1.1.2.1). Returns `None` for other OIDs.
+    #[cfg(feature = "oid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+    pub fn from_oid(oid: &ObjectIdentifier) -> Option<Self> {
+        use crate::params::{CryptoProParam, GOST28147UAParam, TestParam};
+
+        if *oid == CryptoProParam::OID {
+            Some(Self::from_params::<CryptoProParam>())
+        } else if *oid == TestParam::OID {
+            Some(Self::from_params::<TestParam>())
+        } else if *oid == GOST28147UAParam::OID {
+            Some(Self::from_params::<GOST28147UAParam>())
+        } else {
+            None
+        }
+    }
+}
+
+impl HashMarker for Gost94DynCore {}
+
+impl BlockSizeUser for Gost94DynCore {
+    type BlockSize = U32;
+}
+
+impl BufferKindUser for Gost94DynCore {
+    type BufferKind = Eager;
+}
+
+impl OutputSizeUser for Gost94DynCore {
+    type OutputSize = U32;
+}
+
+impl UpdateCore for Gost94DynCore {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[TBlock<Self>]) {
+        self.state.update_blocks(blocks, &self.s_box);
+    }
+}
+
+impl FixedOutputCore for Gost94DynCore {
+    #[inline]
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let mut res = Block::default();
+        self.state.finalize(buffer, &mut res, &self.s_box);
+        out.copy_from_slice(&res);
+    }
+}
+
+impl Default for Gost94DynCore {
+    #[inline]
+    fn default() -> Self {
+        Self::from_params::<CryptoProParam>()
+    }
+}
+
+impl Reset for Gost94DynCore {
+    #[inline]
+    fn reset(&mut self) {
+        self.state = State::new(self.h0);
+    }
+}
+
+impl AlgorithmName for Gost94DynCore {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Gost94Dyn")
+    }
+}
+
+impl fmt::Debug for Gost94DynCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Gost94DynCore { .. }")
+    }
+}
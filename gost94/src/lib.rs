@@ -54,7 +54,7 @@ pub mod params;
 
 pub use digest::{self, Digest};
 
-pub use gost94_core::Gost94Core;
+pub use gost94_core::{Gost94Core, Gost94DynCore};
 
 #[cfg(feature = "oid")]
 impl AssociatedOid for Gost94Core<params::CryptoProParam> {
@@ -76,3 +76,8 @@ pub type Gost94Test = CoreWrapper<Gost94Core<params::TestParam>>;
 /// GOST94 hash function with UAPKI GOST 34.311-95 parameters
 /// (1.2.804.2.1.1.1.1.2.1 OID).
 pub type Gost94UA = CoreWrapper<Gost94Core<params::GOST28147UAParam>>;
+/// GOST94 hash function with parameters selected at runtime.
+///
+/// Use [`CoreWrapper::from_core`] with [`Gost94DynCore`] to select
+/// the parameters. The default instance uses the CryptoPro parameters.
+pub type Gost94Dyn = CoreWrapper<Gost94DynCore>;
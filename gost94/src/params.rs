@@ -2,7 +2,8 @@
 use digest::const_oid::{AssociatedOid, ObjectIdentifier};
 
 pub(crate) type Block = [u8; 32];
-pub(crate) type SBox = [[u8; 16]; 8];
+/// GOST 28147-89 S-box.
+pub type SBox = [[u8; 16]; 8];
 
 /// Trait for storing parameter constants.
 // TODO: replace with const generics
@@ -102,3 +103,8 @@ impl Gost94Params for GOST28147UAParam {
     const H0: Block = [0; 32];
     const NAME: &'static str = "Gost28147UA";
 }
+
+#[cfg(feature = "oid")]
+impl AssociatedOid for GOST28147UAParam {
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.804.2.1.1.1.1.2.1");
+}
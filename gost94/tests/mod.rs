@@ -2,7 +2,10 @@
 use digest::const_oid::{AssociatedOid, ObjectIdentifier};
 use digest::dev::{feed_rand_16mib, fixed_reset_test};
 use digest::new_test;
-use gost94::{Digest, Gost94CryptoPro, Gost94Test, Gost94UA};
+use gost94::{
+    params::{Gost94Params, TestParam},
+    Digest, Gost94CryptoPro, Gost94Dyn, Gost94DynCore, Gost94Test, Gost94UA,
+};
 use hex_literal::hex;
 
 new_test!(gost94_test_main, "test", Gost94Test, fixed_reset_test);
@@ -106,3 +109,53 @@ fn gost_oid_tests() {
         ObjectIdentifier::new_unwrap("1.2.804.2.1.1.1.1.2.1")
     );
 }
+
+#[test]
+fn gost94_dyn_matches_static() {
+    fn check<D: Digest>(mut h: Gost94Dyn) {
+        let mut expected = D::new();
+        for i in 0..100u8 {
+            let data = [i; 37];
+            h.update(data);
+            expected.update(data);
+        }
+        assert_eq!(h.finalize_reset()[..], expected.finalize()[..]);
+
+        // Reset keeps the parameters
+        let mut expected = D::new();
+        h.update(b"test");
+        expected.update(b"test");
+        assert_eq!(h.finalize()[..], expected.finalize()[..]);
+    }
+
+    check::<Gost94CryptoPro>(Gost94Dyn::default());
+    check::<Gost94Test>(Gost94Dyn::from_core(Gost94DynCore::new(
+        &TestParam::S_BOX,
+        &TestParam::H0,
+    )));
+    check::<Gost94UA>(Gost94Dyn::from_core(Gost94DynCore::from_params::<
+        gost94::params::GOST28147UAParam,
+    >()));
+}
+
+#[cfg(feature = "oid")]
+#[test]
+fn gost94_dyn_from_oid() {
+    let hash = |oid: &str| {
+        let oid = ObjectIdentifier::new_unwrap(oid);
+        let core = Gost94DynCore::from_oid(&oid)?;
+        let mut h = Gost94Dyn::from_core(core);
+        h.update(b"test");
+        Some(h.finalize())
+    };
+    assert_eq!(
+        hash("1.2.643.2.2.30.1"),
+        Some(Gost94CryptoPro::digest(b"test"))
+    );
+    assert_eq!(hash("1.2.643.2.2.30.0"), Some(Gost94Test::digest(b"test")));
+    assert_eq!(
+        hash("1.2.804.2.1.1.1.1.2.1"),
+        Some(Gost94UA::digest(b"test"))
+    );
+    assert_eq!(hash("1.2.643.2.2.9"), None);
+}
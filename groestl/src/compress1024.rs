@@ -66,7 +66,8 @@ fn rndp(mut x: [u64; COLS], r: u64) -> [u64; COLS] {
     ]
 }
 
-pub(crate) fn compress(h: &mut [u64; COLS], block: &[u8; 128]) {
+/// Compression function of the long variant.
+pub fn compress(h: &mut [u64; COLS], block: &[u8; 128]) {
     let mut q = [0u64; COLS];
     for (chunk, v) in block.chunks_exact(8).zip(q.iter_mut()) {
         *v = u64::from_be_bytes(chunk.try_into().unwrap());
@@ -86,7 +87,8 @@ pub(crate) fn compress(h: &mut [u64; COLS], block: &[u8; 128]) {
     }
 }
 
-pub(crate) fn p(h: &[u64; COLS]) -> [u64; COLS] {
+/// Output transformation permutation `P` of the long variant.
+pub fn p(h: &[u64; COLS]) -> [u64; COLS] {
     let mut p = *h;
     for i in 0..ROUNDS {
         p = rndp(p, i << 56);
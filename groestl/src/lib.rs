@@ -30,7 +30,7 @@
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
 )]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![warn(rust_2018_idioms)]
 
 pub use digest::{self, Digest};
@@ -51,6 +51,43 @@ mod compress1024;
 mod compress512;
 mod table;
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+mod x86;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+use x86::{compress_long, compress_short, p_long, p_short};
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+)))]
+use crate::{
+    compress1024::{compress as compress_long, p as p_long},
+    compress512::{compress as compress_short, p as p_short},
+};
+
+/// Backends compiled for the target, exposed for the equivalence tests in
+/// `tests/`. Not part of the public API.
+#[doc(hidden)]
+pub mod backends {
+    /// Portable implementation.
+    pub mod soft {
+        pub use crate::compress1024::{compress as compress1024, p as p1024};
+        pub use crate::compress512::{compress as compress512, p as p512};
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft")
+    ))]
+    pub use crate::x86::aesni;
+}
+
 /// Lowest-level core hasher state of the short Groestl variant.
 #[derive(Clone)]
 pub struct GroestlShortVarCore {
@@ -73,7 +110,7 @@ impl UpdateCore for GroestlShortVarCore {
     fn update_blocks(&mut self, blocks: &[Block<Self>]) {
         self.blocks_len += blocks.len() as u64;
         for block in blocks {
-            compress512::compress(&mut self.state, block.as_ref());
+            compress_short(&mut self.state, block.as_ref());
         }
     }
 }
@@ -104,9 +141,9 @@ impl VariableOutputCore for GroestlShortVarCore {
             self.blocks_len + 1
         };
         buffer.len64_padding_be(blocks_len, |block| {
-            compress512::compress(&mut self.state, block.as_ref())
+            compress_short(&mut self.state, block.as_ref())
         });
-        let res = compress512::p(&self.state);
+        let res = p_short(&self.state);
         let n = compress512::COLS / 2;
         for (chunk, v) in out.chunks_exact_mut(8).zip(res[n..].iter()) {
             chunk.copy_from_slice(&v.to_be_bytes());
@@ -161,7 +198,7 @@ impl UpdateCore for GroestlLongVarCore {
     fn update_blocks(&mut self, blocks: &[Block<Self>]) {
         self.blocks_len += blocks.len() as u64;
         for block in blocks {
-            compress1024::compress(&mut self.state, block.as_ref());
+            compress_long(&mut self.state, block.as_ref());
         }
     }
 }
@@ -192,9 +229,9 @@ impl VariableOutputCore for GroestlLongVarCore {
             self.blocks_len + 1
         };
         buffer.len64_padding_be(blocks_len, |block| {
-            compress1024::compress(&mut self.state, block.as_ref())
+            compress_long(&mut self.state, block.as_ref())
         });
-        let res = compress1024::p(&self.state);
+        let res = p_long(&self.state);
         let n = compress1024::COLS / 2;
         for (chunk, v) in out.chunks_exact_mut(8).zip(res[n..].iter()) {
             chunk.copy_from_slice(&v.to_be_bytes());
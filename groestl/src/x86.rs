@@ -0,0 +1,426 @@
+//! AES-NI backend for the Grøstl permutations.
+//!
+//! The state is transposed, so every register holds one or two rows of
+//! the state matrix. ShiftBytes then becomes a byte shuffle inside each
+//! register, which is combined with the inverse of the AES ShiftRows step,
+//! so a following `aesenclast` with zero round key computes SubBytes.
+//! MixBytes is computed on whole rows with doubling in GF(2^8).
+//!
+//! The short variant computes P and Q in parallel, with P rows stored in
+//! the low and Q rows in the high halves of the registers.
+#![allow(unsafe_code)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{compress1024, compress512};
+
+cpufeatures::new!(aes_cpuid, "aes", "ssse3");
+
+/// Combined ShiftBytes and inverse AES ShiftRows shuffles for the rows of
+/// P (low half) and Q (high half) of the short variant.
+const SHUFFLE512: [[u8; 16]; 8] = [
+    [0, 14, 11, 7, 4, 1, 15, 12, 9, 5, 2, 8, 13, 10, 6, 3],
+    [1, 8, 13, 0, 5, 2, 9, 14, 11, 6, 3, 10, 15, 12, 7, 4],
+    [2, 10, 15, 1, 6, 3, 11, 8, 13, 7, 4, 12, 9, 14, 0, 5],
+    [3, 12, 9, 2, 7, 4, 13, 10, 15, 0, 5, 14, 11, 8, 1, 6],
+    [4, 13, 10, 3, 0, 5, 14, 11, 8, 1, 6, 15, 12, 9, 2, 7],
+    [5, 15, 12, 4, 1, 6, 8, 13, 10, 2, 7, 9, 14, 11, 3, 0],
+    [6, 9, 14, 5, 2, 7, 10, 15, 12, 3, 0, 11, 8, 13, 4, 1],
+    [7, 11, 8, 6, 3, 0, 12, 9, 14, 4, 1, 13, 10, 15, 5, 2],
+];
+
+/// Combined ShiftBytes and inverse AES ShiftRows shuffles for the rows of
+/// P of the long variant.
+const SHUFFLE1024P: [[u8; 16]; 8] = [
+    [0, 13, 10, 7, 4, 1, 14, 11, 8, 5, 2, 15, 12, 9, 6, 3],
+    [1, 14, 11, 8, 5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4],
+    [2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4, 1, 14, 11, 8, 5],
+    [3, 0, 13, 10, 7, 4, 1, 14, 11, 8, 5, 2, 15, 12, 9, 6],
+    [4, 1, 14, 11, 8, 5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7],
+    [5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4, 1, 14, 11, 8],
+    [6, 3, 0, 13, 10, 7, 4, 1, 14, 11, 8, 5, 2, 15, 12, 9],
+    [11, 8, 5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4, 1, 14],
+];
+
+/// Combined ShiftBytes and inverse AES ShiftRows shuffles for the rows of
+/// Q of the long variant.
+const SHUFFLE1024Q: [[u8; 16]; 8] = [
+    [1, 14, 11, 8, 5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4],
+    [3, 0, 13, 10, 7, 4, 1, 14, 11, 8, 5, 2, 15, 12, 9, 6],
+    [5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4, 1, 14, 11, 8],
+    [11, 8, 5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4, 1, 14],
+    [0, 13, 10, 7, 4, 1, 14, 11, 8, 5, 2, 15, 12, 9, 6, 3],
+    [2, 15, 12, 9, 6, 3, 0, 13, 10, 7, 4, 1, 14, 11, 8, 5],
+    [4, 1, 14, 11, 8, 5, 2, 15, 12, 9, 6, 3, 0, 13, 10, 7],
+    [6, 3, 0, 13, 10, 7, 4, 1, 14, 11, 8, 5, 2, 15, 12, 9],
+];
+
+/// Column-dependent parts of the round constants, i.e. `j << 4` for
+/// the `j`-th column.
+const COL_RC: [u8; 16] = [
+    0x00, 0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80, 0x90, 0xa0, 0xb0, 0xc0, 0xd0, 0xe0, 0xf0,
+];
+
+pub(crate) fn compress_short(h: &mut [u64; compress512::COLS], block: &[u8; 64]) {
+    // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
+    // after stabilization
+    if aes_cpuid::get() {
+        unsafe { compress512_aesni(h, block) }
+    } else {
+        compress512::compress(h, block)
+    }
+}
+
+pub(crate) fn p_short(h: &[u64; compress512::COLS]) -> [u64; compress512::COLS] {
+    if aes_cpuid::get() {
+        unsafe { p512_aesni(h) }
+    } else {
+        compress512::p(h)
+    }
+}
+
+pub(crate) fn compress_long(h: &mut [u64; compress1024::COLS], block: &[u8; 128]) {
+    if aes_cpuid::get() {
+        unsafe { compress1024_aesni(h, block) }
+    } else {
+        compress1024::compress(h, block)
+    }
+}
+
+pub(crate) fn p_long(h: &[u64; compress1024::COLS]) -> [u64; compress1024::COLS] {
+    if aes_cpuid::get() {
+        unsafe { p1024_aesni(h) }
+    } else {
+        compress1024::p(h)
+    }
+}
+
+/// AES-NI backend, see [`crate::backends`]. The functions return `false` or
+/// `None` if the CPU does not support AES-NI.
+pub mod aesni {
+    use super::{aes_cpuid, compress1024_aesni, compress512_aesni, p1024_aesni, p512_aesni};
+    use crate::{compress1024, compress512};
+
+    /// Compression function of the short variant.
+    pub fn compress512(h: &mut [u64; compress512::COLS], block: &[u8; 64]) -> bool {
+        let supported = aes_cpuid::get();
+        if supported {
+            unsafe { compress512_aesni(h, block) }
+        }
+        supported
+    }
+
+    /// Output transformation permutation `P` of the short variant.
+    pub fn p512(h: &[u64; compress512::COLS]) -> Option<[u64; compress512::COLS]> {
+        if aes_cpuid::get() {
+            Some(unsafe { p512_aesni(h) })
+        } else {
+            None
+        }
+    }
+
+    /// Compression function of the long variant.
+    pub fn compress1024(h: &mut [u64; compress1024::COLS], block: &[u8; 128]) -> bool {
+        let supported = aes_cpuid::get();
+        if supported {
+            unsafe { compress1024_aesni(h, block) }
+        }
+        supported
+    }
+
+    /// Output transformation permutation `P` of the long variant.
+    pub fn p1024(h: &[u64; compress1024::COLS]) -> Option<[u64; compress1024::COLS]> {
+        if aes_cpuid::get() {
+            Some(unsafe { p1024_aesni(h) })
+        } else {
+            None
+        }
+    }
+}
+
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn load(b: &[u8; 16]) -> __m128i {
+    _mm_loadu_si128(b.as_ptr() as *const __m128i)
+}
+
+/// Loads 8 state columns, two columns per register.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn load_cols(h: &[u64]) -> [__m128i; 4] {
+    let mut res = [_mm_setzero_si128(); 4];
+    for (r, c) in res.iter_mut().zip(h.chunks_exact(2)) {
+        *r = _mm_set_epi64x(c[1].swap_bytes() as i64, c[0].swap_bytes() as i64);
+    }
+    res
+}
+
+/// Stores 8 state columns, two columns per register.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn store_cols(cols: &[__m128i; 4], h: &mut [u64]) {
+    let mut buf = [0u64; 8];
+    for (i, c) in cols.iter().enumerate() {
+        _mm_storeu_si128(buf.as_mut_ptr().add(2 * i) as *mut __m128i, *c);
+    }
+    for (o, v) in h.iter_mut().zip(buf.iter()) {
+        *o = v.swap_bytes();
+    }
+}
+
+/// Transposes 8x8 byte matrix stored as pairs of 8 byte units.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn transpose(a: &[__m128i; 4]) -> [__m128i; 4] {
+    let b0 = _mm_unpacklo_epi8(a[0], _mm_srli_si128(a[0], 8));
+    let b1 = _mm_unpacklo_epi8(a[1], _mm_srli_si128(a[1], 8));
+    let b2 = _mm_unpacklo_epi8(a[2], _mm_srli_si128(a[2], 8));
+    let b3 = _mm_unpacklo_epi8(a[3], _mm_srli_si128(a[3], 8));
+    let c0 = _mm_unpacklo_epi16(b0, b1);
+    let c1 = _mm_unpackhi_epi16(b0, b1);
+    let c2 = _mm_unpacklo_epi16(b2, b3);
+    let c3 = _mm_unpackhi_epi16(b2, b3);
+    [
+        _mm_unpacklo_epi32(c0, c2),
+        _mm_unpackhi_epi32(c0, c2),
+        _mm_unpacklo_epi32(c1, c3),
+        _mm_unpackhi_epi32(c1, c3),
+    ]
+}
+
+/// Multiplication by 2 in GF(2^8).
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn mul2(x: __m128i) -> __m128i {
+    let hi = _mm_cmpgt_epi8(_mm_setzero_si128(), x);
+    _mm_xor_si128(_mm_add_epi8(x, x), _mm_and_si128(hi, _mm_set1_epi8(0x1b)))
+}
+
+/// SubBytes and ShiftBytes of a single row.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn sub_shift(x: __m128i, shuffle: &[u8; 16]) -> __m128i {
+    _mm_aesenclast_si128(_mm_shuffle_epi8(x, load(shuffle)), _mm_setzero_si128())
+}
+
+/// MixBytes with circulant matrix `(02, 02, 03, 04, 05, 03, 05, 07)`.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn mix_bytes(a: &mut [__m128i; 8]) {
+    let mut t = [_mm_setzero_si128(); 8];
+    for i in 0..8 {
+        t[i] = _mm_xor_si128(a[i], a[(i + 1) % 8]);
+    }
+    let mut w = [_mm_setzero_si128(); 4];
+    for i in 0..4 {
+        w[i] = _mm_xor_si128(t[i], t[i + 4]);
+    }
+    let mut res = [_mm_setzero_si128(); 8];
+    for i in 0..8 {
+        let s1 = _mm_xor_si128(
+            a[(i + 2) % 8],
+            _mm_xor_si128(t[(i + 4) % 8], t[(i + 6) % 8]),
+        );
+        let s2 = _mm_xor_si128(s1, _mm_xor_si128(w[i % 4], t[(i + 5) % 8]));
+        let s4 = _mm_xor_si128(t[(i + 3) % 8], t[(i + 6) % 8]);
+        res[i] = _mm_xor_si128(s1, mul2(_mm_xor_si128(s2, mul2(s4))));
+    }
+    *a = res;
+}
+
+/// P and Q permutations of the short variant computed in parallel.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn perm512(x: &mut [__m128i; 8]) {
+    let ones = _mm_set_epi64x(-1, 0);
+    let col_rc = _mm_loadl_epi64(COL_RC.as_ptr() as *const __m128i);
+    let rc0 = _mm_or_si128(col_rc, ones);
+    let rc7 = _mm_xor_si128(_mm_slli_si128(col_rc, 8), ones);
+    for r in 0..10 {
+        let r = _mm_set1_epi8(r as i8);
+        x[0] = _mm_xor_si128(x[0], _mm_xor_si128(rc0, _mm_move_epi64(r)));
+        for xi in x[1..7].iter_mut() {
+            *xi = _mm_xor_si128(*xi, ones);
+        }
+        x[7] = _mm_xor_si128(x[7], _mm_xor_si128(rc7, _mm_slli_si128(r, 8)));
+        for (xi, s) in x.iter_mut().zip(SHUFFLE512.iter()) {
+            *xi = sub_shift(*xi, s);
+        }
+        mix_bytes(x);
+    }
+}
+
+/// Computes `h ^ P ^ Q` rows from the permutation input `x` and output `y`,
+/// and converts them back to columns.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn finish512(x: &[__m128i; 8], y: &[__m128i; 8], h: &mut [u64; compress512::COLS]) {
+    let mut rows = [_mm_setzero_si128(); 8];
+    for i in 0..8 {
+        let v = _mm_xor_si128(x[i], y[i]);
+        rows[i] = _mm_xor_si128(v, _mm_srli_si128(v, 8));
+    }
+    let mut pairs = [_mm_setzero_si128(); 4];
+    for (k, p) in pairs.iter_mut().enumerate() {
+        *p = _mm_unpacklo_epi64(rows[2 * k], rows[2 * k + 1]);
+    }
+    store_cols(&transpose(&pairs), h);
+}
+
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn compress512_aesni(h: &mut [u64; compress512::COLS], block: &[u8; 64]) {
+    let hc = load_cols(h);
+    let mut mc = [_mm_setzero_si128(); 4];
+    let mut pc = [_mm_setzero_si128(); 4];
+    for k in 0..4 {
+        mc[k] = _mm_loadu_si128(block.as_ptr().add(16 * k) as *const __m128i);
+        pc[k] = _mm_xor_si128(hc[k], mc[k]);
+    }
+    let pr = transpose(&pc);
+    let qr = transpose(&mc);
+
+    let mut x = [_mm_setzero_si128(); 8];
+    for k in 0..4 {
+        x[2 * k] = _mm_unpacklo_epi64(pr[k], qr[k]);
+        x[2 * k + 1] = _mm_unpackhi_epi64(pr[k], qr[k]);
+    }
+    let mut y = x;
+    perm512(&mut y);
+    finish512(&x, &y, h);
+}
+
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn p512_aesni(h: &[u64; compress512::COLS]) -> [u64; compress512::COLS] {
+    let hr = transpose(&load_cols(h));
+    let mut x = [_mm_setzero_si128(); 8];
+    for k in 0..4 {
+        // The high halves compute Q, which is not needed here
+        x[2 * k] = _mm_move_epi64(hr[k]);
+        x[2 * k + 1] = _mm_srli_si128(hr[k], 8);
+    }
+    let mut y = x;
+    perm512(&mut y);
+    for yi in y.iter_mut() {
+        *yi = _mm_move_epi64(*yi);
+    }
+    let mut res = [0; compress512::COLS];
+    finish512(&x, &y, &mut res);
+    res
+}
+
+/// Loads 16 state columns as 8 rows.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn cols_to_rows(lo: &[__m128i; 4], hi: &[__m128i; 4]) -> [__m128i; 8] {
+    let lo = transpose(lo);
+    let hi = transpose(hi);
+    let mut rows = [_mm_setzero_si128(); 8];
+    for k in 0..4 {
+        rows[2 * k] = _mm_unpacklo_epi64(lo[k], hi[k]);
+        rows[2 * k + 1] = _mm_unpackhi_epi64(lo[k], hi[k]);
+    }
+    rows
+}
+
+/// Stores 8 rows as 16 state columns.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn rows_to_cols(rows: &[__m128i; 8], h: &mut [u64; compress1024::COLS]) {
+    let mut lo = [_mm_setzero_si128(); 4];
+    let mut hi = [_mm_setzero_si128(); 4];
+    for k in 0..4 {
+        lo[k] = _mm_unpacklo_epi64(rows[2 * k], rows[2 * k + 1]);
+        hi[k] = _mm_unpackhi_epi64(rows[2 * k], rows[2 * k + 1]);
+    }
+    let (h_lo, h_hi) = h.split_at_mut(8);
+    store_cols(&transpose(&lo), h_lo);
+    store_cols(&transpose(&hi), h_hi);
+}
+
+/// P and Q permutations of the long variant.
+///
+/// Computing both of them in the same loop allows to interleave
+/// independent instructions.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn perm1024(p: &mut [__m128i; 8], q: &mut [__m128i; 8]) {
+    let ones = _mm_set1_epi8(-1);
+    let col_rc = load(&COL_RC);
+    let rc_q = _mm_xor_si128(col_rc, ones);
+    for r in 0..14 {
+        let r = _mm_set1_epi8(r as i8);
+        p[0] = _mm_xor_si128(p[0], _mm_xor_si128(col_rc, r));
+        for qi in q[..7].iter_mut() {
+            *qi = _mm_xor_si128(*qi, ones);
+        }
+        q[7] = _mm_xor_si128(q[7], _mm_xor_si128(rc_q, r));
+        for i in 0..8 {
+            p[i] = sub_shift(p[i], &SHUFFLE1024P[i]);
+            q[i] = sub_shift(q[i], &SHUFFLE1024Q[i]);
+        }
+        mix_bytes(p);
+        mix_bytes(q);
+    }
+}
+
+/// P permutation of the long variant.
+#[inline]
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn perm1024p(p: &mut [__m128i; 8]) {
+    let col_rc = load(&COL_RC);
+    for r in 0..14 {
+        let r = _mm_set1_epi8(r as i8);
+        p[0] = _mm_xor_si128(p[0], _mm_xor_si128(col_rc, r));
+        for (pi, s) in p.iter_mut().zip(SHUFFLE1024P.iter()) {
+            *pi = sub_shift(*pi, s);
+        }
+        mix_bytes(p);
+    }
+}
+
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn compress1024_aesni(h: &mut [u64; compress1024::COLS], block: &[u8; 128]) {
+    let mut mc = [_mm_setzero_si128(); 8];
+    for (k, m) in mc.iter_mut().enumerate() {
+        *m = _mm_loadu_si128(block.as_ptr().add(16 * k) as *const __m128i);
+    }
+    let hc = [load_cols(&h[..8]), load_cols(&h[8..])];
+    let mut pc = [[_mm_setzero_si128(); 4]; 2];
+    for g in 0..2 {
+        for k in 0..4 {
+            pc[g][k] = _mm_xor_si128(hc[g][k], mc[4 * g + k]);
+        }
+    }
+    let m_lo = [mc[0], mc[1], mc[2], mc[3]];
+    let m_hi = [mc[4], mc[5], mc[6], mc[7]];
+
+    let p_in = cols_to_rows(&pc[0], &pc[1]);
+    let q_in = cols_to_rows(&m_lo, &m_hi);
+    let mut p = p_in;
+    let mut q = q_in;
+    perm1024(&mut p, &mut q);
+
+    let mut rows = [_mm_setzero_si128(); 8];
+    for i in 0..8 {
+        let v = _mm_xor_si128(_mm_xor_si128(p_in[i], q_in[i]), _mm_xor_si128(p[i], q[i]));
+        rows[i] = v;
+    }
+    rows_to_cols(&rows, h);
+}
+
+#[target_feature(enable = "aes,ssse3")]
+unsafe fn p1024_aesni(h: &[u64; compress1024::COLS]) -> [u64; compress1024::COLS] {
+    let x = cols_to_rows(&load_cols(&h[..8]), &load_cols(&h[8..]));
+    let mut p = x;
+    perm1024p(&mut p);
+    let mut rows = [_mm_setzero_si128(); 8];
+    for i in 0..8 {
+        rows[i] = _mm_xor_si128(x[i], p[i]);
+    }
+    let mut res = [0; compress1024::COLS];
+    rows_to_cols(&rows, &mut res);
+    res
+}
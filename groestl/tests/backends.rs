@@ -0,0 +1,95 @@
+//! Equivalence tests of the AES-NI backend against the software
+//! implementation. The tests are skipped with a message if the CPU running
+//! them does not support AES-NI.
+#![cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+
+use groestl::backends::{aesni, soft};
+
+fn rand_u64(seed: &mut u64) -> u64 {
+    // xorshift64
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed
+}
+
+fn rand_bytes(seed: &mut u64, buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        *b = rand_u64(seed) as u8;
+    }
+}
+
+fn skip(name: &str) {
+    eprintln!("AES-NI {} is not supported by the CPU, skipping", name);
+}
+
+#[test]
+fn compress512_aesni_matches_soft() {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    let mut state = [0u64; 8];
+    for v in state.iter_mut() {
+        *v = rand_u64(&mut seed);
+    }
+    let mut expected = state;
+    for _ in 0..16 {
+        let mut block = [0u8; 64];
+        rand_bytes(&mut seed, &mut block);
+        soft::compress512(&mut expected, &block);
+        if !aesni::compress512(&mut state, &block) {
+            return skip("compress512");
+        }
+        assert_eq!(state, expected);
+    }
+}
+
+#[test]
+fn p512_aesni_matches_soft() {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    for _ in 0..16 {
+        let mut state = [0u64; 8];
+        for v in state.iter_mut() {
+            *v = rand_u64(&mut seed);
+        }
+        match aesni::p512(&state) {
+            Some(res) => assert_eq!(res, soft::p512(&state)),
+            None => return skip("p512"),
+        }
+    }
+}
+
+#[test]
+fn compress1024_aesni_matches_soft() {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    let mut state = [0u64; 16];
+    for v in state.iter_mut() {
+        *v = rand_u64(&mut seed);
+    }
+    let mut expected = state;
+    for _ in 0..16 {
+        let mut block = [0u8; 128];
+        rand_bytes(&mut seed, &mut block);
+        soft::compress1024(&mut expected, &block);
+        if !aesni::compress1024(&mut state, &block) {
+            return skip("compress1024");
+        }
+        assert_eq!(state, expected);
+    }
+}
+
+#[test]
+fn p1024_aesni_matches_soft() {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    for _ in 0..16 {
+        let mut state = [0u64; 16];
+        for v in state.iter_mut() {
+            *v = rand_u64(&mut seed);
+        }
+        match aesni::p1024(&state) {
+            Some(res) => assert_eq!(res, soft::p1024(&state)),
+            None => return skip("p1024"),
+        }
+    }
+}
@@ -7,6 +7,9 @@
 //! * [JH-384][Jh384]
 //! * [JH-512][Jh512]
 //!
+//! Other output sizes from 1 to 64 bytes are supported by [`JhVar`] and by
+//! [`JhCore`] wrapped into [`CtVariableCoreWrapper`].
+//!
 //! # Examples
 //!
 //! Hash functionality is usually accessed via the [`Digest`] trait:
@@ -52,7 +55,7 @@ use digest::{
     block_buffer::Eager,
     core_api::{
         AlgorithmName, Block, Buffer, BufferKindUser, CoreWrapper, CtVariableCoreWrapper,
-        TruncSide, UpdateCore, VariableOutputCore,
+        RtVariableCoreWrapper, TruncSide, UpdateCore, VariableOutputCore,
     },
     crypto_common::{BlockSizeUser, OutputSizeUser},
     generic_array::typenum::{Unsigned, U28, U32, U48, U64},
@@ -95,15 +98,16 @@ impl VariableOutputCore for JhCore {
 
     #[inline]
     fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
-        let h0 = match output_size {
-            28 => consts::JH224_H0,
-            32 => consts::JH256_H0,
-            48 => consts::JH384_H0,
-            64 => consts::JH512_H0,
+        let state = match output_size {
+            28 => Compressor::new(consts::JH224_H0),
+            32 => Compressor::new(consts::JH256_H0),
+            48 => Compressor::new(consts::JH384_H0),
+            64 => Compressor::new(consts::JH512_H0),
+            1..=64 => derive_h0(output_size),
             _ => return Err(InvalidOutputSize),
         };
         Ok(Self {
-            state: Compressor::new(h0),
+            state,
             block_len: 0,
         })
     }
@@ -125,6 +129,18 @@ impl VariableOutputCore for JhCore {
     }
 }
 
+/// Derives the initial state for the given output size in bytes.
+///
+/// The first two bytes of `H(-1)` hold the output size in bits, the rest
+/// are zero, and `H(0)` is obtained by compressing a zero block into it.
+fn derive_h0(output_size: usize) -> Compressor {
+    let mut h = [0u8; 128];
+    h[..2].copy_from_slice(&(8 * output_size as u16).to_be_bytes());
+    let mut state = Compressor::new(h);
+    state.update(&Default::default());
+    state
+}
+
 impl AlgorithmName for JhCore {
     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Jh")
@@ -137,6 +153,8 @@ impl fmt::Debug for JhCore {
     }
 }
 
+/// JH hasher state which allows to choose output size at runtime.
+pub type JhVar = RtVariableCoreWrapper<JhCore>;
 /// Jh-224 hasher state
 pub type Jh224 = CoreWrapper<CtVariableCoreWrapper<JhCore, U28>>;
 /// Jh-256 hasher state
@@ -145,3 +163,21 @@ pub type Jh256 = CoreWrapper<CtVariableCoreWrapper<JhCore, U32>>;
 pub type Jh384 = CoreWrapper<CtVariableCoreWrapper<JhCore, U48>>;
 /// Jh-512 hasher state
 pub type Jh512 = CoreWrapper<CtVariableCoreWrapper<JhCore, U64>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{consts, derive_h0};
+
+    #[test]
+    fn derived_h0_matches_consts() {
+        let h0 = [
+            (28, consts::JH224_H0),
+            (32, consts::JH256_H0),
+            (48, consts::JH384_H0),
+            (64, consts::JH512_H0),
+        ];
+        for (size, expected) in h0.iter() {
+            assert_eq!(derive_h0(*size).finalize()[..], expected[..]);
+        }
+    }
+}
@@ -9,3 +9,33 @@ new_test!(short_224, "ShortMsgKAT_224", jh::Jh224, fixed_test);
 new_test!(short_256, "ShortMsgKAT_256", jh::Jh256, fixed_test);
 new_test!(short_384, "ShortMsgKAT_384", jh::Jh384, fixed_test);
 new_test!(short_512, "ShortMsgKAT_512", jh::Jh512, fixed_test);
+
+#[test]
+fn jh_var_matches_fixed() {
+    use digest::{Digest, Update, VariableOutput};
+
+    fn check<D: Digest>(size: usize) {
+        let msg = b"The quick brown fox jumps over the lazy dog";
+        let mut h = jh::JhVar::new(size).unwrap();
+        h.update(msg);
+        let mut out = [0u8; 64];
+        h.finalize_variable(&mut out[..size]).unwrap();
+        assert_eq!(out[..size], D::digest(msg)[..]);
+    }
+
+    check::<jh::Jh224>(28);
+    check::<jh::Jh256>(32);
+    check::<jh::Jh384>(48);
+    check::<jh::Jh512>(64);
+}
+
+#[test]
+fn jh_var_sizes() {
+    use digest::VariableOutput;
+
+    assert!(jh::JhVar::new(0).is_err());
+    assert!(jh::JhVar::new(65).is_err());
+    for size in 1..=64 {
+        assert_eq!(jh::JhVar::new(size).unwrap().output_size(), size);
+    }
+}
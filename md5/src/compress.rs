@@ -1,6 +1,9 @@
 #![allow(clippy::many_single_char_names, clippy::unreadable_literal)]
 use core::convert::TryInto;
 
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+pub(crate) mod asm;
+
 const RC: [u32; 64] = [
     // round 1
     0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
@@ -55,6 +58,7 @@ fn op_i(w: u32, x: u32, y: u32, z: u32, m: u32, c: u32, s: u32) -> u32 {
         .wrapping_add(x)
 }
 
+/// Compress a single block into `state` using only scalar operations.
 #[inline]
 pub fn compress_block(state: &mut [u32; 4], input: &[u8; 64]) {
     let mut a = state[0];
@@ -157,9 +161,19 @@ pub fn compress_block(state: &mut [u32; 4], input: &[u8; 64]) {
     state[3] = state[3].wrapping_add(d);
 }
 
+#[cfg(all(feature = "asm", target_arch = "x86"))]
+#[inline]
+pub fn compress(state: &mut [u32; 4], blocks: &[[u8; 64]]) {
+    md5_asm::compress(state, blocks);
+}
+
+#[cfg(not(all(feature = "asm", target_arch = "x86")))]
 #[inline]
 pub fn compress(state: &mut [u32; 4], blocks: &[[u8; 64]]) {
     for block in blocks {
-        compress_block(state, block)
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        asm::compress_block(state, block);
+        #[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
+        compress_block(state, block);
     }
 }
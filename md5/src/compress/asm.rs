@@ -0,0 +1,168 @@
+//! MD5 compression function written with inline assembly for `x86_64`.
+
+#![allow(clippy::many_single_char_names)]
+use core::{arch::asm, convert::TryInto};
+
+// Every step is a single `asm!` block operating only on registers. The
+// message word and the round constant are added before the boolean function,
+// so they do not extend the dependency chain on the previous step. The terms
+// of `G(x, y, z) = (x & z) | (y & !z)` are disjoint and added separately,
+// which leaves a single instruction between `x` and the accumulation.
+macro_rules! op {
+    (f, $a:ident, $b:ident, $c:ident, $d:ident, $m:expr, $rc:literal, $s:literal) => {
+        op!(@step $a, $b, $c, $d, $m, $rc, $s,
+            "mov {t:e}, {c:e}",
+            "xor {t:e}, {d:e}",
+            "and {t:e}, {b:e}",
+            "xor {t:e}, {d:e}",
+        )
+    };
+    (g, $a:ident, $b:ident, $c:ident, $d:ident, $m:expr, $rc:literal, $s:literal) => {
+        op!(@step $a, $b, $c, $d, $m, $rc, $s,
+            "mov {t:e}, {d:e}",
+            "not {t:e}",
+            "and {t:e}, {c:e}",
+            "add {a:e}, {t:e}",
+            "mov {t:e}, {d:e}",
+            "and {t:e}, {b:e}",
+        )
+    };
+    (h, $a:ident, $b:ident, $c:ident, $d:ident, $m:expr, $rc:literal, $s:literal) => {
+        op!(@step $a, $b, $c, $d, $m, $rc, $s,
+            "mov {t:e}, {c:e}",
+            "xor {t:e}, {d:e}",
+            "xor {t:e}, {b:e}",
+        )
+    };
+    (i, $a:ident, $b:ident, $c:ident, $d:ident, $m:expr, $rc:literal, $s:literal) => {
+        op!(@step $a, $b, $c, $d, $m, $rc, $s,
+            "mov {t:e}, {d:e}",
+            "not {t:e}",
+            "or {t:e}, {b:e}",
+            "xor {t:e}, {c:e}",
+        )
+    };
+    (@step $a:ident, $b:ident, $c:ident, $d:ident, $m:expr, $rc:literal, $s:literal, $($fun:literal,)+) => {
+        // SAFETY: the block only reads and writes its register operands
+        unsafe {
+            asm!(
+                "add {a:e}, {m:e}",
+                concat!("add {a:e}, ", $rc),
+                $($fun,)+
+                "add {a:e}, {t:e}",
+                concat!("rol {a:e}, ", $s),
+                "add {a:e}, {b:e}",
+                a = inout(reg) $a,
+                b = in(reg) $b,
+                c = in(reg) $c,
+                d = in(reg) $d,
+                m = in(reg) $m,
+                t = out(reg) _,
+                options(pure, nomem, nostack),
+            )
+        }
+    };
+}
+
+/// Compress a single block into `state` with the inline assembly steps.
+#[inline]
+pub fn compress_block(state: &mut [u32; 4], input: &[u8; 64]) {
+    let mut a = state[0];
+    let mut b = state[1];
+    let mut c = state[2];
+    let mut d = state[3];
+
+    let mut data = [0u32; 16];
+    for (o, chunk) in data.iter_mut().zip(input.chunks_exact(4)) {
+        *o = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    // round 1
+    op!(f, a, b, c, d, data[0], 0xd76aa478, 7);
+    op!(f, d, a, b, c, data[1], 0xe8c7b756, 12);
+    op!(f, c, d, a, b, data[2], 0x242070db, 17);
+    op!(f, b, c, d, a, data[3], 0xc1bdceee, 22);
+
+    op!(f, a, b, c, d, data[4], 0xf57c0faf, 7);
+    op!(f, d, a, b, c, data[5], 0x4787c62a, 12);
+    op!(f, c, d, a, b, data[6], 0xa8304613, 17);
+    op!(f, b, c, d, a, data[7], 0xfd469501, 22);
+
+    op!(f, a, b, c, d, data[8], 0x698098d8, 7);
+    op!(f, d, a, b, c, data[9], 0x8b44f7af, 12);
+    op!(f, c, d, a, b, data[10], 0xffff5bb1, 17);
+    op!(f, b, c, d, a, data[11], 0x895cd7be, 22);
+
+    op!(f, a, b, c, d, data[12], 0x6b901122, 7);
+    op!(f, d, a, b, c, data[13], 0xfd987193, 12);
+    op!(f, c, d, a, b, data[14], 0xa679438e, 17);
+    op!(f, b, c, d, a, data[15], 0x49b40821, 22);
+
+    // round 2
+    op!(g, a, b, c, d, data[1], 0xf61e2562, 5);
+    op!(g, d, a, b, c, data[6], 0xc040b340, 9);
+    op!(g, c, d, a, b, data[11], 0x265e5a51, 14);
+    op!(g, b, c, d, a, data[0], 0xe9b6c7aa, 20);
+
+    op!(g, a, b, c, d, data[5], 0xd62f105d, 5);
+    op!(g, d, a, b, c, data[10], 0x02441453, 9);
+    op!(g, c, d, a, b, data[15], 0xd8a1e681, 14);
+    op!(g, b, c, d, a, data[4], 0xe7d3fbc8, 20);
+
+    op!(g, a, b, c, d, data[9], 0x21e1cde6, 5);
+    op!(g, d, a, b, c, data[14], 0xc33707d6, 9);
+    op!(g, c, d, a, b, data[3], 0xf4d50d87, 14);
+    op!(g, b, c, d, a, data[8], 0x455a14ed, 20);
+
+    op!(g, a, b, c, d, data[13], 0xa9e3e905, 5);
+    op!(g, d, a, b, c, data[2], 0xfcefa3f8, 9);
+    op!(g, c, d, a, b, data[7], 0x676f02d9, 14);
+    op!(g, b, c, d, a, data[12], 0x8d2a4c8a, 20);
+
+    // round 3
+    op!(h, a, b, c, d, data[5], 0xfffa3942, 4);
+    op!(h, d, a, b, c, data[8], 0x8771f681, 11);
+    op!(h, c, d, a, b, data[11], 0x6d9d6122, 16);
+    op!(h, b, c, d, a, data[14], 0xfde5380c, 23);
+
+    op!(h, a, b, c, d, data[1], 0xa4beea44, 4);
+    op!(h, d, a, b, c, data[4], 0x4bdecfa9, 11);
+    op!(h, c, d, a, b, data[7], 0xf6bb4b60, 16);
+    op!(h, b, c, d, a, data[10], 0xbebfbc70, 23);
+
+    op!(h, a, b, c, d, data[13], 0x289b7ec6, 4);
+    op!(h, d, a, b, c, data[0], 0xeaa127fa, 11);
+    op!(h, c, d, a, b, data[3], 0xd4ef3085, 16);
+    op!(h, b, c, d, a, data[6], 0x04881d05, 23);
+
+    op!(h, a, b, c, d, data[9], 0xd9d4d039, 4);
+    op!(h, d, a, b, c, data[12], 0xe6db99e5, 11);
+    op!(h, c, d, a, b, data[15], 0x1fa27cf8, 16);
+    op!(h, b, c, d, a, data[2], 0xc4ac5665, 23);
+
+    // round 4
+    op!(i, a, b, c, d, data[0], 0xf4292244, 6);
+    op!(i, d, a, b, c, data[7], 0x432aff97, 10);
+    op!(i, c, d, a, b, data[14], 0xab9423a7, 15);
+    op!(i, b, c, d, a, data[5], 0xfc93a039, 21);
+
+    op!(i, a, b, c, d, data[12], 0x655b59c3, 6);
+    op!(i, d, a, b, c, data[3], 0x8f0ccc92, 10);
+    op!(i, c, d, a, b, data[10], 0xffeff47d, 15);
+    op!(i, b, c, d, a, data[1], 0x85845dd1, 21);
+
+    op!(i, a, b, c, d, data[8], 0x6fa87e4f, 6);
+    op!(i, d, a, b, c, data[15], 0xfe2ce6e0, 10);
+    op!(i, c, d, a, b, data[6], 0xa3014314, 15);
+    op!(i, b, c, d, a, data[13], 0x4e0811a1, 21);
+
+    op!(i, a, b, c, d, data[4], 0xf7537e82, 6);
+    op!(i, d, a, b, c, data[11], 0xbd3af235, 10);
+    op!(i, c, d, a, b, data[2], 0x2ad7d2bb, 15);
+    op!(i, b, c, d, a, data[9], 0xeb86d391, 21);
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+}
@@ -18,6 +18,21 @@
 //! assert_eq!(result[..], hex!("5eb63bbbe01eeed093cb22bb8f5acdc3"));
 //! ```
 //!
+//! Many independent messages can be hashed at once with [`digest_many`],
+//! which processes them in parallel SIMD lanes when supported by the CPU:
+//!
+//! ```rust
+//! use md5::{digest_many, Digest, Md5};
+//!
+//! let inputs: [&[u8]; 3] = [b"hello", b"world", b"hello world"];
+//! let mut outputs = [Default::default(); 3];
+//! digest_many(&inputs, &mut outputs);
+//!
+//! for (input, output) in inputs.iter().zip(outputs.iter()) {
+//!     assert_eq!(*output, Md5::digest(input));
+//! }
+//! ```
+//!
 //! Also see [RustCrypto/hashes][2] readme.
 //!
 //! [1]: https://en.wikipedia.org/wiki/MD5
@@ -30,13 +45,25 @@
 )]
 #![warn(missing_docs, rust_2018_idioms)]
 
-#[cfg(all(feature = "asm", any(target_arch = "x86", target_arch = "x86_64")))]
-extern crate md5_asm as compress;
-
-#[cfg(not(all(feature = "asm", any(target_arch = "x86", target_arch = "x86_64"))))]
 mod compress;
+mod many;
 
 pub use digest::{self, Digest};
+pub use many::digest_many;
+
+/// Backends compiled for the target, exposed for the equivalence tests in
+/// `tests/`. Not part of the public API.
+#[doc(hidden)]
+pub mod backends {
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    pub use crate::compress::asm::compress_block as compress_block_asm;
+    pub use crate::compress::compress_block as compress_block_soft;
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft")
+    ))]
+    pub use crate::many::x86::{avx2 as digest_many_avx2, sse2 as digest_many_sse2};
+}
 
 use compress::compress;
 
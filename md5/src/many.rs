@@ -0,0 +1,153 @@
+//! Multi-buffer MD5: hashing of independent messages in parallel lanes.
+
+use crate::{Md5, Output};
+use digest::Digest;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+pub(crate) mod x86;
+
+/// Maximum number of lanes supported by the SIMD backends.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+const MAX_LANES: usize = 8;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+const IV: [u32; 4] = [0x6745_2301, 0xEFCD_AB89, 0x98BA_DCFE, 0x1032_5476];
+
+/// Compute MD5 digests of multiple independent messages.
+///
+/// The digest of `inputs[i]` is written to `outputs[i]` and is equal to
+/// `Md5::digest(inputs[i])`. Messages may have different lengths.
+///
+/// On `x86`/`x86_64` the messages are processed in 8 (AVX2) or 4 (SSE2)
+/// parallel lanes, depending on the CPU features detected at runtime. On
+/// other targets or with the `force-soft` feature enabled the messages are
+/// hashed one after another.
+///
+/// # Panics
+///
+/// If `inputs` and `outputs` have different lengths.
+pub fn digest_many(inputs: &[&[u8]], outputs: &mut [Output<Md5>]) {
+    assert_eq!(
+        inputs.len(),
+        outputs.len(),
+        "number of inputs and outputs must be equal"
+    );
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft")
+    ))]
+    {
+        if x86::digest_many(inputs, outputs) {
+            return;
+        }
+    }
+
+    digest_many_soft(inputs, outputs);
+}
+
+fn digest_many_soft(inputs: &[&[u8]], outputs: &mut [Output<Md5>]) {
+    for (input, output) in inputs.iter().zip(outputs.iter_mut()) {
+        *output = Md5::digest(input);
+    }
+}
+
+/// Number of blocks in the padded message of length `len`.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+#[inline(always)]
+fn padded_blocks(len: usize) -> usize {
+    (len + 8) / 64 + 1
+}
+
+/// Write block `idx` of the padded message `data` to `out`.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+#[inline(always)]
+fn fill_block(data: &[u8], idx: usize, out: &mut [u8; 64]) {
+    let start = 64 * idx;
+    if start + 64 <= data.len() {
+        out.copy_from_slice(&data[start..start + 64]);
+        return;
+    }
+
+    *out = [0; 64];
+    if start <= data.len() {
+        let tail = &data[start..];
+        out[..tail.len()].copy_from_slice(tail);
+        out[tail.len()] = 0x80;
+    }
+    if idx + 1 == padded_blocks(data.len()) {
+        let bit_len = (data.len() as u64).wrapping_mul(8);
+        out[56..].copy_from_slice(&bit_len.to_le_bytes());
+    }
+}
+
+/// Hash `inputs` using `lanes` lanes of the multi-lane compression
+/// function `compress`.
+///
+/// Every lane takes the next pending message as soon as it has finished
+/// its previous one. Lanes without a message left process garbage, which
+/// is discarded.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+#[inline(always)]
+fn digest_lanes<F>(inputs: &[&[u8]], outputs: &mut [Output<Md5>], lanes: usize, mut compress: F)
+where
+    F: FnMut(&mut [[u32; 4]; MAX_LANES], &[[u8; 64]; MAX_LANES]),
+{
+    let mut states = [IV; MAX_LANES];
+    let mut blocks = [[0u8; 64]; MAX_LANES];
+    // (message index, index of the next block)
+    let mut jobs: [Option<(usize, usize)>; MAX_LANES] = [None; MAX_LANES];
+    let mut next = 0;
+
+    loop {
+        let mut active = false;
+        for lane in 0..lanes {
+            if jobs[lane].is_none() && next < inputs.len() {
+                jobs[lane] = Some((next, 0));
+                states[lane] = IV;
+                next += 1;
+            }
+            if let Some((msg, block)) = jobs[lane] {
+                fill_block(inputs[msg], block, &mut blocks[lane]);
+                active = true;
+            }
+        }
+        if !active {
+            break;
+        }
+
+        compress(&mut states, &blocks);
+
+        for lane in 0..lanes {
+            if let Some((msg, block)) = jobs[lane] {
+                if block + 1 == padded_blocks(inputs[msg].len()) {
+                    let out = &mut outputs[msg];
+                    for (chunk, v) in out.chunks_exact_mut(4).zip(states[lane].iter()) {
+                        chunk.copy_from_slice(&v.to_le_bytes());
+                    }
+                    jobs[lane] = None;
+                } else {
+                    jobs[lane] = Some((msg, block + 1));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Multi-buffer MD5 `x86`/`x86_64` backends
+
+#![allow(clippy::many_single_char_names, clippy::unreadable_literal)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::{digest_lanes, MAX_LANES};
+use crate::{Md5, Output};
+use core::convert::TryInto;
+
+cpufeatures::new!(avx2_cpuid, "avx2");
+cpufeatures::new!(sse2_cpuid, "sse2");
+
+const RC: [u32; 64] = [
+    // round 1
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    // round 2
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    // round 3
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    // round 4
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// Hash `inputs` with the best available backend.
+///
+/// Returns `false` if no SIMD backend is supported by the CPU.
+pub(super) fn digest_many(inputs: &[&[u8]], outputs: &mut [Output<Md5>]) -> bool {
+    // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
+    // after stabilization
+    avx2(inputs, outputs) || sse2(inputs, outputs)
+}
+
+/// Hash `inputs` in 8 AVX2 lanes, returns `false` if the CPU does not
+/// support AVX2.
+pub fn avx2(inputs: &[&[u8]], outputs: &mut [Output<Md5>]) -> bool {
+    let supported = avx2_cpuid::get();
+    if supported {
+        digest_lanes(inputs, outputs, 8, |s, b| unsafe { compress_avx2(s, b) });
+    }
+    supported
+}
+
+/// Hash `inputs` in 4 SSE2 lanes, returns `false` if the CPU does not
+/// support SSE2.
+pub fn sse2(inputs: &[&[u8]], outputs: &mut [Output<Md5>]) -> bool {
+    let supported = sse2_cpuid::get();
+    if supported {
+        digest_lanes(inputs, outputs, 4, |s, b| unsafe { compress_sse2(s, b) });
+    }
+    supported
+}
+
+#[inline(always)]
+fn load_word(block: &[u8; 64], i: usize) -> i32 {
+    u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap()) as i32
+}
+
+macro_rules! fn_md5_compress_lanes {
+    ($name:ident, $feature:literal, $ty:ident, $lanes:literal, {
+        ADD32 = $ADD32:ident,
+        SRL32 = $SRL32:ident,
+        SLL32 = $SLL32:ident,
+        AND = $AND:ident,
+        ANDNOT = $ANDNOT:ident,
+        OR = $OR:ident,
+        XOR = $XOR:ident,
+        SET1 = $SET1:ident,
+        LOAD = $LOAD:ident,
+        STORE = $STORE:ident,
+    }) => {
+        /// Compress one block in each of the first lanes.
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(states: &mut [[u32; 4]; MAX_LANES], blocks: &[[u8; 64]; MAX_LANES]) {
+            // Transpose message words and state into lane order
+            let mut m = [$SET1(0); 16];
+            for (i, mi) in m.iter_mut().enumerate() {
+                let mut words = [0i32; $lanes];
+                for (w, block) in words.iter_mut().zip(blocks.iter()) {
+                    *w = load_word(block, i);
+                }
+                *mi = $LOAD(words.as_ptr() as *const _);
+            }
+            let mut s = [$SET1(0); 4];
+            for (i, si) in s.iter_mut().enumerate() {
+                let mut words = [0u32; $lanes];
+                for (w, state) in words.iter_mut().zip(states.iter()) {
+                    *w = state[i];
+                }
+                *si = $LOAD(words.as_ptr() as *const _);
+            }
+
+            macro_rules! op {
+                ($f:ident, $w:ident, $x:ident, $y:ident, $z:ident, $m:expr, $c:expr, $s:literal) => {
+                    $w = $ADD32(
+                        $x,
+                        rotl!(
+                            $ADD32(
+                                $ADD32($w, $f!($x, $y, $z)),
+                                $ADD32(m[$m], $SET1(RC[$c] as i32)),
+                            ),
+                            $s
+                        ),
+                    );
+                };
+            }
+            macro_rules! rotl {
+                ($v:expr, $s:literal) => {{
+                    let v = $v;
+                    $OR($SLL32(v, $s), $SRL32(v, 32 - $s))
+                }};
+            }
+            macro_rules! f {
+                ($x:ident, $y:ident, $z:ident) => {
+                    $OR($AND($x, $y), $ANDNOT($x, $z))
+                };
+            }
+            macro_rules! g {
+                ($x:ident, $y:ident, $z:ident) => {
+                    $OR($AND($x, $z), $ANDNOT($z, $y))
+                };
+            }
+            macro_rules! h {
+                ($x:ident, $y:ident, $z:ident) => {
+                    $XOR($XOR($x, $y), $z)
+                };
+            }
+            macro_rules! i {
+                ($x:ident, $y:ident, $z:ident) => {
+                    $XOR($y, $OR($x, $XOR($z, $SET1(-1))))
+                };
+            }
+
+            let [mut a, mut b, mut c, mut d] = s;
+
+            // round 1
+            op!(f, a, b, c, d, 0, 0, 7);
+            op!(f, d, a, b, c, 1, 1, 12);
+            op!(f, c, d, a, b, 2, 2, 17);
+            op!(f, b, c, d, a, 3, 3, 22);
+            op!(f, a, b, c, d, 4, 4, 7);
+            op!(f, d, a, b, c, 5, 5, 12);
+            op!(f, c, d, a, b, 6, 6, 17);
+            op!(f, b, c, d, a, 7, 7, 22);
+            op!(f, a, b, c, d, 8, 8, 7);
+            op!(f, d, a, b, c, 9, 9, 12);
+            op!(f, c, d, a, b, 10, 10, 17);
+            op!(f, b, c, d, a, 11, 11, 22);
+            op!(f, a, b, c, d, 12, 12, 7);
+            op!(f, d, a, b, c, 13, 13, 12);
+            op!(f, c, d, a, b, 14, 14, 17);
+            op!(f, b, c, d, a, 15, 15, 22);
+
+            // round 2
+            op!(g, a, b, c, d, 1, 16, 5);
+            op!(g, d, a, b, c, 6, 17, 9);
+            op!(g, c, d, a, b, 11, 18, 14);
+            op!(g, b, c, d, a, 0, 19, 20);
+            op!(g, a, b, c, d, 5, 20, 5);
+            op!(g, d, a, b, c, 10, 21, 9);
+            op!(g, c, d, a, b, 15, 22, 14);
+            op!(g, b, c, d, a, 4, 23, 20);
+            op!(g, a, b, c, d, 9, 24, 5);
+            op!(g, d, a, b, c, 14, 25, 9);
+            op!(g, c, d, a, b, 3, 26, 14);
+            op!(g, b, c, d, a, 8, 27, 20);
+            op!(g, a, b, c, d, 13, 28, 5);
+            op!(g, d, a, b, c, 2, 29, 9);
+            op!(g, c, d, a, b, 7, 30, 14);
+            op!(g, b, c, d, a, 12, 31, 20);
+
+            // round 3
+            op!(h, a, b, c, d, 5, 32, 4);
+            op!(h, d, a, b, c, 8, 33, 11);
+            op!(h, c, d, a, b, 11, 34, 16);
+            op!(h, b, c, d, a, 14, 35, 23);
+            op!(h, a, b, c, d, 1, 36, 4);
+            op!(h, d, a, b, c, 4, 37, 11);
+            op!(h, c, d, a, b, 7, 38, 16);
+            op!(h, b, c, d, a, 10, 39, 23);
+            op!(h, a, b, c, d, 13, 40, 4);
+            op!(h, d, a, b, c, 0, 41, 11);
+            op!(h, c, d, a, b, 3, 42, 16);
+            op!(h, b, c, d, a, 6, 43, 23);
+            op!(h, a, b, c, d, 9, 44, 4);
+            op!(h, d, a, b, c, 12, 45, 11);
+            op!(h, c, d, a, b, 15, 46, 16);
+            op!(h, b, c, d, a, 2, 47, 23);
+
+            // round 4
+            op!(i, a, b, c, d, 0, 48, 6);
+            op!(i, d, a, b, c, 7, 49, 10);
+            op!(i, c, d, a, b, 14, 50, 15);
+            op!(i, b, c, d, a, 5, 51, 21);
+            op!(i, a, b, c, d, 12, 52, 6);
+            op!(i, d, a, b, c, 3, 53, 10);
+            op!(i, c, d, a, b, 10, 54, 15);
+            op!(i, b, c, d, a, 1, 55, 21);
+            op!(i, a, b, c, d, 8, 56, 6);
+            op!(i, d, a, b, c, 15, 57, 10);
+            op!(i, c, d, a, b, 6, 58, 15);
+            op!(i, b, c, d, a, 13, 59, 21);
+            op!(i, a, b, c, d, 4, 60, 6);
+            op!(i, d, a, b, c, 11, 61, 10);
+            op!(i, c, d, a, b, 2, 62, 15);
+            op!(i, b, c, d, a, 9, 63, 21);
+
+            let res = [a, b, c, d];
+            for (i, (si, ri)) in s.iter().zip(res.iter()).enumerate() {
+                let mut words = [0u32; $lanes];
+                $STORE(words.as_mut_ptr() as *mut _, $ADD32(*si, *ri));
+                for (w, state) in words.iter().zip(states.iter_mut()) {
+                    state[i] = *w;
+                }
+            }
+        }
+    };
+}
+
+fn_md5_compress_lanes!(compress_sse2, "sse2", __m128i, 4, {
+    ADD32 = _mm_add_epi32,
+    SRL32 = _mm_srli_epi32,
+    SLL32 = _mm_slli_epi32,
+    AND = _mm_and_si128,
+    ANDNOT = _mm_andnot_si128,
+    OR = _mm_or_si128,
+    XOR = _mm_xor_si128,
+    SET1 = _mm_set1_epi32,
+    LOAD = _mm_loadu_si128,
+    STORE = _mm_storeu_si128,
+});
+
+fn_md5_compress_lanes!(compress_avx2, "avx2", __m256i, 8, {
+    ADD32 = _mm256_add_epi32,
+    SRL32 = _mm256_srli_epi32,
+    SLL32 = _mm256_slli_epi32,
+    AND = _mm256_and_si256,
+    ANDNOT = _mm256_andnot_si256,
+    OR = _mm256_or_si256,
+    XOR = _mm256_xor_si256,
+    SET1 = _mm256_set1_epi32,
+    LOAD = _mm256_loadu_si256,
+    STORE = _mm256_storeu_si256,
+});
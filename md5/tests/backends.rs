@@ -0,0 +1,85 @@
+//! Equivalence tests of the multi-buffer and assembly backends against the
+//! software implementation. Backends not supported by the CPU running the
+//! tests are skipped with a message.
+#![cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+
+use md5::{backends, Digest, Md5};
+
+type DigestMany = fn(&[&[u8]], &mut [md5::digest::Output<Md5>]) -> bool;
+
+fn rand_u64(seed: &mut u64) -> u64 {
+    // xorshift64
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed
+}
+
+/// Messages of random content with lengths around the padding and block
+/// boundaries and random lengths spanning up to 10 blocks, so that lanes
+/// retire at different times.
+fn rand_messages(seed: &mut u64) -> Vec<Vec<u8>> {
+    let mut lens = vec![0, 1, 55, 56, 63, 64, 65, 119, 120, 128];
+    for _ in 0..40 {
+        lens.push(rand_u64(seed) as usize % 640);
+    }
+    lens.iter()
+        .map(|&len| (0..len).map(|_| rand_u64(seed) as u8).collect())
+        .collect()
+}
+
+fn check(name: &str, f: DigestMany) {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    let msgs = rand_messages(&mut seed);
+    let inputs: Vec<&[u8]> = msgs.iter().map(|m| &m[..]).collect();
+    // input counts below, at and above the number of lanes
+    for &n in [0, 1, 3, 4, 5, 7, 8, 9, 17, inputs.len()].iter() {
+        let mut outputs = vec![Default::default(); n];
+        if !f(&inputs[..n], &mut outputs) {
+            eprintln!("{} is not supported by the CPU, skipping", name);
+            return;
+        }
+        for (input, output) in inputs.iter().zip(outputs.iter()) {
+            assert_eq!(
+                *output,
+                Md5::digest(input),
+                "{}, len: {}",
+                name,
+                input.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn digest_many_avx2_matches_md5() {
+    check("AVX2", backends::digest_many_avx2);
+}
+
+#[test]
+fn digest_many_sse2_matches_md5() {
+    check("SSE2", backends::digest_many_sse2);
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+#[test]
+fn compress_block_asm_matches_soft() {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    let mut state = [0u32; 4];
+    for v in state.iter_mut() {
+        *v = rand_u64(&mut seed) as u32;
+    }
+    let mut expected = state;
+    for _ in 0..64 {
+        let mut block = [0u8; 64];
+        for b in block.iter_mut() {
+            *b = rand_u64(&mut seed) as u8;
+        }
+        backends::compress_block_soft(&mut expected, &block);
+        backends::compress_block_asm(&mut state, &block);
+        assert_eq!(state, expected);
+    }
+}
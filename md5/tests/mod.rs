@@ -13,3 +13,17 @@ fn md5_rand() {
         hex!("61aec26f1b909578ef638ae02dac0977")[..]
     );
 }
+
+#[test]
+fn md5_digest_many() {
+    let data: Vec<u8> = (0..2048u32).map(|i| (i * 7 + i / 251) as u8).collect();
+    let inputs: Vec<&[u8]> = (0..100).map(|i| &data[i..i + 17 * i]).collect();
+    // input counts below, at and above the number of SIMD lanes
+    for &n in [0, 1, 3, 4, 5, 8, 9, 19, 100].iter() {
+        let mut outputs = vec![Default::default(); n];
+        md5::digest_many(&inputs[..n], &mut outputs);
+        for (input, output) in inputs.iter().zip(outputs.iter()) {
+            assert_eq!(*output, Md5::digest(input));
+        }
+    }
+}
@@ -10,12 +10,9 @@ cfg_if::cfg_if! {
         mod aarch64;
         use aarch64::compress as compress_inner;
     } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
-        #[cfg(not(feature = "asm"))]
         mod soft;
-        #[cfg(feature = "asm")]
-        mod soft {
-            pub use sha1_asm::compress;
-        }
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        mod asm;
         mod x86;
         use x86::compress as compress_inner;
     } else {
@@ -35,3 +32,15 @@ pub fn compress(state: &mut [u32; 5], blocks: &[Block<Sha1Core>]) {
         unsafe { &*(blocks as *const _ as *const [[u8; BLOCK_SIZE]]) };
     compress_inner(state, blocks);
 }
+
+/// SHA-1 backends compiled for the target, see [`crate::backends`].
+pub mod backends {
+    #[cfg(all(feature = "asm", target_arch = "x86_64", not(feature = "force-soft")))]
+    pub use super::asm::compress as asm;
+    pub use super::soft::compress as soft;
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft")
+    ))]
+    pub use super::x86::{avx2, shani, ssse3};
+}
@@ -1,5 +1,11 @@
 //! SHA-1 `aarch64` backend.
 
+// TODO: stdarch intrinsics: RustCrypto/hashes#257
+
+use core::arch::{aarch64::*, asm};
+
+const K: [u32; 4] = [0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xCA62C1D6];
+
 // Per rustc target feature docs for `aarch64-unknown-linux-gnu` and
 // `aarch64-apple-darwin` platforms, the `sha2` target feature enables
 // SHA-1 as well:
@@ -11,8 +17,121 @@ pub fn compress(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
     // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
     // after stabilization
     if sha1_hwcap::get() {
-        sha1_asm::compress(state, blocks);
+        unsafe { sha1_compress(state, blocks) }
     } else {
         super::soft::compress(state, blocks);
     }
 }
+
+#[target_feature(enable = "sha2")]
+unsafe fn sha1_compress(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
+    // SAFETY: Requires the sha2 feature.
+
+    // Load state into vectors.
+    let mut abcd = vld1q_u32(state[0..4].as_ptr());
+    let mut e = state[4];
+
+    // Iterate through the message blocks.
+    for block in blocks {
+        // Keep original state values.
+        let abcd_orig = abcd;
+        let e_orig = e;
+
+        // Load the message block into vectors, assuming little endianness.
+        let mut s = [
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[0..16].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[16..32].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[32..48].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[48..64].as_ptr()))),
+        ];
+
+        // Rounds 4 * i to 4 * i + 3
+        for i in 0..20 {
+            let tmp = vaddq_u32(s[i % 4], vdupq_n_u32(K[i / 5]));
+            let e_next = vsha1h_u32(vgetq_lane_u32(abcd, 0));
+            abcd = match i / 5 {
+                0 => vsha1cq_u32(abcd, e, tmp),
+                2 => vsha1mq_u32(abcd, e, tmp),
+                _ => vsha1pq_u32(abcd, e, tmp),
+            };
+            e = e_next;
+
+            // Message words 4 * i + 16 to 4 * i + 19
+            if i < 16 {
+                let w = vsha1su0q_u32(s[i % 4], s[(i + 1) % 4], s[(i + 2) % 4]);
+                s[i % 4] = vsha1su1q_u32(w, s[(i + 3) % 4]);
+            }
+        }
+
+        // Add the block-specific state to the original state.
+        abcd = vaddq_u32(abcd, abcd_orig);
+        e = e.wrapping_add(e_orig);
+    }
+
+    // Store vectors into state.
+    vst1q_u32(state[0..4].as_mut_ptr(), abcd);
+    state[4] = e;
+}
+
+// TODO remove these polyfills once SHA1 intrinsics land
+
+#[inline(always)]
+unsafe fn vsha1cq_u32(mut hash_abcd: uint32x4_t, hash_e: u32, wk: uint32x4_t) -> uint32x4_t {
+    asm!(
+        "SHA1C {:q}, {:s}, {:v}.4S",
+        inout(vreg) hash_abcd, in(vreg) hash_e, in(vreg) wk,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    hash_abcd
+}
+
+#[inline(always)]
+unsafe fn vsha1pq_u32(mut hash_abcd: uint32x4_t, hash_e: u32, wk: uint32x4_t) -> uint32x4_t {
+    asm!(
+        "SHA1P {:q}, {:s}, {:v}.4S",
+        inout(vreg) hash_abcd, in(vreg) hash_e, in(vreg) wk,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    hash_abcd
+}
+
+#[inline(always)]
+unsafe fn vsha1mq_u32(mut hash_abcd: uint32x4_t, hash_e: u32, wk: uint32x4_t) -> uint32x4_t {
+    asm!(
+        "SHA1M {:q}, {:s}, {:v}.4S",
+        inout(vreg) hash_abcd, in(vreg) hash_e, in(vreg) wk,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    hash_abcd
+}
+
+#[inline(always)]
+unsafe fn vsha1h_u32(hash_e: u32) -> u32 {
+    let res: u32;
+    asm!(
+        "SHA1H {:s}, {:s}",
+        out(vreg) res, in(vreg) hash_e,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    res
+}
+
+#[inline(always)]
+unsafe fn vsha1su0q_u32(mut w0_3: uint32x4_t, w4_7: uint32x4_t, w8_11: uint32x4_t) -> uint32x4_t {
+    asm!(
+        "SHA1SU0 {:v}.4S, {:v}.4S, {:v}.4S",
+        inout(vreg) w0_3, in(vreg) w4_7, in(vreg) w8_11,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    w0_3
+}
+
+#[inline(always)]
+unsafe fn vsha1su1q_u32(mut tw0_3: uint32x4_t, w12_15: uint32x4_t) -> uint32x4_t {
+    asm!(
+        "SHA1SU1 {:v}.4S, {:v}.4S",
+        inout(vreg) tw0_3, in(vreg) w12_15,
+        options(pure, nomem, nostack, preserves_flags)
+    );
+    tw0_3
+}
@@ -0,0 +1,130 @@
+//! SHA-1 compression function written with inline assembly for `x86_64`.
+
+#![allow(clippy::many_single_char_names)]
+use super::BLOCK_SIZE;
+use core::arch::asm;
+
+// All 80 rounds of a block are a single `asm!` block. The message schedule
+// is computed on the fly in a circular buffer of 16 words; the assembler
+// picks between loading a message word and expanding the schedule for every
+// round with `.if`. The round structure follows the x86-64 assembly of the
+// former `sha1-asm` crate: the message word is added to `e` first, then the
+// boolean function and the round constant are added with a single `lea`,
+// which leaves a single rotation and addition between `a` and the new value
+// of `e`. The majority function is computed as `(b & (c | d)) | (c & d)`
+// using the register of the message word as a second temporary. Round
+// constants above `i32::MAX` are written as negative displacements.
+macro_rules! reg {
+    ($r:ident) => {
+        concat!("{", stringify!($r), ":e}")
+    };
+}
+
+macro_rules! round {
+    (ch, $k:literal, $i:expr, $a:ident, $b:ident, $c:ident, $d:ident, $e:ident) => {
+        round!(@step $k, $i, $a, $b, $e, concat!(
+            "mov {t:e}, ", reg!($c), "\n",
+            "xor {t:e}, ", reg!($d), "\n",
+            "and {t:e}, ", reg!($b), "\n",
+            "xor {t:e}, ", reg!($d), "\n",
+        ))
+    };
+    (parity, $k:literal, $i:expr, $a:ident, $b:ident, $c:ident, $d:ident, $e:ident) => {
+        round!(@step $k, $i, $a, $b, $e, concat!(
+            "mov {t:e}, ", reg!($c), "\n",
+            "xor {t:e}, ", reg!($d), "\n",
+            "xor {t:e}, ", reg!($b), "\n",
+        ))
+    };
+    (maj, $k:literal, $i:expr, $a:ident, $b:ident, $c:ident, $d:ident, $e:ident) => {
+        round!(@step $k, $i, $a, $b, $e, concat!(
+            "mov {t:e}, ", reg!($c), "\n",
+            "mov {x:e}, ", reg!($c), "\n",
+            "or {t:e}, ", reg!($d), "\n",
+            "and {t:e}, ", reg!($b), "\n",
+            "and {x:e}, ", reg!($d), "\n",
+            "or {t:e}, {x:e}\n",
+        ))
+    };
+    (@step $k:literal, $i:expr, $a:ident, $b:ident, $e:ident, $fun:expr) => {
+        concat!(
+            ".if ", $i, " < 16\n",
+            "mov {x:e}, dword ptr [{data} + 4*(", $i, ")]\n",
+            "bswap {x:e}\n",
+            ".else\n",
+            "mov {x:e}, dword ptr [{w} + 4*((", $i, " - 3) & 15)]\n",
+            "xor {x:e}, dword ptr [{w} + 4*((", $i, " - 8) & 15)]\n",
+            "xor {x:e}, dword ptr [{w} + 4*((", $i, " - 14) & 15)]\n",
+            "xor {x:e}, dword ptr [{w} + 4*((", $i, ") & 15)]\n",
+            "rol {x:e}, 1\n",
+            ".endif\n",
+            "mov dword ptr [{w} + 4*((", $i, ") & 15)], {x:e}\n",
+            "add ", reg!($e), ", {x:e}\n",
+            $fun,
+            "rol ", reg!($b), ", 30\n",
+            "lea ", reg!($e), ", [{", stringify!($e), ":r} + {t:r} + ", $k, "]\n",
+            "mov {t:e}, ", reg!($a), "\n",
+            "rol {t:e}, 5\n",
+            "add ", reg!($e), ", {t:e}\n",
+        )
+    };
+}
+
+macro_rules! rounds5 {
+    ($f:ident, $k:literal, $i:literal) => {
+        concat!(
+            round!($f, $k, concat!($i, " + 0"), a, b, c, d, e),
+            round!($f, $k, concat!($i, " + 1"), e, a, b, c, d),
+            round!($f, $k, concat!($i, " + 2"), d, e, a, b, c),
+            round!($f, $k, concat!($i, " + 3"), c, d, e, a, b),
+            round!($f, $k, concat!($i, " + 4"), b, c, d, e, a),
+        )
+    };
+}
+
+/// Compress `blocks` into `state` with the inline assembly rounds.
+pub fn compress(state: &mut [u32; 5], blocks: &[[u8; BLOCK_SIZE]]) {
+    let mut w = [0u32; 16];
+    for block in blocks {
+        let [mut a, mut b, mut c, mut d, mut e] = *state;
+
+        // SAFETY: the block reads 64 bytes of `block` and accesses only
+        // the 16 words of `w`
+        unsafe {
+            asm!(
+                rounds5!(ch, 0x5a827999, 0),
+                rounds5!(ch, 0x5a827999, 5),
+                rounds5!(ch, 0x5a827999, 10),
+                rounds5!(ch, 0x5a827999, 15),
+                rounds5!(parity, 0x6ed9eba1, 20),
+                rounds5!(parity, 0x6ed9eba1, 25),
+                rounds5!(parity, 0x6ed9eba1, 30),
+                rounds5!(parity, 0x6ed9eba1, 35),
+                rounds5!(maj, -0x70e44324, 40),
+                rounds5!(maj, -0x70e44324, 45),
+                rounds5!(maj, -0x70e44324, 50),
+                rounds5!(maj, -0x70e44324, 55),
+                rounds5!(parity, -0x359d3e2a, 60),
+                rounds5!(parity, -0x359d3e2a, 65),
+                rounds5!(parity, -0x359d3e2a, 70),
+                rounds5!(parity, -0x359d3e2a, 75),
+                a = inout(reg) a,
+                b = inout(reg) b,
+                c = inout(reg) c,
+                d = inout(reg) d,
+                e = inout(reg) e,
+                data = in(reg) block.as_ptr(),
+                w = in(reg) w.as_mut_ptr(),
+                x = out(reg) _,
+                t = out(reg) _,
+                options(nostack),
+            );
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+    }
+}
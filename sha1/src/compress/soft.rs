@@ -245,6 +245,7 @@ fn sha1_digest_block_u32(state: &mut [u32; 5], block: &[u32; 16]) {
     state[4] = state[4].wrapping_add(e);
 }
 
+/// Compress `blocks` into `state` using only scalar operations.
 pub fn compress(state: &mut [u32; 5], blocks: &[[u8; BLOCK_SIZE]]) {
     let mut block_u32 = [0u32; BLOCK_SIZE / 4];
     // since LLVM can't properly use aliasing yet it will make
@@ -98,6 +98,8 @@ unsafe fn digest_blocks(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
 }
 
 cpufeatures::new!(shani_cpuid, "sha", "sse2", "ssse3", "sse4.1");
+cpufeatures::new!(avx2_cpuid, "avx2");
+cpufeatures::new!(ssse3_cpuid, "sse2", "ssse3");
 
 pub fn compress(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
     // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
@@ -106,7 +108,258 @@ pub fn compress(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
         unsafe {
             digest_blocks(state, blocks);
         }
+    } else if avx2_cpuid::get() {
+        unsafe {
+            sha1_compress_avx2(state, blocks);
+        }
+    } else if ssse3_cpuid::get() {
+        unsafe {
+            sha1_compress_ssse3(state, blocks);
+        }
     } else {
-        super::soft::compress(state, blocks);
+        compress_scalar(state, blocks);
+    }
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+use super::asm::compress as compress_scalar;
+#[cfg(not(feature = "asm"))]
+use super::soft::compress as compress_scalar;
+#[cfg(all(feature = "asm", target_arch = "x86"))]
+use sha1_asm::compress as compress_scalar;
+
+macro_rules! backend {
+    ($name:ident, $cpuid:ident, $f:ident) => {
+        /// Run the backend if the CPU supports it, returns `false` otherwise.
+        pub fn $name(state: &mut [u32; 5], blocks: &[[u8; 64]]) -> bool {
+            let supported = $cpuid::get();
+            if supported {
+                unsafe { $f(state, blocks) }
+            }
+            supported
+        }
+    };
+}
+
+backend!(shani, shani_cpuid, digest_blocks);
+backend!(avx2, avx2_cpuid, sha1_compress_avx2);
+backend!(ssse3, ssse3_cpuid, sha1_compress_ssse3);
+
+// SSSE3 and AVX2 backends for CPUs without the SHA extensions. Following
+// Intel's "Improving the Performance of the Secure Hash Algorithm (SHA-1)",
+// the 80 words of `W[t] + K` are expanded with SIMD instructions ahead of the
+// rounds, which stay on general purpose registers. With AVX2 the schedules
+// of two blocks are expanded together, one in each 128-bit lane.
+
+const K: [u32; 4] = [0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xCA62C1D6];
+
+type State = [u32; 5];
+type RoundStates = [__m128i; 20];
+
+#[target_feature(enable = "sse2,ssse3")]
+unsafe fn sha1_compress_ssse3(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
+    for block in blocks {
+        sha1_compress_block_ssse3(state, block);
     }
 }
+
+#[target_feature(enable = "avx2")]
+unsafe fn sha1_compress_avx2(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
+    let mut start_block = 0;
+
+    if blocks.len() & 0b1 != 0 {
+        sha1_compress_block_ssse3(state, &blocks[0]);
+        start_block += 1;
+    }
+
+    let mut x = [_mm256_setzero_si256(); 4];
+    let mut ms: RoundStates = [_mm_setzero_si128(); 20];
+    let mut t2: RoundStates = [_mm_setzero_si128(); 20];
+
+    for i in (start_block..blocks.len()).step_by(2) {
+        load_data_avx2(&mut x, &mut ms, &mut t2, blocks.as_ptr().add(i) as *const _);
+
+        for j in 4..20 {
+            let k = _mm256_set1_epi32(K[j / 5] as i32);
+            let y = sha1_update_x_avx2(&mut x, k);
+
+            ms[j] = _mm256_extracti128_si256(y, 0);
+            t2[j] = _mm256_extracti128_si256(y, 1);
+        }
+
+        rounds_0_79(state, &ms);
+        rounds_0_79(state, &t2);
+    }
+}
+
+#[inline(always)]
+unsafe fn sha1_compress_block_ssse3(state: &mut State, block: &[u8; 64]) {
+    let mut x = [_mm_setzero_si128(); 4];
+    let mut ms: RoundStates = [_mm_setzero_si128(); 20];
+
+    load_data_ssse3(&mut x, &mut ms, block.as_ptr() as *const _);
+
+    for (j, wk) in ms.iter_mut().enumerate().skip(4) {
+        let k = _mm_set1_epi32(K[j / 5] as i32);
+        *wk = sha1_update_x_ssse3(&mut x, k);
+    }
+
+    rounds_0_79(state, &ms);
+}
+
+#[inline(always)]
+unsafe fn load_data_ssse3(x: &mut [__m128i; 4], ms: &mut RoundStates, data: *const __m128i) {
+    #[allow(non_snake_case)]
+    let MASK = _mm_setr_epi32(0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f);
+    let k = _mm_set1_epi32(K[0] as i32);
+
+    macro_rules! unrolled_iterations {
+        ($($i:literal),*) => {$(
+            x[$i] = _mm_loadu_si128(data.add($i) as *const _);
+            x[$i] = _mm_shuffle_epi8(x[$i], MASK);
+            ms[$i] = _mm_add_epi32(x[$i], k);
+        )*};
+    }
+
+    unrolled_iterations!(0, 1, 2, 3);
+}
+
+#[inline(always)]
+unsafe fn load_data_avx2(
+    x: &mut [__m256i; 4],
+    ms: &mut RoundStates,
+    t2: &mut RoundStates,
+    data: *const __m128i,
+) {
+    #[allow(non_snake_case)]
+    let MASK = _mm256_set_epi64x(
+        0x0c0d_0e0f_0809_0a0b_i64,
+        0x0405_0607_0001_0203_i64,
+        0x0c0d_0e0f_0809_0a0b_i64,
+        0x0405_0607_0001_0203_i64,
+    );
+    let k = _mm256_set1_epi32(K[0] as i32);
+
+    macro_rules! unrolled_iterations {
+        ($($i:literal),*) => {$(
+            x[$i] = _mm256_insertf128_si256(x[$i], _mm_loadu_si128(data.add(4 + $i) as *const _), 1);
+            x[$i] = _mm256_insertf128_si256(x[$i], _mm_loadu_si128(data.add($i) as *const _), 0);
+
+            x[$i] = _mm256_shuffle_epi8(x[$i], MASK);
+
+            let y = _mm256_add_epi32(x[$i], k);
+
+            ms[$i] = _mm256_extracti128_si256(y, 0);
+            t2[$i] = _mm256_extracti128_si256(y, 1);
+        )*};
+    }
+
+    unrolled_iterations!(0, 1, 2, 3);
+}
+
+#[inline(always)]
+fn rounds_0_79(state: &mut State, rs: &RoundStates) {
+    macro_rules! bool3ary_202 {
+        ($a:expr, $b:expr, $c:expr) => {
+            $c ^ ($a & ($b ^ $c))
+        };
+    } // Choose, MD5F, SHA1C
+    macro_rules! bool3ary_150 {
+        ($a:expr, $b:expr, $c:expr) => {
+            $a ^ $b ^ $c
+        };
+    } // Parity, XOR, MD5H, SHA1P
+    macro_rules! bool3ary_232 {
+        ($a:expr, $b:expr, $c:expr) => {
+            ($a & $b) ^ ($a & $c) ^ ($b & $c)
+        };
+    } // Majority, SHA1M
+
+    macro_rules! rounds20 {
+        ($s:ident, $wk:ident, $range:expr, $f:ident) => {
+            for &wk in $wk[$range].iter() {
+                let [a, b, c, d, e] = $s;
+                let t = a
+                    .rotate_left(5)
+                    .wrapping_add($f!(b, c, d))
+                    .wrapping_add(e)
+                    .wrapping_add(wk);
+                $s = [t, a, b.rotate_left(30), c, d];
+            }
+        };
+    }
+
+    // SAFETY: `RoundStates` and `[u32; 80]` have exactly the same size
+    let wk = unsafe { &*(rs as *const RoundStates as *const [u32; 80]) };
+    let mut s = *state;
+
+    rounds20!(s, wk, 0..20, bool3ary_202);
+    rounds20!(s, wk, 20..40, bool3ary_150);
+    rounds20!(s, wk, 40..60, bool3ary_232);
+    rounds20!(s, wk, 60..80, bool3ary_150);
+
+    for (dst, src) in state.iter_mut().zip(s.iter()) {
+        *dst = dst.wrapping_add(*src);
+    }
+}
+
+macro_rules! fn_sha1_update_x {
+    ($name:ident, $ty:ident, {
+        ADD32 = $ADD32:ident,
+        ALIGNR8 = $ALIGNR8:ident,
+        SRL32 = $SRL32:ident,
+        SLL32 = $SLL32:ident,
+        SRL128 = $SRL128:ident,
+        SLL128 = $SLL128:ident,
+        XOR = $XOR:ident,
+    }) => {
+        #[inline(always)]
+        unsafe fn $name(x: &mut [$ty; 4], k: $ty) -> $ty {
+            macro_rules! rotl {
+                ($v:expr, $n:literal) => {
+                    $XOR($SLL32($v, $n), $SRL32($v, 32 - $n))
+                };
+            }
+
+            // w[t-14..t-11]
+            let w2 = $ALIGNR8(x[1], x[0], 8);
+            // w[t-3..t-1] and zero in place of w[t]
+            let w13 = $SRL128(x[3], 4);
+            // w[t-16..t-13] ^ w[t-14..t-11] ^ w[t-8..t-5] ^ w[t-3..t]
+            let t = $XOR($XOR(x[0], w2), $XOR(x[2], w13));
+            // w[t..t+3] without the w[t] contribution to w[t+3]
+            let mut w = rotl!(t, 1);
+            // rotl(w[t], 1) == rotl(t[0], 2)
+            let t0 = $SLL128(t, 12);
+            w = $XOR(w, rotl!(t0, 2));
+
+            // rotate
+            x[0] = x[1];
+            x[1] = x[2];
+            x[2] = x[3];
+            x[3] = w;
+
+            $ADD32(w, k)
+        }
+    };
+}
+
+fn_sha1_update_x!(sha1_update_x_ssse3, __m128i, {
+        ADD32 = _mm_add_epi32,
+        ALIGNR8 = _mm_alignr_epi8,
+        SRL32 = _mm_srli_epi32,
+        SLL32 = _mm_slli_epi32,
+        SRL128 = _mm_srli_si128,
+        SLL128 = _mm_slli_si128,
+        XOR = _mm_xor_si128,
+});
+
+fn_sha1_update_x!(sha1_update_x_avx2, __m256i, {
+        ADD32 = _mm256_add_epi32,
+        ALIGNR8 = _mm256_alignr_epi8,
+        SRL32 = _mm256_srli_epi32,
+        SLL32 = _mm256_slli_epi32,
+        SRL128 = _mm256_srli_si256,
+        SLL128 = _mm256_slli_si256,
+        XOR = _mm256_xor_si256,
+});
@@ -0,0 +1,275 @@
+//! SHA-1 with collision detection (SHA-1DC).
+//!
+//! Counter-cryptanalysis as described in [Counter-cryptanalysis][1] by
+//! Marc Stevens and implemented in the [`sha1collisiondetection`][2] library
+//! used by Git. During compression of every block the hasher checks whether
+//! the block is a near-collision block of a known differential attack and
+//! records it if one is found. Inputs without such blocks produce regular
+//! SHA-1 digests.
+//!
+//! [1]: https://marc-stevens.nl/research/papers/C13-S.pdf
+//! [2]: https://github.com/cr-marcstevens/sha1collisiondetection
+
+use crate::STATE_LEN;
+use core::{fmt, slice::from_ref};
+#[cfg(feature = "oid")]
+use digest::const_oid::{AssociatedOid, ObjectIdentifier};
+use digest::{
+    block_buffer::Eager,
+    core_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, FixedOutputCore,
+        OutputSizeUser, UpdateCore,
+    },
+    typenum::{Unsigned, U20, U64},
+    FixedOutput, FixedOutputReset, HashMarker, Output, Reset, Update,
+};
+
+mod compress;
+mod ubc_check;
+
+const BLOCK_SIZE: usize = <Sha1DcCore as BlockSizeUser>::BlockSize::USIZE;
+
+/// Core SHA-1 hasher state with collision detection.
+#[derive(Clone)]
+pub struct Sha1DcCore {
+    h: [u32; STATE_LEN],
+    block_len: u64,
+    safe_hash: bool,
+    detected: bool,
+}
+
+impl Sha1DcCore {
+    /// Create new hasher state with the "safe hash" mode enabled or disabled.
+    ///
+    /// In the safe hash mode blocks detected as part of a collision attack
+    /// are processed with extra compressions, so a colliding message gets a
+    /// digest different from its counterpart. Without it the regular SHA-1
+    /// digest is produced in all cases.
+    #[inline]
+    pub fn new_with_safe_hash(safe_hash: bool) -> Self {
+        Self {
+            safe_hash,
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if a collision attack was detected in the data
+    /// processed so far.
+    #[inline]
+    pub fn collision_detected(&self) -> bool {
+        self.detected
+    }
+
+    #[inline]
+    fn compress(&mut self, blocks: &[Block<Self>]) {
+        // SAFETY: GenericArray<u8, U64> and [u8; 64] have
+        // exactly the same memory layout
+        let blocks: &[[u8; BLOCK_SIZE]] =
+            unsafe { &*(blocks as *const _ as *const [[u8; BLOCK_SIZE]]) };
+        self.detected |= compress::compress(&mut self.h, blocks, self.safe_hash);
+    }
+}
+
+impl HashMarker for Sha1DcCore {}
+
+impl BlockSizeUser for Sha1DcCore {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for Sha1DcCore {
+    type BufferKind = Eager;
+}
+
+impl OutputSizeUser for Sha1DcCore {
+    type OutputSize = U20;
+}
+
+impl UpdateCore for Sha1DcCore {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.block_len += blocks.len() as u64;
+        self.compress(blocks);
+    }
+}
+
+impl FixedOutputCore for Sha1DcCore {
+    #[inline]
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let bs = Self::BlockSize::U64;
+        let bit_len = 8 * (buffer.get_pos() as u64 + bs * self.block_len);
+
+        buffer.len64_padding_be(bit_len, |b| self.compress(from_ref(b)));
+        for (chunk, v) in out.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&v.to_be_bytes());
+        }
+    }
+}
+
+impl Default for Sha1DcCore {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            h: [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0],
+            block_len: 0,
+            safe_hash: false,
+            detected: false,
+        }
+    }
+}
+
+impl Reset for Sha1DcCore {
+    #[inline]
+    fn reset(&mut self) {
+        *self = Self::new_with_safe_hash(self.safe_hash);
+    }
+}
+
+impl AlgorithmName for Sha1DcCore {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sha1Dc")
+    }
+}
+
+impl fmt::Debug for Sha1DcCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sha1DcCore { ... }")
+    }
+}
+
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+impl AssociatedOid for Sha1DcCore {
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+}
+
+/// SHA-1 hasher state with collision detection.
+///
+/// Implements the [`Digest`][crate::Digest] API, in which case detected
+/// collision attacks are ignored, and provides
+/// [`finalize_checked`][Sha1Dc::finalize_checked] to reject them.
+#[derive(Clone, Default)]
+pub struct Sha1Dc {
+    core: Sha1DcCore,
+    buffer: Buffer<Sha1DcCore>,
+}
+
+impl Sha1Dc {
+    /// Create new hasher with the "safe hash" mode enabled or disabled.
+    ///
+    /// See [`Sha1DcCore::new_with_safe_hash`] for more information.
+    #[inline]
+    pub fn new_with_safe_hash(safe_hash: bool) -> Self {
+        Self {
+            core: Sha1DcCore::new_with_safe_hash(safe_hash),
+            buffer: Default::default(),
+        }
+    }
+
+    /// Returns `true` if a collision attack was detected in the full blocks
+    /// processed so far.
+    #[inline]
+    pub fn collision_detected(&self) -> bool {
+        self.core.collision_detected()
+    }
+
+    /// Retrieve result and consume hasher instance, returning an error if
+    /// a collision attack was detected in the processed data.
+    pub fn finalize_checked(mut self) -> Result<Output<Self>, CollisionDetected> {
+        let mut out = Output::<Self>::default();
+        self.core.finalize_fixed_core(&mut self.buffer, &mut out);
+        if self.core.collision_detected() {
+            Err(CollisionDetected { hash: out })
+        } else {
+            Ok(out)
+        }
+    }
+}
+
+impl HashMarker for Sha1Dc {}
+
+impl OutputSizeUser for Sha1Dc {
+    type OutputSize = U20;
+}
+
+impl Update for Sha1Dc {
+    #[inline]
+    fn update(&mut self, input: &[u8]) {
+        let Self { core, buffer } = self;
+        buffer.digest_blocks(input, |blocks| core.update_blocks(blocks));
+    }
+}
+
+impl FixedOutput for Sha1Dc {
+    #[inline]
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        self.core.finalize_fixed_core(&mut self.buffer, out);
+    }
+}
+
+impl Reset for Sha1Dc {
+    #[inline]
+    fn reset(&mut self) {
+        self.core.reset();
+        self.buffer.reset();
+    }
+}
+
+impl FixedOutputReset for Sha1Dc {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.core.finalize_fixed_core(&mut self.buffer, out);
+        self.reset();
+    }
+}
+
+impl fmt::Debug for Sha1Dc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sha1Dc { ... }")
+    }
+}
+
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+impl AssociatedOid for Sha1Dc {
+    const OID: ObjectIdentifier = Sha1DcCore::OID;
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for Sha1Dc {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Update::update(self, buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Error returned by [`Sha1Dc::finalize_checked`] if a collision attack was
+/// detected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollisionDetected {
+    hash: Output<Sha1Dc>,
+}
+
+impl CollisionDetected {
+    /// Digest computed for the rejected input.
+    ///
+    /// It is the regular SHA-1 digest, or the safe hash if the hasher was
+    /// created with the "safe hash" mode enabled.
+    pub fn hash(&self) -> &Output<Sha1Dc> {
+        &self.hash
+    }
+}
+
+impl fmt::Display for CollisionDetected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SHA-1 collision attack detected")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CollisionDetected {}
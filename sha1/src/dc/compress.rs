@@ -0,0 +1,154 @@
+//! SHA-1 compression function with collision detection
+//!
+//! Based on [`sha1.c`] of the reference implementation.
+//!
+//! [`sha1.c`]: https://github.com/cr-marcstevens/sha1collisiondetection/blob/master/lib/sha1.c
+
+#![allow(clippy::many_single_char_names)]
+use super::ubc_check::{ubc_check, Testt, SHA1_DVS};
+use core::convert::TryInto;
+
+const K: [u32; 4] = [0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xCA62C1D6];
+
+type State = [u32; 5];
+type Schedule = [u32; 80];
+
+#[inline(always)]
+fn f(i: usize, b: u32, c: u32, d: u32) -> u32 {
+    match i / 20 {
+        0 => d ^ (b & (c ^ d)),
+        2 => (b & c) | (d & (b | c)),
+        _ => b ^ c ^ d,
+    }
+}
+
+#[inline(always)]
+fn step(s: &mut State, w: &Schedule, i: usize) {
+    let [a, b, c, d, e] = *s;
+    let t = a
+        .rotate_left(5)
+        .wrapping_add(f(i, b, c, d))
+        .wrapping_add(e)
+        .wrapping_add(K[i / 20])
+        .wrapping_add(w[i]);
+    *s = [t, a, b.rotate_left(30), c, d];
+}
+
+/// Inverse of `step`: computes the state preceding step `i`.
+#[inline(always)]
+fn step_back(s: &mut State, w: &Schedule, i: usize) {
+    let [t, a, b, c, d] = *s;
+    let b = b.rotate_right(30);
+    let e = t.wrapping_sub(
+        a.rotate_left(5)
+            .wrapping_add(f(i, b, c, d))
+            .wrapping_add(K[i / 20])
+            .wrapping_add(w[i]),
+    );
+    *s = [a, b, c, d, e];
+}
+
+#[inline(always)]
+fn add_state(dst: &mut State, src: &State) {
+    for (d, s) in dst.iter_mut().zip(src.iter()) {
+        *d = d.wrapping_add(*s);
+    }
+}
+
+fn expand(block: &[u8; 64]) -> Schedule {
+    let mut w = [0u32; 80];
+    for (o, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *o = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    for i in 16..80 {
+        w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+    }
+    w
+}
+
+/// Plain compression of an already expanded message block.
+fn compress_w(ihv: &mut State, w: &Schedule) {
+    let mut s = *ihv;
+    for i in 0..80 {
+        step(&mut s, w, i);
+    }
+    add_state(ihv, &s);
+}
+
+/// Compresses an expanded message block and returns the intermediate
+/// states before steps 58 and 65, which are used for recompression.
+fn compress_states(ihv: &mut State, w: &Schedule) -> (State, State) {
+    let mut s = *ihv;
+    for i in 0..58 {
+        step(&mut s, w, i);
+    }
+    let state58 = s;
+    for i in 58..65 {
+        step(&mut s, w, i);
+    }
+    let state65 = s;
+    for i in 65..80 {
+        step(&mut s, w, i);
+    }
+    add_state(ihv, &s);
+    (state58, state65)
+}
+
+/// Computes the output chaining value of a block which has the message
+/// schedule `w` and passes through `state` before step `t`.
+fn recompress(t: usize, w: &Schedule, state: &State) -> State {
+    let mut ihv_in = *state;
+    for i in (0..t).rev() {
+        step_back(&mut ihv_in, w, i);
+    }
+    let mut ihv_out = *state;
+    for i in t..80 {
+        step(&mut ihv_out, w, i);
+    }
+    add_state(&mut ihv_out, &ihv_in);
+    ihv_out
+}
+
+/// Processes `blocks`, checking each of them for a near-collision attack.
+///
+/// Returns `true` if an attack was detected in at least one block. In
+/// the safe hash mode the chaining value of such blocks is additionally
+/// modified, so colliding messages produce different digests.
+pub(super) fn compress(state: &mut [u32; 5], blocks: &[[u8; 64]], safe_hash: bool) -> bool {
+    let mut detected = false;
+    for block in blocks {
+        let w1 = expand(block);
+        let (state58, state65) = compress_states(state, &w1);
+
+        let mask = ubc_check(&w1);
+        if mask == 0 {
+            continue;
+        }
+
+        for dv in SHA1_DVS.iter() {
+            if mask & (1 << dv.maskb) == 0 {
+                continue;
+            }
+
+            let mut w2 = [0u32; 80];
+            for ((m2, m1), dm) in w2.iter_mut().zip(w1.iter()).zip(dv.dm.iter()) {
+                *m2 = m1 ^ dm;
+            }
+
+            let ihv_out = match dv.testt {
+                Testt::T58 => recompress(58, &w2, &state58),
+                Testt::T65 => recompress(65, &w2, &state65),
+            };
+
+            if ihv_out == *state {
+                detected = true;
+                if safe_hash {
+                    compress_w(state, &w1);
+                    compress_w(state, &w1);
+                }
+                break;
+            }
+        }
+    }
+    detected
+}
@@ -29,6 +29,24 @@
 //!
 //! Also see [RustCrypto/hashes][3] readme.
 //!
+//! # Collision detection
+//!
+//! [`Sha1Dc`] is a SHA-1 hasher with the counter-cryptanalysis used by Git,
+//! which detects messages crafted with the known collision attacks, such as
+//! [SHAttered][4] and [SHAmbles][2]:
+//!
+//! ```rust
+//! use hex_literal::hex;
+//! use sha1::{Digest, Sha1Dc};
+//!
+//! let mut hasher = Sha1Dc::new();
+//! hasher.update(b"hello world");
+//!
+//! // `finalize_checked` returns an error if a collision attack was detected
+//! let result = hasher.finalize_checked().unwrap();
+//! assert_eq!(result[..], hex!("2aae6c35c94fcfb415dbe95f408b9ce91ee846ed"));
+//! ```
+//!
 //! # Note for users of `sha1 v0.6`
 //!
 //! This crate has been transferred to the RustCrypto organization and uses
@@ -38,6 +56,7 @@
 //! [1]: https://en.wikipedia.org/wiki/SHA-1
 //! [2]: https://sha-mbles.github.io/
 //! [3]: https://github.com/RustCrypto/hashes
+//! [4]: https://shattered.io/
 //! [`sha1_smol`]: https://github.com/mitsuhiko/sha1-smol/
 
 #![no_std]
@@ -48,6 +67,9 @@
 )]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use digest::{self, Digest};
 
 use core::{fmt, slice::from_ref};
@@ -64,12 +86,20 @@ use digest::{
 };
 
 mod compress;
+mod dc;
+
+pub use dc::{CollisionDetected, Sha1Dc, Sha1DcCore};
 
 #[cfg(feature = "compress")]
 pub use compress::compress;
 #[cfg(not(feature = "compress"))]
 use compress::compress;
 
+/// Compression function backends compiled for the target, exposed for the
+/// equivalence tests in `tests/`. Not part of the public API.
+#[doc(hidden)]
+pub use compress::backends;
+
 const STATE_LEN: usize = 5;
 
 /// Core SHA-1 hasher state.
@@ -0,0 +1,71 @@
+//! Equivalence tests of the compression function backends against the
+//! software implementation. Backends not supported by the CPU running the
+//! tests are skipped with a message.
+#![cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+
+use sha1::backends;
+
+type Compress = fn(&mut [u32; 5], &[[u8; 64]]) -> bool;
+
+fn rand_bytes(seed: &mut u64, buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        // xorshift64
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        *b = *seed as u8;
+    }
+}
+
+fn check(name: &str, f: Compress) {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    // odd and even block counts, the AVX2 backend processes blocks in pairs
+    for n in 0..10 {
+        let mut state = [0u32; 5];
+        let mut blocks = vec![[0u8; 64]; n];
+        for v in state.iter_mut() {
+            let mut buf = [0u8; 4];
+            rand_bytes(&mut seed, &mut buf);
+            *v = u32::from_le_bytes(buf);
+        }
+        for block in blocks.iter_mut() {
+            rand_bytes(&mut seed, block);
+        }
+
+        let mut expected = state;
+        backends::soft(&mut expected, &blocks);
+        let mut res = state;
+        if !f(&mut res, &blocks) {
+            eprintln!("{} is not supported by the CPU, skipping", name);
+            return;
+        }
+        assert_eq!(res, expected, "{} with {} blocks", name, n);
+    }
+}
+
+#[test]
+fn shani_matches_soft() {
+    check("SHA-NI", backends::shani);
+}
+
+#[test]
+fn avx2_matches_soft() {
+    check("AVX2", backends::avx2);
+}
+
+#[test]
+fn ssse3_matches_soft() {
+    check("SSSE3", backends::ssse3);
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+#[test]
+fn asm_matches_soft() {
+    check("asm", |state, blocks| {
+        backends::asm(state, blocks);
+        true
+    });
+}
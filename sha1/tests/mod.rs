@@ -1,6 +1,6 @@
 use digest::dev::{feed_rand_16mib, fixed_reset_test};
 use hex_literal::hex;
-use sha1::{Digest, Sha1};
+use sha1::{Digest, Sha1, Sha1Dc};
 
 digest::new_test!(sha1_main, "sha1", Sha1, fixed_reset_test);
 
@@ -13,3 +13,61 @@ fn sha1_rand() {
         hex!("7e565a25a8b123e9881addbcedcd927b23377a78")[..]
     );
 }
+
+digest::new_test!(sha1dc_main, "sha1", Sha1Dc, fixed_reset_test);
+
+#[test]
+fn sha1dc_rand() {
+    let mut h = Sha1Dc::new();
+    feed_rand_16mib(&mut h);
+    assert!(!h.collision_detected());
+    assert_eq!(
+        h.finalize_checked().unwrap()[..],
+        hex!("7e565a25a8b123e9881addbcedcd927b23377a78")[..]
+    );
+}
+
+fn sha1dc_collision_test(input: &[u8], hash: [u8; 20], safe_hash: [u8; 20]) {
+    assert_eq!(Sha1::digest(input)[..], hash[..]);
+    assert_eq!(Sha1Dc::digest(input)[..], hash[..]);
+
+    let mut h = Sha1Dc::new();
+    h.update(input);
+    assert!(h.collision_detected());
+    let err = h.finalize_checked().unwrap_err();
+    assert_eq!(err.hash()[..], hash[..]);
+
+    let mut h = Sha1Dc::new_with_safe_hash(true);
+    h.update(input);
+    let err = h.finalize_checked().unwrap_err();
+    assert_eq!(err.hash()[..], safe_hash[..]);
+}
+
+// First 320 bytes of the SHAttered PDFs, which contain the colliding blocks
+#[test]
+fn sha1dc_shattered() {
+    sha1dc_collision_test(
+        include_bytes!("data/shattered-1.bin"),
+        hex!("f92d74e3874587aaf443d1db961d4e26dde13e9c"),
+        hex!("7117b3cb9225aaf0d8ef1a40e493957b0bf8693d"),
+    );
+    sha1dc_collision_test(
+        include_bytes!("data/shattered-2.bin"),
+        hex!("f92d74e3874587aaf443d1db961d4e26dde13e9c"),
+        hex!("29f38ae9fd98e2931120fa0bf213e024250d3f6a"),
+    );
+}
+
+#[test]
+fn sha1dc_shambles() {
+    sha1dc_collision_test(
+        include_bytes!("data/sha-mbles-1.bin"),
+        hex!("8ac60ba76f1999a1ab70223f225aefdc78d4ddc0"),
+        hex!("4f3d9be4a472c4dae83c6314aa6c36a064c1fd14"),
+    );
+    sha1dc_collision_test(
+        include_bytes!("data/sha-mbles-2.bin"),
+        hex!("8ac60ba76f1999a1ab70223f225aefdc78d4ddc0"),
+        hex!("9ed5d77a4f48be1dbf3e9e15650733eb850897f2"),
+    );
+}
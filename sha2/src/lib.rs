@@ -75,6 +75,14 @@ pub use sha512::compress512;
 
 pub use core_api::{Sha256VarCore, Sha512VarCore};
 
+/// Compression function backends compiled for the target, exposed for the
+/// equivalence tests in `tests/`. Not part of the public API.
+#[doc(hidden)]
+pub mod backends {
+    pub use crate::sha256::backends as sha256;
+    pub use crate::sha512::backends as sha512;
+}
+
 impl_oid_carrier!(OidSha256, "2.16.840.1.101.3.4.2.1");
 impl_oid_carrier!(OidSha384, "2.16.840.1.101.3.4.2.2");
 impl_oid_carrier!(OidSha512, "2.16.840.1.101.3.4.2.3");
@@ -5,12 +5,9 @@ cfg_if::cfg_if! {
         mod soft;
         use soft::compress;
     } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
-        #[cfg(not(feature = "asm"))]
         mod soft;
-        #[cfg(feature = "asm")]
-        mod soft {
-            pub(crate) use sha2_asm::compress256 as compress;
-        }
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        mod asm;
         mod x86;
         use x86::compress;
     } else if #[cfg(all(feature = "asm", target_arch = "aarch64"))] {
@@ -35,3 +32,15 @@ pub fn compress256(state: &mut [u32; 8], blocks: &[GenericArray<u8, U64>]) {
     let blocks = unsafe { core::slice::from_raw_parts(p, blocks.len()) };
     compress(state, blocks)
 }
+
+/// SHA-256 backends compiled for the target, see [`crate::backends`].
+pub mod backends {
+    #[cfg(all(feature = "asm", target_arch = "x86_64", not(feature = "force-soft")))]
+    pub use super::asm::compress as asm;
+    pub use super::soft::compress as soft;
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft")
+    ))]
+    pub use super::x86::{avx2, shani, ssse3};
+}
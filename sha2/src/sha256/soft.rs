@@ -203,6 +203,7 @@ fn sha256_digest_block_u32(state: &mut [u32; 8], block: &[u32; 16]) {
     state[7] = state[7].wrapping_add(h);
 }
 
+/// Compress `blocks` into `state` using only scalar operations.
 pub fn compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
     let mut block_u32 = [0u32; BLOCK_LEN];
     // since LLVM can't properly use aliasing yet it will make
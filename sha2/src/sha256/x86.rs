@@ -7,6 +7,8 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+use crate::consts::K32;
+
 unsafe fn schedule(v0: __m128i, v1: __m128i, v2: __m128i, v3: __m128i) -> __m128i {
     let t1 = _mm_sha256msg1_epu32(v0, v1);
     let t2 = _mm_alignr_epi8(v3, v2, 4);
@@ -98,6 +100,8 @@ unsafe fn digest_blocks(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
 }
 
 cpufeatures::new!(shani_cpuid, "sha", "sse2", "ssse3", "sse4.1");
+cpufeatures::new!(avx2_cpuid, "avx2");
+cpufeatures::new!(ssse3_cpuid, "sse2", "ssse3");
 
 pub fn compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
     // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
@@ -106,7 +110,303 @@ pub fn compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
         unsafe {
             digest_blocks(state, blocks);
         }
+    } else if avx2_cpuid::get() {
+        unsafe {
+            sha256_compress_avx2(state, blocks);
+        }
+    } else if ssse3_cpuid::get() {
+        unsafe {
+            sha256_compress_ssse3(state, blocks);
+        }
     } else {
-        super::soft::compress(state, blocks);
+        compress_scalar(state, blocks);
+    }
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+use super::asm::compress as compress_scalar;
+#[cfg(not(feature = "asm"))]
+use super::soft::compress as compress_scalar;
+#[cfg(all(feature = "asm", target_arch = "x86"))]
+use sha2_asm::compress256 as compress_scalar;
+
+macro_rules! backend {
+    ($name:ident, $cpuid:ident, $f:ident) => {
+        /// Run the backend if the CPU supports it, returns `false` otherwise.
+        pub fn $name(state: &mut [u32; 8], blocks: &[[u8; 64]]) -> bool {
+            let supported = $cpuid::get();
+            if supported {
+                unsafe { $f(state, blocks) }
+            }
+            supported
+        }
+    };
+}
+
+backend!(shani, shani_cpuid, digest_blocks);
+backend!(avx2, avx2_cpuid, sha256_compress_avx2);
+backend!(ssse3, ssse3_cpuid, sha256_compress_ssse3);
+
+// The backends below are used on CPUs without the SHA extensions. They
+// compute the message schedule four words at a time with SIMD instructions
+// and run the rounds themselves on general purpose registers. The AVX2
+// backend expands the schedules of two consecutive blocks at once, one per
+// 128-bit lane.
+
+#[target_feature(enable = "sse2,ssse3")]
+unsafe fn sha256_compress_ssse3(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    for block in blocks {
+        sha256_compress_block_ssse3(state, block);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn sha256_compress_avx2(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    let mut start_block = 0;
+
+    if blocks.len() & 0b1 != 0 {
+        sha256_compress_block_ssse3(state, &blocks[0]);
+        start_block += 1;
+    }
+
+    let mut x = [_mm256_setzero_si256(); 4];
+    let mut ms: RoundStates = [_mm_setzero_si128(); 16];
+    let mut t2: RoundStates = [_mm_setzero_si128(); 16];
+
+    for i in (start_block..blocks.len()).step_by(2) {
+        load_data_avx2(&mut x, &mut ms, &mut t2, blocks.as_ptr().add(i) as *const _);
+
+        for j in 4..16 {
+            let t = _mm_loadu_si128(K32.as_ptr().add(4 * j) as *const _);
+            let y = sha256_update_x_avx2(&mut x, _mm256_set_m128i(t, t));
+
+            ms[j] = _mm256_extracti128_si256(y, 0);
+            t2[j] = _mm256_extracti128_si256(y, 1);
+        }
+
+        // First block
+        let mut current_state = *state;
+        rounds_0_63(&mut current_state, &ms);
+        accumulate_state(state, &current_state);
+
+        // Second block
+        current_state = *state;
+        rounds_0_63(&mut current_state, &t2);
+        accumulate_state(state, &current_state);
+    }
+}
+
+#[inline(always)]
+unsafe fn sha256_compress_block_ssse3(state: &mut State, block: &[u8; 64]) {
+    let mut x = [_mm_setzero_si128(); 4];
+    let mut ms: RoundStates = [_mm_setzero_si128(); 16];
+
+    load_data_ssse3(&mut x, &mut ms, block.as_ptr() as *const _);
+
+    for (j, wk) in ms.iter_mut().enumerate().skip(4) {
+        let k32 = _mm_loadu_si128(K32.as_ptr().add(4 * j) as *const _);
+        *wk = sha256_update_x_ssse3(&mut x, k32);
+    }
+
+    let mut current_state = *state;
+    rounds_0_63(&mut current_state, &ms);
+    accumulate_state(state, &current_state);
+}
+
+#[inline(always)]
+unsafe fn load_data_ssse3(x: &mut [__m128i; 4], ms: &mut RoundStates, data: *const __m128i) {
+    #[allow(non_snake_case)]
+    let MASK = _mm_setr_epi32(0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f);
+
+    macro_rules! unrolled_iterations {
+        ($($i:literal),*) => {$(
+            x[$i] = _mm_loadu_si128(data.add($i) as *const _);
+            x[$i] = _mm_shuffle_epi8(x[$i], MASK);
+
+            let k32 = _mm_loadu_si128(K32.as_ptr().add(4 * $i) as *const _);
+            ms[$i] = _mm_add_epi32(x[$i], k32);
+        )*};
     }
+
+    unrolled_iterations!(0, 1, 2, 3);
+}
+
+#[inline(always)]
+unsafe fn load_data_avx2(
+    x: &mut [__m256i; 4],
+    ms: &mut RoundStates,
+    t2: &mut RoundStates,
+    data: *const __m128i,
+) {
+    #[allow(non_snake_case)]
+    let MASK = _mm256_set_epi64x(
+        0x0c0d_0e0f_0809_0a0b_i64,
+        0x0405_0607_0001_0203_i64,
+        0x0c0d_0e0f_0809_0a0b_i64,
+        0x0405_0607_0001_0203_i64,
+    );
+
+    macro_rules! unrolled_iterations {
+        ($($i:literal),*) => {$(
+            x[$i] = _mm256_insertf128_si256(x[$i], _mm_loadu_si128(data.add(4 + $i) as *const _), 1);
+            x[$i] = _mm256_insertf128_si256(x[$i], _mm_loadu_si128(data.add($i) as *const _), 0);
+
+            x[$i] = _mm256_shuffle_epi8(x[$i], MASK);
+
+            let t = _mm_loadu_si128(K32.as_ptr().add(4 * $i) as *const _);
+            let y = _mm256_add_epi32(x[$i], _mm256_set_m128i(t, t));
+
+            ms[$i] = _mm256_extracti128_si256(y, 0);
+            t2[$i] = _mm256_extracti128_si256(y, 1);
+        )*};
+    }
+
+    unrolled_iterations!(0, 1, 2, 3);
 }
+
+#[inline(always)]
+fn rounds_0_63(current_state: &mut State, rs: &RoundStates) {
+    for wk in cast_rs(rs).iter() {
+        sha_round(current_state, *wk);
+    }
+}
+
+#[inline(always)]
+fn sha_round(s: &mut State, x: u32) {
+    macro_rules! big_sigma0 {
+        ($a:expr) => {
+            $a.rotate_right(2) ^ $a.rotate_right(13) ^ $a.rotate_right(22)
+        };
+    }
+    macro_rules! big_sigma1 {
+        ($a:expr) => {
+            $a.rotate_right(6) ^ $a.rotate_right(11) ^ $a.rotate_right(25)
+        };
+    }
+    macro_rules! bool3ary_202 {
+        ($a:expr, $b:expr, $c:expr) => {
+            $c ^ ($a & ($b ^ $c))
+        };
+    } // Choose, MD5F, SHA1C
+    macro_rules! bool3ary_232 {
+        ($a:expr, $b:expr, $c:expr) => {
+            ($a & $b) ^ ($a & $c) ^ ($b & $c)
+        };
+    } // Majority, SHA1M
+
+    macro_rules! rotate_state {
+        ($s:ident) => {{
+            let tmp = $s[7];
+            $s[7] = $s[6];
+            $s[6] = $s[5];
+            $s[5] = $s[4];
+            $s[4] = $s[3];
+            $s[3] = $s[2];
+            $s[2] = $s[1];
+            $s[1] = $s[0];
+            $s[0] = tmp;
+        }};
+    }
+
+    let t = x
+        .wrapping_add(s[7])
+        .wrapping_add(big_sigma1!(s[4]))
+        .wrapping_add(bool3ary_202!(s[4], s[5], s[6]));
+
+    s[7] = t
+        .wrapping_add(big_sigma0!(s[0]))
+        .wrapping_add(bool3ary_232!(s[0], s[1], s[2]));
+    s[3] = s[3].wrapping_add(t);
+
+    rotate_state!(s);
+}
+
+#[inline(always)]
+fn accumulate_state(dst: &mut State, src: &State) {
+    for i in 0..SHA256_HASH_WORDS_NUM {
+        dst[i] = dst[i].wrapping_add(src[i]);
+    }
+}
+
+macro_rules! fn_sha256_update_x {
+    ($name:ident, $ty:ident, {
+        ADD32 = $ADD32:ident,
+        ALIGNR8 = $ALIGNR8:ident,
+        SRL32 = $SRL32:ident,
+        SLL32 = $SLL32:ident,
+        XOR = $XOR:ident,
+        SHUF32 = $SHUF32:ident,
+        UNPACKLO64 = $UNPACKLO64:ident,
+        ZERO = $ZERO:ident,
+    }) => {
+        #[inline(always)]
+        unsafe fn $name(x: &mut [$ty; 4], k32: $ty) -> $ty {
+            macro_rules! rotr {
+                ($v:expr, $n:literal) => {
+                    $XOR($SRL32($v, $n), $SLL32($v, 32 - $n))
+                };
+            }
+
+            // w[t-15..t-12]
+            let w1 = $ALIGNR8(x[1], x[0], 4);
+            // w[t-7..t-4]
+            let w9 = $ALIGNR8(x[3], x[2], 4);
+            // sigma0(w[t-15..t-12])
+            let s0 = $XOR($XOR(rotr!(w1, 7), rotr!(w1, 18)), $SRL32(w1, 3));
+            // w[t-16..t-13] + w[t-7..t-4] + sigma0(w[t-15..t-12])
+            let mut t = $ADD32($ADD32(x[0], w9), s0);
+
+            // w[t-2..t-1] in both halves
+            let w14 = $SHUF32(x[3], 0b1110_1110);
+            // sigma1(w[t-2..t-1])
+            let s1 = $XOR($XOR(rotr!(w14, 17), rotr!(w14, 19)), $SRL32(w14, 10));
+            // w[t..t+1] are complete
+            t = $ADD32(t, $UNPACKLO64(s1, $ZERO()));
+            // sigma1(w[t..t+1])
+            let s1 = $XOR($XOR(rotr!(t, 17), rotr!(t, 19)), $SRL32(t, 10));
+            // w[t+2..t+3] are complete
+            t = $ADD32(t, $UNPACKLO64($ZERO(), s1));
+
+            // rotate
+            x[0] = x[1];
+            x[1] = x[2];
+            x[2] = x[3];
+            x[3] = t;
+
+            $ADD32(t, k32)
+        }
+    };
+}
+
+fn_sha256_update_x!(sha256_update_x_ssse3, __m128i, {
+        ADD32 = _mm_add_epi32,
+        ALIGNR8 = _mm_alignr_epi8,
+        SRL32 = _mm_srli_epi32,
+        SLL32 = _mm_slli_epi32,
+        XOR = _mm_xor_si128,
+        SHUF32 = _mm_shuffle_epi32,
+        UNPACKLO64 = _mm_unpacklo_epi64,
+        ZERO = _mm_setzero_si128,
+});
+
+fn_sha256_update_x!(sha256_update_x_avx2, __m256i, {
+        ADD32 = _mm256_add_epi32,
+        ALIGNR8 = _mm256_alignr_epi8,
+        SRL32 = _mm256_srli_epi32,
+        SLL32 = _mm256_slli_epi32,
+        XOR = _mm256_xor_si256,
+        SHUF32 = _mm256_shuffle_epi32,
+        UNPACKLO64 = _mm256_unpacklo_epi64,
+        ZERO = _mm256_setzero_si256,
+});
+
+#[inline(always)]
+fn cast_rs(rs: &RoundStates) -> &[u32; SHA256_ROUNDS_NUM] {
+    unsafe { &*(rs as *const RoundStates as *const _) }
+}
+
+type State = [u32; SHA256_HASH_WORDS_NUM];
+type RoundStates = [__m128i; SHA256_ROUNDS_NUM / 4];
+
+const SHA256_ROUNDS_NUM: usize = 64;
+const SHA256_HASH_WORDS_NUM: usize = 8;
@@ -5,14 +5,9 @@ cfg_if::cfg_if! {
         mod soft;
         use soft::compress;
     } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
-        #[cfg(not(feature = "asm"))]
         mod soft;
-        #[cfg(feature = "asm")]
-        mod soft {
-            pub(crate) fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
-                sha2_asm::compress512(state, blocks);
-            }
-        }
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        mod asm;
         mod x86;
         use x86::compress;
     } else if #[cfg(all(feature = "asm", target_arch = "aarch64"))] {
@@ -37,3 +32,15 @@ pub fn compress512(state: &mut [u64; 8], blocks: &[GenericArray<u8, U128>]) {
     let blocks = unsafe { core::slice::from_raw_parts(p, blocks.len()) };
     compress(state, blocks)
 }
+
+/// SHA-512 backends compiled for the target, see [`crate::backends`].
+pub mod backends {
+    #[cfg(all(feature = "asm", target_arch = "x86_64", not(feature = "force-soft")))]
+    pub use super::asm::compress as asm;
+    pub use super::soft::compress as soft;
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft")
+    ))]
+    pub use super::x86::avx2;
+}
@@ -0,0 +1,139 @@
+//! SHA-512 compression function written with inline assembly for `x86_64`.
+
+use crate::consts::K64;
+use core::{arch::asm, convert::TryInto};
+
+// All rounds of a block are a single `asm!` block. The message schedule is
+// expanded on the fly in a circular buffer of 16 words; the assembler picks
+// between using a loaded message word and expanding the schedule for every
+// round with `.if`. The `Σ` functions are computed with a single temporary
+// register by interleaving the rotations with XORs, e.g.
+// `Σ1(e) = ror(ror(ror(e, 23) ^ e, 4) ^ e, 14)`.
+macro_rules! reg {
+    ($r:ident) => {
+        concat!("{", stringify!($r), "}")
+    };
+}
+
+#[rustfmt::skip]
+macro_rules! round {
+    ($i:expr, $a:ident, $b:ident, $c:ident, $d:ident, $e:ident, $f:ident, $g:ident, $h:ident) => {
+        concat!(
+            ".if ", $i, " < 16\n",
+            "add ", reg!($h), ", qword ptr [{w} + 8*(", $i, ")]\n",
+            ".else\n",
+            // w[i] = σ1(w[i - 2]) + w[i - 7] + σ0(w[i - 15]) + w[i - 16]
+            "mov {t1}, qword ptr [{w} + 8*((", $i, " - 15) & 15)]\n",
+            "mov {t2}, {t1}\n",
+            "ror {t1}, 7\n",
+            "xor {t1}, {t2}\n",
+            "ror {t1}, 1\n",
+            "shr {t2}, 7\n",
+            "xor {t1}, {t2}\n",
+            "add qword ptr [{w} + 8*((", $i, ") & 15)], {t1}\n",
+            "mov {t1}, qword ptr [{w} + 8*((", $i, " - 2) & 15)]\n",
+            "mov {t2}, {t1}\n",
+            "ror {t1}, 42\n",
+            "xor {t1}, {t2}\n",
+            "ror {t1}, 19\n",
+            "shr {t2}, 6\n",
+            "xor {t1}, {t2}\n",
+            "add {t1}, qword ptr [{w} + 8*((", $i, " - 7) & 15)]\n",
+            "add {t1}, qword ptr [{w} + 8*((", $i, ") & 15)]\n",
+            "mov qword ptr [{w} + 8*((", $i, ") & 15)], {t1}\n",
+            "add ", reg!($h), ", {t1}\n",
+            ".endif\n",
+            "add ", reg!($h), ", qword ptr [{k} + 8*(", $i, ")]\n",
+            // h += Σ1(e) + Ch(e, f, g)
+            "mov {t1}, ", reg!($e), "\n",
+            "ror {t1}, 23\n",
+            "xor {t1}, ", reg!($e), "\n",
+            "ror {t1}, 4\n",
+            "xor {t1}, ", reg!($e), "\n",
+            "ror {t1}, 14\n",
+            "add ", reg!($h), ", {t1}\n",
+            "mov {t1}, ", reg!($f), "\n",
+            "xor {t1}, ", reg!($g), "\n",
+            "and {t1}, ", reg!($e), "\n",
+            "xor {t1}, ", reg!($g), "\n",
+            "add ", reg!($h), ", {t1}\n",
+            "add ", reg!($d), ", ", reg!($h), "\n",
+            // h += Σ0(a) + Maj(a, b, c)
+            "mov {t1}, ", reg!($a), "\n",
+            "ror {t1}, 5\n",
+            "xor {t1}, ", reg!($a), "\n",
+            "ror {t1}, 6\n",
+            "xor {t1}, ", reg!($a), "\n",
+            "ror {t1}, 28\n",
+            "add ", reg!($h), ", {t1}\n",
+            "mov {t1}, ", reg!($a), "\n",
+            "or {t1}, ", reg!($b), "\n",
+            "and {t1}, ", reg!($c), "\n",
+            "mov {t2}, ", reg!($a), "\n",
+            "and {t2}, ", reg!($b), "\n",
+            "or {t1}, {t2}\n",
+            "add ", reg!($h), ", {t1}\n",
+        )
+    };
+}
+
+macro_rules! rounds8 {
+    ($i:literal) => {
+        concat!(
+            round!(concat!($i, " + 0"), a, b, c, d, e, f, g, h),
+            round!(concat!($i, " + 1"), h, a, b, c, d, e, f, g),
+            round!(concat!($i, " + 2"), g, h, a, b, c, d, e, f),
+            round!(concat!($i, " + 3"), f, g, h, a, b, c, d, e),
+            round!(concat!($i, " + 4"), e, f, g, h, a, b, c, d),
+            round!(concat!($i, " + 5"), d, e, f, g, h, a, b, c),
+            round!(concat!($i, " + 6"), c, d, e, f, g, h, a, b),
+            round!(concat!($i, " + 7"), b, c, d, e, f, g, h, a),
+        )
+    };
+}
+
+/// Compress `blocks` into `state` with the inline assembly rounds.
+pub fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
+    let mut w = [0u64; 16];
+    for block in blocks {
+        for (o, chunk) in w.iter_mut().zip(block.chunks_exact(8)) {
+            *o = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+        // SAFETY: the block accesses only the 16 words of `w` and the
+        // 80 words of `K64`
+        unsafe {
+            asm!(
+                rounds8!(0),
+                rounds8!(8),
+                rounds8!(16),
+                rounds8!(24),
+                rounds8!(32),
+                rounds8!(40),
+                rounds8!(48),
+                rounds8!(56),
+                rounds8!(64),
+                rounds8!(72),
+                a = inout(reg) a,
+                b = inout(reg) b,
+                c = inout(reg) c,
+                d = inout(reg) d,
+                e = inout(reg) e,
+                f = inout(reg) f,
+                g = inout(reg) g,
+                h = inout(reg) h,
+                w = in(reg) w.as_mut_ptr(),
+                k = in(reg) K64.as_ptr(),
+                t1 = out(reg) _,
+                t2 = out(reg) _,
+                options(nostack),
+            );
+        }
+
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+            *s = s.wrapping_add(*v);
+        }
+    }
+}
@@ -21,10 +21,26 @@ pub fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
             sha512_compress_x86_64_avx2(state, blocks);
         }
     } else {
-        super::soft::compress(state, blocks);
+        compress_scalar(state, blocks);
     }
 }
 
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+use super::asm::compress as compress_scalar;
+#[cfg(not(feature = "asm"))]
+use super::soft::compress as compress_scalar;
+#[cfg(all(feature = "asm", target_arch = "x86"))]
+use sha2_asm::compress512 as compress_scalar;
+
+/// Run the backend if the CPU supports it, returns `false` otherwise.
+pub fn avx2(state: &mut [u64; 8], blocks: &[[u8; 128]]) -> bool {
+    let supported = avx2_cpuid::get();
+    if supported {
+        unsafe { sha512_compress_x86_64_avx2(state, blocks) }
+    }
+    supported
+}
+
 #[target_feature(enable = "avx2")]
 unsafe fn sha512_compress_x86_64_avx2(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
     let mut start_block = 0;
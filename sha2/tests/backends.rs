@@ -0,0 +1,111 @@
+//! Equivalence tests of the compression function backends against the
+//! software implementation. Backends not supported by the CPU running the
+//! tests are skipped with a message.
+#![cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+
+use sha2::backends::{sha256, sha512};
+
+type Compress256 = fn(&mut [u32; 8], &[[u8; 64]]) -> bool;
+type Compress512 = fn(&mut [u64; 8], &[[u8; 128]]) -> bool;
+
+fn rand_bytes(seed: &mut u64, buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        // xorshift64
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        *b = *seed as u8;
+    }
+}
+
+fn check_sha256(name: &str, f: Compress256) {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    // odd and even block counts, the AVX2 backend processes blocks in pairs
+    for n in 0..10 {
+        let mut state = [0u32; 8];
+        let mut blocks = vec![[0u8; 64]; n];
+        for v in state.iter_mut() {
+            let mut buf = [0u8; 4];
+            rand_bytes(&mut seed, &mut buf);
+            *v = u32::from_le_bytes(buf);
+        }
+        for block in blocks.iter_mut() {
+            rand_bytes(&mut seed, block);
+        }
+
+        let mut expected = state;
+        sha256::soft(&mut expected, &blocks);
+        let mut res = state;
+        if !f(&mut res, &blocks) {
+            eprintln!("{} is not supported by the CPU, skipping", name);
+            return;
+        }
+        assert_eq!(res, expected, "{} with {} blocks", name, n);
+    }
+}
+
+fn check_sha512(name: &str, f: Compress512) {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    for n in 0..10 {
+        let mut state = [0u64; 8];
+        let mut blocks = vec![[0u8; 128]; n];
+        for v in state.iter_mut() {
+            let mut buf = [0u8; 8];
+            rand_bytes(&mut seed, &mut buf);
+            *v = u64::from_le_bytes(buf);
+        }
+        for block in blocks.iter_mut() {
+            rand_bytes(&mut seed, block);
+        }
+
+        let mut expected = state;
+        sha512::soft(&mut expected, &blocks);
+        let mut res = state;
+        if !f(&mut res, &blocks) {
+            eprintln!("{} is not supported by the CPU, skipping", name);
+            return;
+        }
+        assert_eq!(res, expected, "{} with {} blocks", name, n);
+    }
+}
+
+#[test]
+fn sha256_shani_matches_soft() {
+    check_sha256("SHA-NI", sha256::shani);
+}
+
+#[test]
+fn sha256_avx2_matches_soft() {
+    check_sha256("AVX2", sha256::avx2);
+}
+
+#[test]
+fn sha256_ssse3_matches_soft() {
+    check_sha256("SSSE3", sha256::ssse3);
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+#[test]
+fn sha256_asm_matches_soft() {
+    check_sha256("asm", |state, blocks| {
+        sha256::asm(state, blocks);
+        true
+    });
+}
+
+#[test]
+fn sha512_avx2_matches_soft() {
+    check_sha512("AVX2", sha512::avx2);
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+#[test]
+fn sha512_asm_matches_soft() {
+    check_sha512("asm", |state, blocks| {
+        sha512::asm(state, blocks);
+        true
+    });
+}
@@ -143,6 +143,45 @@ impl ShabalVarCore {
     fn swap_b_c(&mut self) {
         mem::swap(&mut self.b, &mut self.c);
     }
+
+    #[allow(clippy::needless_range_loop)]
+    fn from_init(init: ([u32; 12], [u32; 16], [u32; 16])) -> Self {
+        let w = Wrapping(1);
+        // TODO: use `array::map` on MSRV bump
+        let mut a = [Wrapping(0u32); 12];
+        let mut b = [Wrapping(0u32); 16];
+        let mut c = [Wrapping(0u32); 16];
+        for i in 0..12 {
+            a[i] = Wrapping(init.0[i]);
+        }
+        for i in 0..16 {
+            b[i] = Wrapping(init.1[i]);
+            c[i] = Wrapping(init.2[i]);
+        }
+        Self { a, b, c, w }
+    }
+
+    /// Derives the initial state for the given output size in bytes.
+    ///
+    /// Starting from the all-zero state with `W = -1`, two prefix blocks
+    /// are processed whose 32-bit words are `ℓ + i` for `i` in `0..32`,
+    /// where `ℓ` is the output size in bits.
+    fn derive_init(output_size: usize) -> Self {
+        let mut state = Self {
+            a: [Wrapping(0); 12],
+            b: [Wrapping(0); 16],
+            c: [Wrapping(0); 16],
+            w: Wrapping(u64::MAX),
+        };
+        let bits = 8 * output_size as u32;
+        let mut prefix = [Block::default(), Block::default()];
+        let words = prefix.iter_mut().flat_map(|b| b.chunks_exact_mut(4));
+        for (i, chunk) in (0u32..).zip(words) {
+            chunk.copy_from_slice(&(bits + i).to_le_bytes());
+        }
+        state.update_blocks(&prefix);
+        state
+    }
 }
 
 #[inline]
@@ -188,29 +227,17 @@ impl VariableOutputCore for ShabalVarCore {
     const TRUNC_SIDE: TruncSide = TruncSide::Right;
 
     #[inline]
-    #[allow(clippy::needless_range_loop)]
     fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
-        let init = match output_size {
-            24 => consts::INIT_192,
-            28 => consts::INIT_224,
-            32 => consts::INIT_256,
-            48 => consts::INIT_384,
-            64 => consts::INIT_512,
+        let state = match output_size {
+            24 => Self::from_init(consts::INIT_192),
+            28 => Self::from_init(consts::INIT_224),
+            32 => Self::from_init(consts::INIT_256),
+            48 => Self::from_init(consts::INIT_384),
+            64 => Self::from_init(consts::INIT_512),
+            4..=64 => Self::derive_init(output_size),
             _ => return Err(InvalidOutputSize),
         };
-        let w = Wrapping(1);
-        // TODO: use `array::map` on MSRV bump
-        let mut a = [Wrapping(0u32); 12];
-        let mut b = [Wrapping(0u32); 16];
-        let mut c = [Wrapping(0u32); 16];
-        for i in 0..12 {
-            a[i] = Wrapping(init.0[i]);
-        }
-        for i in 0..16 {
-            b[i] = Wrapping(init.1[i]);
-            c[i] = Wrapping(init.2[i]);
-        }
-        Ok(Self { a, b, c, w })
+        Ok(state)
     }
 
     #[inline]
@@ -248,3 +275,27 @@ impl fmt::Debug for ShabalVarCore {
         f.write_str("ShabalVarCore { ... }")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{consts, ShabalVarCore};
+
+    #[test]
+    fn derived_init_matches_consts() {
+        let inits = [
+            (24, consts::INIT_192),
+            (28, consts::INIT_224),
+            (32, consts::INIT_256),
+            (48, consts::INIT_384),
+            (64, consts::INIT_512),
+        ];
+        for (size, init) in inits.iter() {
+            let expected = ShabalVarCore::from_init(*init);
+            let derived = ShabalVarCore::derive_init(*size);
+            assert_eq!(derived.a, expected.a);
+            assert_eq!(derived.b, expected.b);
+            assert_eq!(derived.c, expected.c);
+            assert_eq!(derived.w, expected.w);
+        }
+    }
+}
@@ -11,6 +11,10 @@
 //! There is a single Shabal algorithm. All variants have different initialisation and apart
 //! from Shabal512 all truncate the result.
 //!
+//! Other output sizes which are a multiple of 8 bits between 32 and 512 bits
+//! are supported by [`ShabalVar`], with the initial state derived as
+//! described in the specification.
+//!
 //! # Usage
 //!
 //! ```rust
@@ -56,9 +60,11 @@ pub use digest::{self, Digest};
 
 use digest::{
     consts::{U24, U28, U32, U48, U64},
-    core_api::{CoreWrapper, CtVariableCoreWrapper},
+    core_api::{CoreWrapper, CtVariableCoreWrapper, RtVariableCoreWrapper},
 };
 
+/// Shabal hasher which allows to choose output size at runtime.
+pub type ShabalVar = RtVariableCoreWrapper<ShabalVarCore>;
 /// Shabal192 hasher.
 pub type Shabal192 = CoreWrapper<CtVariableCoreWrapper<ShabalVarCore, U24>>;
 /// Shabal224 hasher.
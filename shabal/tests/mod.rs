@@ -68,3 +68,32 @@ fn shabal512_rand() {
         ")[..]
     );
 }
+
+#[test]
+fn shabal_var() {
+    use digest::{Update, VariableOutput};
+    use shabal::ShabalVar;
+
+    let mut out = [0u8; 64];
+    for &(size, expected) in &[
+        (24, &Shabal192::digest(b"abc")[..]),
+        (32, &Shabal256::digest(b"abc")[..]),
+        (64, &Shabal512::digest(b"abc")[..]),
+    ] {
+        let mut h = ShabalVar::new(size).unwrap();
+        h.update(b"abc");
+        h.finalize_variable(&mut out[..size]).unwrap();
+        assert_eq!(&out[..size], expected);
+    }
+
+    // the initial state depends on the output size, so shorter outputs
+    // are not truncations of longer ones
+    let mut h = ShabalVar::new(20).unwrap();
+    h.update(b"abc");
+    h.finalize_variable(&mut out[..20]).unwrap();
+    assert_ne!(out[..20], Shabal192::digest(b"abc")[..20]);
+
+    for &size in &[0, 1, 3, 65] {
+        assert!(ShabalVar::new(size).is_err());
+    }
+}
@@ -29,6 +29,51 @@
 //! let expected = hex!("b3250457e05d3060b1a4bbc1428bc75a3f525ca389aeab96cfa34638d96e492a");
 //! assert_eq!(result[..], expected[..]);
 //! ```
+//!
+//! The optional UBI inputs defined by the specification (key,
+//! personalization, public key, key identifier and nonce) can be set
+//! using [`SkeinBuilder`]. Skein-MAC is available as [`Skein256Mac`],
+//! [`Skein512Mac`] and [`Skein1024Mac`]:
+//!
+//! ```
+//! use skein::{consts::U32, digest::Mac, Skein512Mac};
+//!
+//! let mut mac = Skein512Mac::<U32>::new_from_slice(b"my secret key").unwrap();
+//! mac.update(b"input message");
+//! let tag = mac.finalize().into_bytes();
+//! # assert_eq!(tag.len(), 32);
+//! ```
+//!
+//! Skein can also be used as an extendable-output function with
+//! [`Skein256Xof`], [`Skein512Xof`] and [`Skein1024Xof`]:
+//!
+//! ```
+//! use skein::{digest::{ExtendableOutput, Update, XofReader}, Skein512Xof};
+//!
+//! let mut hasher = Skein512Xof::default();
+//! hasher.update(b"input message");
+//! let mut reader = hasher.finalize_xof();
+//! let mut output = [0u8; 100];
+//! reader.read(&mut output);
+//! ```
+//!
+//! Output size can also be selected at runtime using [`Skein256Var`],
+//! [`Skein512Var`] and [`Skein1024Var`]. Sizes up to 1024 bytes are
+//! supported independently of the state size:
+//!
+//! ```
+//! use skein::{digest::{Update, VariableOutput}, Skein512Var};
+//!
+//! // Skein-512-160
+//! let mut hasher = Skein512Var::new(20).unwrap();
+//! hasher.update(b"The quick brown fox jumps over the lazy dog");
+//! let mut output = [0u8; 20];
+//! hasher.finalize_variable(&mut output).unwrap();
+//! ```
+//!
+//! The tree hashing mode is available as [`Skein256Tree`], [`Skein512Tree`]
+//! and [`Skein1024Tree`] with parameters set by [`SkeinTreeParams`].
+//!
 //! Also see [RustCrypto/hashes] readme.
 //!
 //! [Skein]: https://schneier.com/academic/skein
@@ -46,171 +91,214 @@ pub use digest::{self, consts, Digest};
 
 use core::{fmt, marker::PhantomData};
 use digest::{
-    block_buffer::Lazy,
-    consts::{U128, U32, U64},
+    block_buffer::{BlockBuffer, Lazy},
+    consts::{U1024, U128, U32, U64},
     core_api::{
-        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
-        OutputSizeUser, Reset, UpdateCore,
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper,
+        ExtendableOutputCore, FixedOutputCore, OutputSizeUser, Reset, RtVariableCoreWrapper,
+        TruncSide, UpdateCore, VariableOutputCore, XofReaderCore, XofReaderCoreWrapper,
     },
+    crypto_common::{InvalidLength, Key, KeyInit, KeySizeUser},
     generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
-    HashMarker, Output,
+    HashMarker, InvalidOutputSize, MacMarker, Output,
 };
 use threefish::{Threefish1024, Threefish256, Threefish512};
 
+#[macro_use]
+mod macros;
+
 const VERSION: u64 = 1;
 const ID_STRING_LE: u64 = 0x3341_4853;
 const SCHEMA_VER: u64 = (VERSION << 32) | ID_STRING_LE;
 const CFG_TREE_INFO_SEQUENTIAL: u64 = 0;
 const T1_FLAG_FIRST: u64 = 1 << 62;
 const T1_FLAG_FINAL: u64 = 1 << 63;
+const T1_BLK_TYPE_KEY: u64 = 0;
 const T1_BLK_TYPE_CFG: u64 = 4 << 56;
+const T1_BLK_TYPE_PERS: u64 = 8 << 56;
+const T1_BLK_TYPE_PK: u64 = 12 << 56;
+const T1_BLK_TYPE_KDF: u64 = 16 << 56;
+const T1_BLK_TYPE_NONCE: u64 = 20 << 56;
 const T1_BLK_TYPE_MSG: u64 = 48 << 56;
 const T1_BLK_TYPE_OUT: u64 = 63 << 56;
 const CFG_STR_LEN: usize = 4 * 8;
+const T1_TREE_LEVEL_SHIFT: u32 = 48;
+/// Maximum number of tree levels above the leaves. Message length is
+/// limited to 2^64 bytes and every node has at least two children,
+/// so it's never reached.
+const TREE_MAX_LEVELS: usize = 64;
+/// Maximum output size in bytes of the hashers with output size selected
+/// at runtime. Skein supports arbitrary output sizes, so the limit only
+/// bounds the size of the intermediate output buffer.
+type VarMaxOutputSize = U1024;
 
-macro_rules! define_hasher {
-    (
-        $name:ident, $full_name:ident, $threefish:ident,
-        $state_bytes:ty, $alg_name:expr
-    ) => {
-        #[doc = $alg_name]
-        #[doc = " core hasher state"]
-        #[derive(Clone)]
-        pub struct $name<N: ArrayLength<u8> + 'static> {
-            t: [u64; 2],
-            x: [u64; <$state_bytes>::USIZE / 8],
-            _pd: PhantomData<N>,
-        }
+/// Builder of Skein hasher cores with the optional UBI inputs.
+///
+/// The inputs are processed in the order defined by the Skein
+/// specification: key, configuration, personalization, public key,
+/// key identifier and nonce. Empty inputs are skipped.
+///
+/// ```
+/// use skein::{consts::U64, Skein512, Skein512Core, SkeinBuilder};
+/// use skein::digest::Digest;
+///
+/// let builder = SkeinBuilder::new()
+///     .personalization(b"20081031 me@example.com my-app/v1")
+///     .nonce(b"some nonce");
+/// let mut hasher = Skein512::from_core(Skein512Core::<U64>::from_builder(&builder));
+/// hasher.update(b"message");
+/// let hash = hasher.finalize();
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct SkeinBuilder<'a> {
+    key: &'a [u8],
+    personalization: &'a [u8],
+    public_key: &'a [u8],
+    key_id: &'a [u8],
+    nonce: &'a [u8],
+}
 
-        impl<N: ArrayLength<u8> + 'static> $name<N> {
-            fn blank_state(t1: u64, x: [u64; <$state_bytes>::USIZE / 8]) -> Self {
-                Self {
-                    t: [0, t1],
-                    x,
-                    _pd: PhantomData,
-                }
-            }
-
-            fn process_block(
-                &mut self,
-                block: &GenericArray<u8, $state_bytes>,
-                byte_count_add: usize,
-            ) {
-                const STATE_WORDS: usize = <$state_bytes>::USIZE / 8;
-
-                self.t[0] += byte_count_add as u64;
-                let cipher = $threefish::new_with_tweak_u64(&self.x.into(), &self.t);
-
-                let mut x = [0u64; STATE_WORDS];
-                for (src, dst) in block.chunks_exact(8).zip(x.iter_mut()) {
-                    *dst = u64::from_le_bytes(src.try_into().unwrap());
-                }
-                let t = x;
-
-                cipher.encrypt_block_u64(&mut x);
-
-                for i in 0..STATE_WORDS {
-                    self.x[i] = t[i] ^ x[i];
-                }
-                self.t[1] &= !T1_FLAG_FIRST;
-            }
-        }
+impl<'a> SkeinBuilder<'a> {
+    /// Create new builder without any optional inputs.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        impl<N> HashMarker for $name<N> where N: ArrayLength<u8> + 'static {}
+    /// Set key, which turns the hash function into Skein-MAC.
+    pub fn key(mut self, key: &'a [u8]) -> Self {
+        self.key = key;
+        self
+    }
 
-        impl<N: ArrayLength<u8> + 'static> BlockSizeUser for $name<N> {
-            type BlockSize = $state_bytes;
-        }
+    /// Set personalization string.
+    pub fn personalization(mut self, personalization: &'a [u8]) -> Self {
+        self.personalization = personalization;
+        self
+    }
 
-        impl<N: ArrayLength<u8> + 'static> BufferKindUser for $name<N> {
-            type BufferKind = Lazy;
-        }
+    /// Set public key, used when hashing messages for signing.
+    pub fn public_key(mut self, public_key: &'a [u8]) -> Self {
+        self.public_key = public_key;
+        self
+    }
 
-        impl<N: ArrayLength<u8> + 'static> OutputSizeUser for $name<N> {
-            type OutputSize = N;
-        }
+    /// Set key identifier, used by Skein-KDF.
+    pub fn key_id(mut self, key_id: &'a [u8]) -> Self {
+        self.key_id = key_id;
+        self
+    }
 
-        impl<N: ArrayLength<u8> + 'static> UpdateCore for $name<N> {
-            #[inline]
-            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
-                for block in blocks {
-                    self.process_block(block, block.len())
-                }
-            }
-        }
+    /// Set nonce, used by Skein as a stream cipher and in randomized hashing.
+    pub fn nonce(mut self, nonce: &'a [u8]) -> Self {
+        self.nonce = nonce;
+        self
+    }
+}
 
-        impl<N: ArrayLength<u8> + 'static> FixedOutputCore for $name<N> {
-            #[inline]
-            fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
-                self.t[1] |= T1_FLAG_FINAL;
-                let pos = buffer.get_pos();
-                let final_block = buffer.pad_with_zeros();
-                self.process_block(final_block, pos);
-
-                // run Threefish in "counter mode" to generate output
-                let flag = T1_FLAG_FIRST | T1_BLK_TYPE_OUT | T1_FLAG_FINAL;
-                let mut block = GenericArray::<u8, $state_bytes>::default();
-                for (i, chunk) in out.chunks_mut(<$state_bytes>::USIZE).enumerate() {
-                    let mut ctr = Self::blank_state(flag, self.x);
-
-                    block[..8].copy_from_slice(&(i as u64).to_le_bytes());
-                    Self::process_block(&mut ctr, &block, 8);
-
-                    for (src, dst) in ctr.x.iter().zip(chunk.chunks_exact_mut(8)) {
-                        dst.copy_from_slice(&src.to_le_bytes());
-                    }
-                }
-            }
-        }
+impl fmt::Debug for SkeinBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SkeinBuilder { ... }")
+    }
+}
 
-        impl<N: ArrayLength<u8> + 'static> Default for $name<N> {
-            fn default() -> Self {
-                // build and process config block
-                let mut state = Self::blank_state(
-                    T1_FLAG_FIRST | T1_BLK_TYPE_CFG | T1_FLAG_FINAL,
-                    Default::default(),
-                );
-
-                let mut cfg = GenericArray::<u8, $state_bytes>::default();
-                cfg[..8].copy_from_slice(&SCHEMA_VER.to_le_bytes());
-                cfg[8..16].copy_from_slice(&(N::to_u64() * 8).to_le_bytes());
-                cfg[16..24].copy_from_slice(&CFG_TREE_INFO_SEQUENTIAL.to_le_bytes());
-
-                state.process_block(&cfg, CFG_STR_LEN);
-
-                // The chaining vars ctx->X are now initialized for the given hashBitLen.
-                // Set up to process the data message portion of the hash (default)
-                state.t = [0, T1_FLAG_FIRST | T1_BLK_TYPE_MSG];
-                state
-            }
-        }
+/// Parameters of the Skein tree hashing mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SkeinTreeParams {
+    leaf_size: u8,
+    fan_out: u8,
+    max_height: u8,
+}
 
-        impl<N: ArrayLength<u8> + 'static> Reset for $name<N> {
-            #[inline]
-            fn reset(&mut self) {
-                *self = Default::default();
-            }
+impl SkeinTreeParams {
+    /// Create tree parameters.
+    ///
+    /// Tree leaves contain `2^leaf_size` message blocks (`Y_l` in the
+    /// specification), tree nodes contain `2^fan_out` chaining values
+    /// (`Y_f`) and the tree height is limited by `max_height` (`Y_m`).
+    ///
+    /// Returns `None` if `leaf_size` or `fan_out` is zero or `max_height`
+    /// is smaller than 2.
+    pub fn new(leaf_size: u8, fan_out: u8, max_height: u8) -> Option<Self> {
+        if leaf_size == 0 || fan_out == 0 || max_height < 2 {
+            return None;
         }
+        Some(Self {
+            leaf_size,
+            fan_out,
+            max_height,
+        })
+    }
 
-        impl<N: ArrayLength<u8> + 'static> AlgorithmName for $name<N> {
-            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(stringify!($full_name))
-            }
-        }
+    fn tree_info(&self) -> u64 {
+        u64::from(self.leaf_size) | u64::from(self.fan_out) << 8 | u64::from(self.max_height) << 16
+    }
 
-        impl<N: ArrayLength<u8> + 'static> fmt::Debug for $name<N> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-                write!(f, "{}<{}> {{ .. }}", stringify!($name), N::USIZE)
-            }
-        }
+    fn leaf_blocks(&self) -> u64 {
+        pow2(self.leaf_size)
+    }
+
+    fn node_blocks(&self) -> u64 {
+        pow2(self.fan_out)
+    }
+}
 
-        #[doc = $alg_name]
-        #[doc = " hasher state"]
-        pub type $full_name<OutputSize = $state_bytes> = CoreWrapper<$name<OutputSize>>;
-    };
+/// Compute `2^e` saturating to `u64::MAX`, which can never be reached
+/// by block counters.
+fn pow2(e: u8) -> u64 {
+    1u64.checked_shl(e.into()).unwrap_or(u64::MAX)
 }
 
-define_hasher!(Skein256Core, Skein256, Threefish256, U32, "Skein-256");
-define_hasher!(Skein512Core, Skein512, Threefish512, U64, "Skein-512");
-#[rustfmt::skip]
-define_hasher!(Skein1024Core, Skein1024, Threefish1024, U128, "Skein-1024");
+define_hasher!(
+    Skein256State,
+    Skein256Core,
+    Skein256,
+    Skein256MacCore,
+    Skein256Mac,
+    Skein256XofCore,
+    Skein256Xof,
+    Skein256XofReaderCore,
+    Skein256XofReader,
+    Skein256TreeCore,
+    Skein256Tree,
+    Skein256VarCore,
+    Skein256Var,
+    Threefish256,
+    U32,
+    "Skein-256"
+);
+define_hasher!(
+    Skein512State,
+    Skein512Core,
+    Skein512,
+    Skein512MacCore,
+    Skein512Mac,
+    Skein512XofCore,
+    Skein512Xof,
+    Skein512XofReaderCore,
+    Skein512XofReader,
+    Skein512TreeCore,
+    Skein512Tree,
+    Skein512VarCore,
+    Skein512Var,
+    Threefish512,
+    U64,
+    "Skein-512"
+);
+define_hasher!(
+    Skein1024State,
+    Skein1024Core,
+    Skein1024,
+    Skein1024MacCore,
+    Skein1024Mac,
+    Skein1024XofCore,
+    Skein1024Xof,
+    Skein1024XofReaderCore,
+    Skein1024XofReader,
+    Skein1024TreeCore,
+    Skein1024Tree,
+    Skein1024VarCore,
+    Skein1024Var,
+    Threefish1024,
+    U128,
+    "Skein-1024"
+);
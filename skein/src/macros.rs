@@ -0,0 +1,732 @@
+macro_rules! define_hasher {
+    (
+        $state:ident, $name:ident, $full_name:ident,
+        $mac_name:ident, $full_mac_name:ident,
+        $xof_name:ident, $full_xof_name:ident, $reader:ident, $full_reader:ident,
+        $tree_name:ident, $full_tree_name:ident,
+        $var_name:ident, $full_var_name:ident,
+        $threefish:ident, $state_bytes:ty, $alg_name:expr
+    ) => {
+        /// UBI state shared by the hasher cores.
+        #[derive(Clone, Copy)]
+        struct $state {
+            t: [u64; 2],
+            x: [u64; $state::WORDS],
+        }
+
+        impl $state {
+            const WORDS: usize = <$state_bytes>::USIZE / 8;
+
+            /// Process the key, configuration and the other optional UBI
+            /// inputs set in `builder` and return the chaining value used
+            /// for the message processing.
+            fn init(
+                builder: &SkeinBuilder<'_>,
+                output_bits: u64,
+                tree_info: u64,
+            ) -> [u64; Self::WORDS] {
+                let mut x = Default::default();
+                if !builder.key.is_empty() {
+                    x = Self::ubi(x, T1_BLK_TYPE_KEY, builder.key);
+                }
+
+                let mut cfg = [0u8; CFG_STR_LEN];
+                cfg[..8].copy_from_slice(&SCHEMA_VER.to_le_bytes());
+                cfg[8..16].copy_from_slice(&output_bits.to_le_bytes());
+                cfg[16..24].copy_from_slice(&tree_info.to_le_bytes());
+                x = Self::ubi(x, T1_BLK_TYPE_CFG, &cfg);
+
+                let inputs = [
+                    (T1_BLK_TYPE_PERS, builder.personalization),
+                    (T1_BLK_TYPE_PK, builder.public_key),
+                    (T1_BLK_TYPE_KDF, builder.key_id),
+                    (T1_BLK_TYPE_NONCE, builder.nonce),
+                ];
+                for (block_type, data) in inputs.iter() {
+                    if !data.is_empty() {
+                        x = Self::ubi(x, *block_type, data);
+                    }
+                }
+
+                x
+            }
+
+            /// Create state for processing the message portion of the hash
+            /// in the sequential mode.
+            fn msg_state(x: [u64; Self::WORDS]) -> Self {
+                Self::blank_state(T1_FLAG_FIRST | T1_BLK_TYPE_MSG, x)
+            }
+
+            fn blank_state(t1: u64, x: [u64; Self::WORDS]) -> Self {
+                Self { t: [0, t1], x }
+            }
+
+            /// Process `data` as a complete UBI call of the given type
+            /// and return the new chaining value.
+            fn ubi(x: [u64; Self::WORDS], block_type: u64, data: &[u8]) -> [u64; Self::WORDS] {
+                const BLOCK: usize = <$state_bytes>::USIZE;
+
+                let mut state = Self::blank_state(T1_FLAG_FIRST | block_type, x);
+                let tail_len = if data.is_empty() {
+                    0
+                } else {
+                    (data.len() - 1) % BLOCK + 1
+                };
+                let (head, tail) = data.split_at(data.len() - tail_len);
+                for block in head.chunks_exact(BLOCK) {
+                    state.process_block(GenericArray::from_slice(block), BLOCK);
+                }
+
+                let mut block = GenericArray::<u8, $state_bytes>::default();
+                block[..tail_len].copy_from_slice(tail);
+                state.t[1] |= T1_FLAG_FINAL;
+                state.process_block(&block, tail_len);
+                state.x
+            }
+
+            fn process_block(
+                &mut self,
+                block: &GenericArray<u8, $state_bytes>,
+                byte_count_add: usize,
+            ) {
+                self.t[0] += byte_count_add as u64;
+                let cipher = $threefish::new_with_tweak_u64(&self.x.into(), &self.t);
+
+                let mut x = [0u64; Self::WORDS];
+                for (src, dst) in block.chunks_exact(8).zip(x.iter_mut()) {
+                    *dst = u64::from_le_bytes(src.try_into().unwrap());
+                }
+                let t = x;
+
+                cipher.encrypt_block_u64(&mut x);
+
+                for i in 0..Self::WORDS {
+                    self.x[i] = t[i] ^ x[i];
+                }
+                self.t[1] &= !T1_FLAG_FIRST;
+            }
+
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[GenericArray<u8, $state_bytes>]) {
+                for block in blocks {
+                    self.process_block(block, block.len())
+                }
+            }
+
+            /// Process the last message block and return the chaining value
+            /// used as input of the output function.
+            #[inline]
+            fn finalize(
+                &mut self,
+                buffer: &mut BlockBuffer<$state_bytes, Lazy>,
+            ) -> [u64; Self::WORDS] {
+                self.t[1] |= T1_FLAG_FINAL;
+                let pos = buffer.get_pos();
+                let final_block = buffer.pad_with_zeros();
+                self.process_block(final_block, pos);
+                self.x
+            }
+
+            /// Compute block `i` of the output function, i.e. run Threefish
+            /// in "counter mode".
+            fn output_block(x: [u64; Self::WORDS], i: u64) -> GenericArray<u8, $state_bytes> {
+                Self::block_from_words(&Self::ubi(x, T1_BLK_TYPE_OUT, &i.to_le_bytes()))
+            }
+
+            /// Fill `out` using the output function.
+            fn output(x: [u64; Self::WORDS], out: &mut [u8]) {
+                for (i, chunk) in out.chunks_mut(<$state_bytes>::USIZE).enumerate() {
+                    let block = Self::output_block(x, i as u64);
+                    chunk.copy_from_slice(&block[..chunk.len()]);
+                }
+            }
+
+            #[inline]
+            fn block_from_words(x: &[u64; Self::WORDS]) -> GenericArray<u8, $state_bytes> {
+                let mut block = GenericArray::<u8, $state_bytes>::default();
+                for (src, dst) in x.iter().zip(block.chunks_exact_mut(8)) {
+                    dst.copy_from_slice(&src.to_le_bytes());
+                }
+                block
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " core hasher state"]
+        #[derive(Clone)]
+        pub struct $name<N: ArrayLength<u8> + 'static> {
+            state: $state,
+            x0: [u64; $state::WORDS],
+            _pd: PhantomData<N>,
+        }
+
+        impl<N: ArrayLength<u8> + 'static> $name<N> {
+            /// Create new hasher core using the optional UBI inputs set in `builder`.
+            pub fn from_builder(builder: &SkeinBuilder<'_>) -> Self {
+                let x0 = $state::init(builder, N::to_u64() * 8, CFG_TREE_INFO_SEQUENTIAL);
+                Self {
+                    state: $state::msg_state(x0),
+                    x0,
+                    _pd: PhantomData,
+                }
+            }
+        }
+
+        impl<N> HashMarker for $name<N> where N: ArrayLength<u8> + 'static {}
+
+        impl<N: ArrayLength<u8> + 'static> BlockSizeUser for $name<N> {
+            type BlockSize = $state_bytes;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> BufferKindUser for $name<N> {
+            type BufferKind = Lazy;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> OutputSizeUser for $name<N> {
+            type OutputSize = N;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> UpdateCore for $name<N> {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                self.state.update_blocks(blocks);
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> FixedOutputCore for $name<N> {
+            #[inline]
+            fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+                let x = self.state.finalize(buffer);
+                $state::output(x, out);
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> Default for $name<N> {
+            fn default() -> Self {
+                Self::from_builder(&SkeinBuilder::new())
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> Reset for $name<N> {
+            #[inline]
+            fn reset(&mut self) {
+                self.state = $state::msg_state(self.x0);
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> AlgorithmName for $name<N> {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(stringify!($full_name))
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> fmt::Debug for $name<N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                write!(f, "{}<{}> {{ .. }}", stringify!($name), N::USIZE)
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " hasher state"]
+        pub type $full_name<OutputSize = $state_bytes> = CoreWrapper<$name<OutputSize>>;
+
+        #[doc = $alg_name]
+        #[doc = "-MAC core state"]
+        ///
+        /// Keys of any length are accepted.
+        #[derive(Clone)]
+        pub struct $mac_name<N: ArrayLength<u8> + 'static> {
+            core: $name<N>,
+        }
+
+        impl<N: ArrayLength<u8> + 'static> MacMarker for $mac_name<N> {}
+
+        impl<N: ArrayLength<u8> + 'static> KeySizeUser for $mac_name<N> {
+            type KeySize = $state_bytes;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> KeyInit for $mac_name<N> {
+            #[inline]
+            fn new(key: &Key<Self>) -> Self {
+                Self::new_from_slice(key).expect("Key has correct length")
+            }
+
+            #[inline]
+            fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+                let core = $name::from_builder(&SkeinBuilder::new().key(key));
+                Ok(Self { core })
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> BlockSizeUser for $mac_name<N> {
+            type BlockSize = $state_bytes;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> BufferKindUser for $mac_name<N> {
+            type BufferKind = Lazy;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> OutputSizeUser for $mac_name<N> {
+            type OutputSize = N;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> UpdateCore for $mac_name<N> {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                self.core.update_blocks(blocks);
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> FixedOutputCore for $mac_name<N> {
+            #[inline]
+            fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+                self.core.finalize_fixed_core(buffer, out);
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> Reset for $mac_name<N> {
+            #[inline]
+            fn reset(&mut self) {
+                self.core.reset();
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> AlgorithmName for $mac_name<N> {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(stringify!($full_mac_name))
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> fmt::Debug for $mac_name<N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                write!(f, "{}<{}> {{ ... }}", stringify!($mac_name), N::USIZE)
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = "-MAC state"]
+        pub type $full_mac_name<OutputSize = $state_bytes> = CoreWrapper<$mac_name<OutputSize>>;
+
+        #[doc = $alg_name]
+        #[doc = " core XOF hasher state"]
+        ///
+        /// The expected output length is encoded into the configuration
+        /// block, so the first `output_size` bytes of the output are equal
+        /// to the output of the fixed-size hasher with the same output size.
+        /// The reader can produce output of any length. [`Default`] uses
+        /// the state size as the expected output length.
+        #[derive(Clone)]
+        pub struct $xof_name {
+            state: $state,
+            x0: [u64; $state::WORDS],
+        }
+
+        impl $xof_name {
+            /// Create new XOF hasher core with the expected output length
+            /// `output_size` in bytes.
+            pub fn new(output_size: usize) -> Self {
+                Self::from_builder(&SkeinBuilder::new(), output_size)
+            }
+
+            /// Create new XOF hasher core with the expected output length
+            /// `output_size` in bytes using the optional UBI inputs set
+            /// in `builder`.
+            pub fn from_builder(builder: &SkeinBuilder<'_>, output_size: usize) -> Self {
+                let output_bits = 8 * output_size as u64;
+                let x0 = $state::init(builder, output_bits, CFG_TREE_INFO_SEQUENTIAL);
+                Self {
+                    state: $state::msg_state(x0),
+                    x0,
+                }
+            }
+        }
+
+        impl HashMarker for $xof_name {}
+
+        impl BlockSizeUser for $xof_name {
+            type BlockSize = $state_bytes;
+        }
+
+        impl BufferKindUser for $xof_name {
+            type BufferKind = Lazy;
+        }
+
+        impl UpdateCore for $xof_name {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                self.state.update_blocks(blocks);
+            }
+        }
+
+        impl ExtendableOutputCore for $xof_name {
+            type ReaderCore = $reader;
+
+            #[inline]
+            fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+                $reader {
+                    x: self.state.finalize(buffer),
+                    ctr: 0,
+                }
+            }
+        }
+
+        impl Default for $xof_name {
+            #[inline]
+            fn default() -> Self {
+                Self::new(<$state_bytes>::USIZE)
+            }
+        }
+
+        impl Reset for $xof_name {
+            #[inline]
+            fn reset(&mut self) {
+                self.state = $state::msg_state(self.x0);
+            }
+        }
+
+        impl AlgorithmName for $xof_name {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(stringify!($full_xof_name))
+            }
+        }
+
+        impl fmt::Debug for $xof_name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($xof_name), " { ... }"))
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " core XOF reader state"]
+        #[derive(Clone)]
+        pub struct $reader {
+            x: [u64; $state::WORDS],
+            ctr: u64,
+        }
+
+        impl BlockSizeUser for $reader {
+            type BlockSize = $state_bytes;
+        }
+
+        impl XofReaderCore for $reader {
+            #[inline]
+            fn read_block(&mut self) -> Block<Self> {
+                let block = $state::output_block(self.x, self.ctr);
+                self.ctr = self.ctr.wrapping_add(1);
+                block
+            }
+        }
+
+        impl fmt::Debug for $reader {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($reader), " { ... }"))
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " XOF hasher state"]
+        pub type $full_xof_name = CoreWrapper<$xof_name>;
+
+        #[doc = $alg_name]
+        #[doc = " XOF reader state"]
+        pub type $full_reader = XofReaderCoreWrapper<$reader>;
+
+        #[doc = $alg_name]
+        #[doc = " core hasher state in the tree hashing mode"]
+        ///
+        /// Partially processed nodes are kept for every tree level, so the
+        /// state is considerably larger than the state of the sequential
+        /// hasher.
+        #[derive(Clone)]
+        pub struct $tree_name<N: ArrayLength<u8> + 'static> {
+            x0: [u64; $state::WORDS],
+            params: SkeinTreeParams,
+            leaf: $state,
+            blocks: u64,
+            levels: [$state; TREE_MAX_LEVELS],
+            pending: [[u64; $state::WORDS]; TREE_MAX_LEVELS],
+            counts: [u64; TREE_MAX_LEVELS],
+            _pd: PhantomData<N>,
+        }
+
+        impl<N: ArrayLength<u8> + 'static> $tree_name<N> {
+            /// Create new hasher core with the given tree parameters.
+            pub fn new(params: SkeinTreeParams) -> Self {
+                Self::from_builder(&SkeinBuilder::new(), params)
+            }
+
+            /// Create new hasher core with the given tree parameters using
+            /// the optional UBI inputs set in `builder`.
+            pub fn from_builder(builder: &SkeinBuilder<'_>, params: SkeinTreeParams) -> Self {
+                let x0 = $state::init(builder, N::to_u64() * 8, params.tree_info());
+                let blank = $state::blank_state(0, x0);
+                Self {
+                    x0,
+                    params,
+                    leaf: blank,
+                    blocks: 0,
+                    levels: [blank; TREE_MAX_LEVELS],
+                    pending: [[0; $state::WORDS]; TREE_MAX_LEVELS],
+                    counts: [0; TREE_MAX_LEVELS],
+                    _pd: PhantomData,
+                }
+            }
+
+            /// Start new UBI call at the given tree level. The byte position
+            /// is not reset, since it's equal to the number of bytes
+            /// processed on this level.
+            fn start_node(state: &mut $state, x0: [u64; $state::WORDS], level: usize) {
+                let level = (level as u64) << T1_TREE_LEVEL_SHIFT;
+                state.t[1] = T1_FLAG_FIRST | T1_BLK_TYPE_MSG | level;
+                state.x = x0;
+            }
+
+            fn process_leaf_block(
+                &mut self,
+                block: &GenericArray<u8, $state_bytes>,
+                len: usize,
+                last: bool,
+            ) {
+                let leaf_blocks = self.params.leaf_blocks();
+                let pos = self.blocks % leaf_blocks;
+                if pos == 0 {
+                    Self::start_node(&mut self.leaf, self.x0, 1);
+                }
+                let leaf_end = last || pos == leaf_blocks - 1;
+                if leaf_end {
+                    self.leaf.t[1] |= T1_FLAG_FINAL;
+                }
+                self.leaf.process_block(block, len);
+                self.blocks += 1;
+                if leaf_end {
+                    self.push(2, self.leaf.x);
+                }
+            }
+
+            /// Returns `true` if chaining value with index `pos` is
+            /// the last one in its node.
+            fn is_node_end(&self, level: usize, pos: u64) -> bool {
+                let node_blocks = self.params.node_blocks();
+                level < self.params.max_height as usize && pos % node_blocks == node_blocks - 1
+            }
+
+            fn absorb(&mut self, level: usize, pos: u64, cv: &[u64; $state::WORDS], last: bool) {
+                let node_start = if level < self.params.max_height as usize {
+                    pos % self.params.node_blocks() == 0
+                } else {
+                    pos == 0
+                };
+                let state = &mut self.levels[level - 2];
+                if node_start {
+                    Self::start_node(state, self.x0, level);
+                }
+                if last {
+                    state.t[1] |= T1_FLAG_FINAL;
+                }
+                state.process_block(&$state::block_from_words(cv), <$state_bytes>::USIZE);
+            }
+
+            /// Add chaining value to the given tree level.
+            ///
+            /// The last chaining value on every level is kept unprocessed
+            /// until it's known whether it's the last one in its node
+            /// and whether the level contains only one chaining value.
+            fn push(&mut self, mut level: usize, mut cv: [u64; $state::WORDS]) {
+                loop {
+                    let idx = level - 2;
+                    let count = self.counts[idx];
+                    self.counts[idx] += 1;
+                    if count > 0 && !self.is_node_end(level, count - 1) {
+                        let prev = self.pending[idx];
+                        self.absorb(level, count - 1, &prev, false);
+                    }
+                    if !self.is_node_end(level, count) {
+                        self.pending[idx] = cv;
+                        return;
+                    }
+                    self.absorb(level, count, &cv, true);
+                    cv = self.levels[idx].x;
+                    level += 1;
+                }
+            }
+        }
+
+        impl<N> HashMarker for $tree_name<N> where N: ArrayLength<u8> + 'static {}
+
+        impl<N: ArrayLength<u8> + 'static> BlockSizeUser for $tree_name<N> {
+            type BlockSize = $state_bytes;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> BufferKindUser for $tree_name<N> {
+            type BufferKind = Lazy;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> OutputSizeUser for $tree_name<N> {
+            type OutputSize = N;
+        }
+
+        impl<N: ArrayLength<u8> + 'static> UpdateCore for $tree_name<N> {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                // Lazy buffering guarantees that the last message block
+                // is processed during finalization
+                for block in blocks {
+                    self.process_leaf_block(block, block.len(), false);
+                }
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> FixedOutputCore for $tree_name<N> {
+            fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+                let pos = buffer.get_pos();
+                self.process_leaf_block(buffer.pad_with_zeros(), pos, true);
+
+                let max_height = self.params.max_height as usize;
+                let mut level = 2;
+                let x = loop {
+                    let idx = level - 2;
+                    let count = self.counts[idx];
+                    if count == 1 {
+                        break self.pending[idx];
+                    }
+                    if !self.is_node_end(level, count - 1) {
+                        let prev = self.pending[idx];
+                        self.absorb(level, count - 1, &prev, true);
+                        if level == max_height {
+                            break self.levels[idx].x;
+                        }
+                        self.push(level + 1, self.levels[idx].x);
+                    }
+                    level += 1;
+                };
+                $state::output(x, out);
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> Reset for $tree_name<N> {
+            #[inline]
+            fn reset(&mut self) {
+                let blank = $state::blank_state(0, self.x0);
+                self.leaf = blank;
+                self.blocks = 0;
+                self.levels = [blank; TREE_MAX_LEVELS];
+                self.counts = [0; TREE_MAX_LEVELS];
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> AlgorithmName for $tree_name<N> {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(stringify!($full_tree_name))
+            }
+        }
+
+        impl<N: ArrayLength<u8> + 'static> fmt::Debug for $tree_name<N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+                write!(f, "{}<{}> {{ ... }}", stringify!($tree_name), N::USIZE)
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " hasher state in the tree hashing mode"]
+        pub type $full_tree_name<OutputSize = $state_bytes> = CoreWrapper<$tree_name<OutputSize>>;
+
+        #[doc = $alg_name]
+        #[doc = " core hasher state with output size selected at runtime"]
+        ///
+        /// The output size in bits is encoded into the configuration block,
+        /// so the output is equal to the output of the fixed-size hasher with
+        /// the same output size.
+        #[derive(Clone)]
+        pub struct $var_name {
+            state: $state,
+            x0: [u64; $state::WORDS],
+            output_size: usize,
+        }
+
+        impl $var_name {
+            /// Create new hasher core with the given output size in bytes
+            /// using the optional UBI inputs set in `builder`.
+            ///
+            /// Returns an error if `output_size` is zero or larger than
+            /// 1024 bytes.
+            pub fn from_builder(
+                builder: &SkeinBuilder<'_>,
+                output_size: usize,
+            ) -> Result<Self, InvalidOutputSize> {
+                if output_size == 0 || output_size > VarMaxOutputSize::USIZE {
+                    return Err(InvalidOutputSize);
+                }
+                let output_bits = 8 * output_size as u64;
+                let x0 = $state::init(builder, output_bits, CFG_TREE_INFO_SEQUENTIAL);
+                Ok(Self {
+                    state: $state::msg_state(x0),
+                    x0,
+                    output_size,
+                })
+            }
+        }
+
+        impl HashMarker for $var_name {}
+
+        impl BlockSizeUser for $var_name {
+            type BlockSize = $state_bytes;
+        }
+
+        impl BufferKindUser for $var_name {
+            type BufferKind = Lazy;
+        }
+
+        impl OutputSizeUser for $var_name {
+            type OutputSize = VarMaxOutputSize;
+        }
+
+        impl UpdateCore for $var_name {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                self.state.update_blocks(blocks);
+            }
+        }
+
+        impl VariableOutputCore for $var_name {
+            const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+            #[inline]
+            fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+                Self::from_builder(&SkeinBuilder::new(), output_size)
+            }
+
+            #[inline]
+            fn finalize_variable_core(
+                &mut self,
+                buffer: &mut Buffer<Self>,
+                out: &mut Output<Self>,
+            ) {
+                let x = self.state.finalize(buffer);
+                $state::output(x, &mut out[..self.output_size]);
+            }
+        }
+
+        impl Reset for $var_name {
+            #[inline]
+            fn reset(&mut self) {
+                self.state = $state::msg_state(self.x0);
+            }
+        }
+
+        impl AlgorithmName for $var_name {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(stringify!($full_name))
+            }
+        }
+
+        impl fmt::Debug for $var_name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($var_name), " { ... }"))
+            }
+        }
+
+        #[doc = $alg_name]
+        #[doc = " hasher state with output size selected at runtime"]
+        pub type $full_var_name = RtVariableCoreWrapper<$var_name>;
+    };
+}
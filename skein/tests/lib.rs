@@ -1,7 +1,8 @@
+use hex_literal::hex;
 use skein::{
-    consts::{U128, U32, U64},
+    consts::{U128, U28, U32, U64},
     digest::{dev::fixed_test, new_test},
-    Skein1024, Skein256, Skein512,
+    Digest, Skein1024, Skein256, Skein512,
 };
 
 new_test!(skein256_32, "skein256_32", Skein256<U32>, fixed_test);
@@ -11,3 +12,13 @@ new_test!(skein512_64, "skein512_64", Skein512<U64>, fixed_test);
 new_test!(skein1024_32, "skein1024_32", Skein1024<U32>, fixed_test);
 new_test!(skein1024_64, "skein1024_64", Skein1024<U64>, fixed_test);
 new_test!(skein1024_128, "skein1024_128", Skein1024<U128>, fixed_test);
+
+/// Skein-512-224 of the empty message from the Skein submission KATs.
+/// The output size is not a multiple of the 8 byte output word.
+#[test]
+fn skein512_224_empty() {
+    assert_eq!(
+        Skein512::<U28>::digest(b"")[..],
+        hex!("1541ae9fc3ebe24eb758ccb1fd60c2c31a9ebfe65b220086e7819e25")[..],
+    );
+}
@@ -0,0 +1,115 @@
+//! Skein-MAC and optional UBI inputs.
+//!
+//! The expected values were computed with an independent implementation
+//! of the Skein 1.3 specification, which was checked against the hashing
+//! KATs. Keys are `(3 * i + 1) as u8` and messages are `i as u8` sequences.
+use hex_literal::hex;
+use skein::{
+    consts::{U128, U32, U64},
+    digest::{Digest, FixedOutputReset, KeyInit, Mac},
+    Skein1024Mac, Skein256Mac, Skein512, Skein512Core, Skein512Mac, SkeinBuilder,
+};
+
+fn key(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (3 * i + 1) as u8).collect()
+}
+
+fn msg(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+fn check_mac<M>(key_len: usize, msg_len: usize, expected: &[u8])
+where
+    M: Mac + KeyInit + FixedOutputReset + Clone,
+{
+    let msg = msg(msg_len);
+    let mut mac = <M as Mac>::new_from_slice(&key(key_len)).unwrap();
+    let mut mac2 = mac.clone();
+    Mac::update(&mut mac, &msg);
+    assert_eq!(mac.finalize().into_bytes()[..], expected[..]);
+
+    // split input and check that reset restores the keyed state
+    let (a, b) = msg.split_at(msg_len / 2);
+    Mac::update(&mut mac2, a);
+    Mac::update(&mut mac2, b);
+    assert_eq!(mac2.finalize_reset().into_bytes()[..], expected[..]);
+    Mac::update(&mut mac2, &msg);
+    mac2.verify_slice(expected).unwrap();
+}
+
+#[test]
+#[rustfmt::skip]
+fn skein256_mac() {
+    check_mac::<Skein256Mac<U32>>(16, 31, &hex!(
+        "b474b69c95a2459db437445b4946ac453e5b976459cb5ec9d8f7fd0b955f9aa5"
+    ));
+    check_mac::<Skein256Mac<U32>>(65, 64, &hex!(
+        "03436ba4912910842f06368198a2660a564fed3eb4584d1e65770355071e9d94"
+    ));
+}
+
+#[test]
+#[rustfmt::skip]
+fn skein512_mac() {
+    check_mac::<Skein512Mac<U64>>(64, 1, &hex!("
+        c0c47128ed661adf5a9bb700bd50bd7a42902dc9d42b3397f1269889f809b480
+        39b34d8e72f30069cfd47c15dc38feaba460a32626f2642c8535bdead976cdec
+    "));
+    check_mac::<Skein512Mac<U32>>(129, 200, &hex!(
+        "1e5d4ccf362d11f230aefb075776b6a58eb4ed6b6505836b15e75d972800ad10"
+    ));
+}
+
+#[test]
+#[rustfmt::skip]
+fn skein1024_mac() {
+    check_mac::<Skein1024Mac<U128>>(128, 128, &hex!("
+        eac18f7e7a7a9ba3af4b5a1a7ae36744a890cdeff72746571046e11683de796d
+        b3dceb119b9ea8d41ae07f104fdf6c1e0924484c7d74ff6fb38dd761f03bfaeb
+        d90a31955dc2e98767e6cc0af921c0b4d70e20be9136f4160246d66767bf1581
+        ec1fc82f823b83363225ed996fdfb51b259d94f9b9a08b7026920d11bc157221
+    "));
+    check_mac::<Skein1024Mac<U64>>(257, 255, &hex!("
+        2d50e62f9f281ddeddd91276fbc2b614cf119565cad0e52a498ce727b3a981aa
+        b12f527ab9d7aa50014f5ae6fb4ebb5a1225ebf0be6a0feff455d9b785e5702b
+    "));
+}
+
+#[test]
+fn empty_key_is_plain_hash() {
+    let mut mac = <Skein512Mac<U32> as Mac>::new_from_slice(&[]).unwrap();
+    mac.update(b"message");
+    let expected = Skein512::<U32>::digest(b"message");
+    assert_eq!(mac.finalize().into_bytes(), expected);
+}
+
+#[test]
+#[rustfmt::skip]
+fn builder() {
+    const PERS: &[u8] = b"20081031 me@example.com my-app/v1";
+
+    let (key, public_key) = (key(10), msg(70));
+    let builder = SkeinBuilder::new()
+        .key(&key)
+        .personalization(PERS)
+        .public_key(&public_key)
+        .key_id(b"key id")
+        .nonce(b"nonce");
+    let mut h = Skein512::from_core(Skein512Core::<U64>::from_builder(&builder));
+    let expected = hex!("
+        979ee70d76900c68402dc56a614c7cbed4d39f2cd06fedd65244bf45b00a7619
+        037ef0766997a3273805d444d2b1880570045475f683effd8f2886714f359134
+    ");
+    h.update(msg(100));
+    assert_eq!(h.finalize_reset()[..], expected[..]);
+    h.update(msg(100));
+    assert_eq!(h.finalize()[..], expected[..]);
+
+    let builder = SkeinBuilder::new().personalization(PERS).nonce(b"nonce");
+    let mut h = Skein512::from_core(Skein512Core::<U64>::from_builder(&builder));
+    h.update(msg(100));
+    assert_eq!(h.finalize()[..], hex!("
+        eed5d5cf192964b5527d8ee1451e1f6da26b447df2294b9b9b3f52b40fcfa509
+        39a899663ab51d65acfbe1bc532619ba450ea6d676266889414344f369332f24
+    ")[..]);
+}
@@ -0,0 +1,85 @@
+//! Skein tree hashing mode.
+//!
+//! The expected values were computed with an independent implementation
+//! of the Skein 1.3 specification. Messages are `i as u8` sequences.
+use digest::{FixedOutputReset, Update};
+use hex_literal::hex;
+use skein::{
+    consts::{U128, U32, U64},
+    digest, Skein1024Tree, Skein1024TreeCore, Skein256Tree, Skein256TreeCore, Skein512Tree,
+    Skein512TreeCore, SkeinTreeParams,
+};
+
+fn msg(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+fn check<H: Update + FixedOutputReset>(mut h: H, msg_len: usize, expected: &[u8]) {
+    let msg = msg(msg_len);
+    h.update(&msg);
+    assert_eq!(h.finalize_fixed_reset()[..], expected[..]);
+
+    // feed the message in chunks which are not aligned to the block size
+    for chunk in msg.chunks(37) {
+        h.update(chunk);
+    }
+    assert_eq!(h.finalize_fixed_reset()[..], expected[..]);
+}
+
+fn params(leaf_size: u8, fan_out: u8, max_height: u8) -> SkeinTreeParams {
+    SkeinTreeParams::new(leaf_size, fan_out, max_height).unwrap()
+}
+
+#[test]
+fn invalid_params() {
+    assert!(SkeinTreeParams::new(0, 1, 2).is_none());
+    assert!(SkeinTreeParams::new(1, 0, 2).is_none());
+    assert!(SkeinTreeParams::new(1, 1, 1).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn skein256_tree() {
+    let new = |p| Skein256Tree::<U32>::from_core(Skein256TreeCore::new(p));
+    check(new(params(1, 1, 2)), 0, &hex!(
+        "4c2521b1d3ce21b8ea87e126044c1def8b01772e4e3838f7b191b66664aa190c"
+    ));
+    // the tree height limit is reached
+    check(new(params(1, 1, 2)), 200, &hex!(
+        "e01282088dc2b9a9846f8dd4d26772081ea6cebc03d26db836bf19cfbe069ac5"
+    ));
+    check(new(params(1, 1, 255)), 1000, &hex!(
+        "e6b81cd4b5dd53979ff0130d372bc05ca98d21eb4beff6c2f134b8e80cdddd6a"
+    ));
+}
+
+#[test]
+#[rustfmt::skip]
+fn skein512_tree() {
+    let new = |p| Skein512Tree::<U64>::from_core(Skein512TreeCore::new(p));
+    // single leaf
+    check(new(params(1, 1, 2)), 64, &hex!("
+        6b316f3f4a40c10eb65146339d5485ac47f98bd3fea1b38809110fff9ead1ec1
+        e47e965ecae21f8c0818750560c9e3eb535f210e4228fb197445db35b7dabf0e
+    "));
+    check(new(params(2, 1, 4)), 5000, &hex!("
+        d53ba1bf018e9fd408a21afd56e5acbe517a7441778e6e5d880394bb515ea84e
+        59fe87a122ccfcc0da0afc63d4f87460ff5c6d38a4ca4ed3b67c46f7fe2ab52c
+    "));
+    check(new(params(1, 2, 3)), 2049, &hex!("
+        a303126f497351107e300bae880cd0397eb2a788023d0a3620d786ce78bf0bcd
+        dd49c8188afe1ac7f85bee0c65c7451c66194af8746dc3e8fc26dd25e24fe720
+    "));
+}
+
+#[test]
+#[rustfmt::skip]
+fn skein1024_tree() {
+    let h = Skein1024Tree::<U128>::from_core(Skein1024TreeCore::new(params(1, 1, 3)));
+    check(h, 5000, &hex!("
+        45c01dc1032371434fa1560ea4dafc5a5c64caf4c7c18d6c76a3042a63c28620
+        288cdf926d865b8946e8e08cfa2666880f3b706885a4362521f4c052693795e3
+        d2886a4208e359c0e899c30ab3975df3f0e15b649c486c58a07a2c3e2633fcbe
+        00715ae383138e6530a80f6fcc14fb5cca3db364ed7a3fa8d175f7251fbd913b
+    "));
+}
@@ -0,0 +1,68 @@
+use digest::{typenum::Unsigned, Digest, Update, VariableOutput, VariableOutputReset};
+use hex_literal::hex;
+use skein::{
+    consts::{U1, U100, U128, U20, U28, U32, U36, U48, U64, U7},
+    Skein1024, Skein1024Var, Skein256, Skein256Var, Skein512, Skein512Var,
+};
+
+const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+macro_rules! check_fixed {
+    ($var:ident, $fixed:ident, $($size:ident),*) => {$({
+        let n = $size::USIZE;
+        let mut h = $var::new(n).unwrap();
+        let mut out = [0u8; 128];
+        for _ in 0..2 {
+            h.update(MSG);
+            h.finalize_variable_reset(&mut out[..n]).unwrap();
+            assert_eq!(out[..n], $fixed::<$size>::digest(MSG)[..]);
+        }
+    })*};
+}
+
+#[test]
+fn matches_fixed() {
+    check_fixed!(
+        Skein256Var,
+        Skein256,
+        U1,
+        U7,
+        U20,
+        U28,
+        U32,
+        U64,
+        U100,
+        U128
+    );
+    check_fixed!(
+        Skein512Var,
+        Skein512,
+        U1,
+        U20,
+        U32,
+        U36,
+        U48,
+        U64,
+        U100,
+        U128
+    );
+    check_fixed!(Skein1024Var, Skein1024, U1, U20, U64, U100, U128);
+}
+
+#[test]
+fn skein512_160() {
+    let mut h = Skein512Var::new(20).unwrap();
+    h.update(MSG);
+    let mut out = [0u8; 20];
+    h.finalize_variable(&mut out).unwrap();
+    assert_eq!(out, hex!("826325ee55a6dd18c3b2dbbc9c10420f5475975e"));
+}
+
+#[test]
+fn invalid_output_size() {
+    assert!(Skein256Var::new(0).is_err());
+    assert!(Skein256Var::new(1024).is_ok());
+    assert!(Skein256Var::new(1025).is_err());
+    assert!(Skein512Var::new(1025).is_err());
+    assert!(Skein1024Var::new(1025).is_err());
+}
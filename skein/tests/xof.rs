@@ -0,0 +1,98 @@
+use digest::{
+    core_api::CoreWrapper, generic_array::ArrayLength, Digest, ExtendableOutput, Update, XofReader,
+};
+use hex_literal::hex;
+use skein::{
+    consts::{U100, U128, U20, U32, U36, U64},
+    Skein1024, Skein1024Xof, Skein1024XofCore, Skein256, Skein256Xof, Skein256XofCore, Skein512,
+    Skein512Xof, Skein512XofCore,
+};
+
+const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn msg(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+macro_rules! check_fixed {
+    ($xof_core:ident, $fixed:ident, $($size:ident),*) => {$({
+        for &len in &[0, 1, 63, 64, 65, 300] {
+            let msg = msg(len);
+            let n = <$size as digest::typenum::Unsigned>::USIZE;
+            let mut xof = CoreWrapper::from_core($xof_core::new(n));
+            xof.update(&msg);
+            let mut out = vec![0u8; n];
+            xof.finalize_xof_into(&mut out);
+            assert_eq!(out[..], $fixed::<$size>::digest(&msg)[..]);
+        }
+    })*};
+}
+
+#[test]
+fn matches_fixed() {
+    check_fixed!(Skein256XofCore, Skein256, U20, U32, U36, U64, U100);
+    check_fixed!(Skein512XofCore, Skein512, U20, U32, U36, U64, U100, U128);
+    check_fixed!(Skein1024XofCore, Skein1024, U20, U32, U64, U100, U128);
+}
+
+#[test]
+fn default_matches_state_size() {
+    fn check<X: Default + Update + ExtendableOutput, N: ArrayLength<u8>>(expected: &[u8]) {
+        let mut xof = X::default();
+        xof.update(MSG);
+        let mut out = vec![0u8; N::USIZE];
+        xof.finalize_xof_into(&mut out);
+        assert_eq!(out, expected);
+    }
+    check::<Skein256Xof, U32>(&Skein256::<U32>::digest(MSG));
+    check::<Skein512Xof, U64>(&Skein512::<U64>::digest(MSG));
+    check::<Skein1024Xof, U128>(&Skein1024::<U128>::digest(MSG));
+}
+
+#[test]
+fn incremental_read() {
+    let mut xof = CoreWrapper::from_core(Skein1024XofCore::new(300));
+    xof.update(MSG);
+    let mut reader = xof.finalize_xof();
+    let mut out = [0u8; 300];
+    let mut pos = 0;
+    for &len in [1, 7, 100, 128, 0, 64].iter().cycle() {
+        let end = core::cmp::min(pos + len, out.len());
+        reader.read(&mut out[pos..end]);
+        pos = end;
+        if pos == out.len() {
+            break;
+        }
+    }
+    assert_eq!(out[..], Skein1024::<digest::consts::U300>::digest(MSG)[..]);
+
+    // Output of the Skein-1024-2400 hash
+    let expected = hex!(
+        "921a1643557fe467dc98863a58e6c7916a9a43a376eaa126da68974db6622d20"
+        "f60a997c1642368bebb2bfc9b690756c20d0c07a5bbbd2fac6b8c29a283e6f3d"
+        "e949f4d4b5303ae68259d8a8ddae708d918412b438a8738298e0594a2e27b418"
+        "ff5afb8a64accaa5bcaf76862979784af88fc2da7afa59e16f1931709a8b38df"
+        "6f99adbc677b7c95c10942b4ad7ae30ab980a248e1a4d4fe1242b0d4e5ef2412"
+        "33236c2443dbcd4697d3b5c71fdf8c29ee93a99522cb1fffb810bb03f5c654b5"
+        "c9f7fc0b2adf2a583aa4a74556ed49bdf1ff86457798a310ad08fcbc9bc3a5b8"
+        "af90c1fd2c70b81a949f36713bb10a034b3bf0dc444383a03678e80a562260ba"
+        "c7219adefe6d4421bd5a18b93cf4e452af755cb84547f8ff8c7f7db5f5e7953e"
+        "aa331741e6e868013bf4cd49"
+    );
+    assert_eq!(out[..], expected[..]);
+}
+
+#[test]
+fn partial_words() {
+    assert_eq!(
+        Skein512::<U20>::digest(MSG)[..],
+        hex!("826325ee55a6dd18c3b2dbbc9c10420f5475975e")[..],
+    );
+    assert_eq!(
+        Skein256::<U36>::digest(MSG)[..],
+        hex!(
+            "0b4b9611ae70c8d562c8bfba3ac05616fea30081ec40df365c74086d59ddb43e"
+            "c7a251dd"
+        )[..],
+    );
+}
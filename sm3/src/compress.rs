@@ -51,7 +51,7 @@ fn t(i: usize) -> u32 {
     T32[i]
 }
 
-fn sm3_round1(
+pub(crate) fn sm3_round1(
     a: u32,
     b: u32,
     c: u32,
@@ -82,7 +82,7 @@ fn sm3_round1(
     [a, b, c, d, e, f, g, h]
 }
 
-fn sm3_round2(
+pub(crate) fn sm3_round2(
     a: u32,
     b: u32,
     c: u32,
@@ -149,7 +149,8 @@ macro_rules! R2 {
     }};
 }
 
-fn compress_u32(state: &mut [u32; 8], block: &[u32; 16]) {
+/// Compress a block of big-endian message words into `state`.
+pub fn compress_u32(state: &mut [u32; 8], block: &[u32; 16]) {
     let mut x: [u32; 16] = *block;
 
     let mut a = state[0];
@@ -245,3 +246,15 @@ pub(crate) fn compress(state: &mut [u32; 8], blocks: &[Block<Sm3Core>]) {
         compress_u32(state, &w);
     }
 }
+
+pub(crate) fn compress4(states: &mut [[u32; 8]; 4], blocks: &[[u32; 16]; 4]) {
+    for (state, block) in states.iter_mut().zip(blocks.iter()) {
+        compress_u32(state, block);
+    }
+}
+
+pub(crate) fn compress8(states: &mut [[u32; 8]; 8], blocks: &[[u32; 16]; 8]) {
+    for (state, block) in states.iter_mut().zip(blocks.iter()) {
+        compress_u32(state, block);
+    }
+}
@@ -0,0 +1,142 @@
+//! Key derivation function defined in GB/T 32918.4-2016 (SM2).
+//!
+//! `KDF(Z, klen)` is the concatenation of `SM3(Z || ct)` for the 32-bit
+//! big-endian counter `ct = 1, 2, ...`, truncated to `klen` bits. It is used
+//! by SM2 encryption and key exchange.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use sm3::{digest::{ExtendableOutput, Update, XofReader}, kdf::{kdf, Kdf}};
+//!
+//! let z = b"shared secret";
+//!
+//! // one-shot
+//! let mut key = [0u8; 16];
+//! kdf(z, &mut key);
+//!
+//! // streaming
+//! let mut hasher = Kdf::default();
+//! hasher.update(b"shared ");
+//! hasher.update(b"secret");
+//! let mut reader = hasher.finalize_xof();
+//! let mut key2 = [0u8; 16];
+//! reader.read(&mut key2[..5]);
+//! reader.read(&mut key2[5..]);
+//! assert_eq!(key, key2);
+//! ```
+
+use crate::{Sm3, Sm3Core};
+use core::fmt;
+use digest::{
+    block_buffer::Eager,
+    core_api::{
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper,
+        ExtendableOutputCore, Reset, UpdateCore, XofReaderCore, XofReaderCoreWrapper,
+    },
+    typenum::U32,
+    FixedOutput, HashMarker, Update,
+};
+
+/// Core SM3 KDF state which absorbs the shared secret `Z`.
+#[derive(Clone, Default)]
+pub struct KdfCore {
+    core: Sm3Core,
+}
+
+impl HashMarker for KdfCore {}
+
+impl BlockSizeUser for KdfCore {
+    type BlockSize = <Sm3Core as BlockSizeUser>::BlockSize;
+}
+
+impl BufferKindUser for KdfCore {
+    type BufferKind = Eager;
+}
+
+impl UpdateCore for KdfCore {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.core.update_blocks(blocks);
+    }
+}
+
+impl ExtendableOutputCore for KdfCore {
+    type ReaderCore = KdfReaderCore;
+
+    #[inline]
+    fn finalize_xof_core(&mut self, buffer: &mut Buffer<Self>) -> Self::ReaderCore {
+        let mut hasher = Sm3::from_core(self.core.clone());
+        hasher.update(buffer.get_data());
+        KdfReaderCore { hasher, ctr: 1 }
+    }
+}
+
+impl Reset for KdfCore {
+    #[inline]
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
+impl AlgorithmName for KdfCore {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sm3Kdf")
+    }
+}
+
+impl fmt::Debug for KdfCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("KdfCore { ... }")
+    }
+}
+
+/// Core SM3 KDF reader state.
+#[derive(Clone)]
+pub struct KdfReaderCore {
+    hasher: Sm3,
+    ctr: u32,
+}
+
+impl BlockSizeUser for KdfReaderCore {
+    type BlockSize = U32;
+}
+
+impl XofReaderCore for KdfReaderCore {
+    /// # Panics
+    ///
+    /// If more than `(2^32 - 1) * 32` bytes are read, as the counter would
+    /// overflow.
+    #[inline]
+    fn read_block(&mut self) -> Block<Self> {
+        assert_ne!(self.ctr, 0, "SM3 KDF output length limit exceeded");
+        let mut hasher = self.hasher.clone();
+        hasher.update(&self.ctr.to_be_bytes());
+        self.ctr = self.ctr.wrapping_add(1);
+        hasher.finalize_fixed()
+    }
+}
+
+impl fmt::Debug for KdfReaderCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("KdfReaderCore { ... }")
+    }
+}
+
+/// SM3 KDF state.
+pub type Kdf = CoreWrapper<KdfCore>;
+/// SM3 KDF reader.
+pub type KdfReader = XofReaderCoreWrapper<KdfReaderCore>;
+
+/// Derive `out.len()` bytes of key material from the shared secret `z`.
+///
+/// # Panics
+///
+/// If `out` is longer than `(2^32 - 1) * 32` bytes.
+pub fn kdf(z: &[u8], out: &mut [u8]) {
+    use digest::ExtendableOutput;
+
+    let mut hasher = Kdf::default();
+    hasher.update(z);
+    hasher.finalize_xof_into(out);
+}
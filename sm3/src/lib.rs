@@ -23,6 +23,13 @@
 //! ")[..]);
 //! ```
 //!
+//! The SM2 key derivation function built on SM3 is available in the [`kdf`]
+//! module, and the `Z_A` signer identity digest used by SM2 signatures in the
+//! [`sm2`] module.
+//!
+//! Several independent messages can be hashed at once with the multi-buffer
+//! functions in the [`multi`] module.
+//!
 //! Also see [RustCrypto/hashes] readme.
 //!
 //! [SM3]: https://en.wikipedia.org/wiki/SM3_(hash_function)
@@ -33,7 +40,7 @@
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
 )]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
 
 pub use digest::{self, Digest};
@@ -51,8 +58,39 @@ use digest::{
 
 mod compress;
 mod consts;
-
-use compress::compress;
+pub mod kdf;
+pub mod multi;
+pub mod sm2;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+mod x86;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+use x86::{compress, compress4, compress8};
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+)))]
+use compress::{compress, compress4, compress8};
+
+/// Compression function backends compiled for the target, exposed for the
+/// equivalence tests in `tests/`. Not part of the public API.
+#[doc(hidden)]
+pub mod backends {
+    pub use crate::compress::compress_u32 as soft;
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft")
+    ))]
+    pub use crate::x86::{avx2, avx2_x8, sse2_x4};
+}
 
 /// Core SM3 hasher state.
 #[derive(Clone)]
@@ -0,0 +1,98 @@
+//! Multi-buffer SM3 which hashes several independent messages at once.
+//!
+//! On `x86`/`x86_64` the lanes are processed in parallel with SSE2 (4 lanes)
+//! or AVX2 (8 lanes) when the CPU supports it. Messages may have different
+//! lengths, but lanes whose message is exhausted still take part in the
+//! computation, so the best throughput is achieved with messages of similar
+//! length.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use sm3::{multi, Digest, Sm3};
+//!
+//! let msgs: [&[u8]; 4] = [b"a", b"bc", b"def", b"hello world"];
+//! let digests = multi::digest4(msgs);
+//! for (msg, digest) in msgs.iter().zip(digests.iter()) {
+//!     assert_eq!(digest, &Sm3::digest(msg));
+//! }
+//! ```
+
+use crate::{compress4, compress8, consts::H0, Sm3};
+use core::convert::TryInto;
+use digest::Output;
+
+/// Number of padded blocks of a message with length `len`.
+fn block_count(len: usize) -> usize {
+    (len + 8) / 64 + 1
+}
+
+/// Read block `i` of the padded message into `block`.
+fn read_block(msg: &[u8], i: usize, block: &mut [u32; 16]) {
+    let mut buf = [0u8; 64];
+    let start = 64 * i;
+    if start < msg.len() {
+        let end = core::cmp::min(start + 64, msg.len());
+        buf[..end - start].copy_from_slice(&msg[start..end]);
+    }
+    if (start..start + 64).contains(&msg.len()) {
+        buf[msg.len() - start] = 0x80;
+    }
+    if i + 1 == block_count(msg.len()) {
+        let bit_len = 8 * msg.len() as u64;
+        buf[56..].copy_from_slice(&bit_len.to_be_bytes());
+    }
+    for (w, chunk) in block.iter_mut().zip(buf.chunks_exact(4)) {
+        *w = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+}
+
+macro_rules! digest_lanes {
+    ($name:ident, $lanes:literal, $compress:ident, $doc:expr) => {
+        #[doc = $doc]
+        pub fn $name(msgs: [&[u8]; $lanes]) -> [Output<Sm3>; $lanes] {
+            let mut counts = [0; $lanes];
+            for (count, msg) in counts.iter_mut().zip(msgs.iter()) {
+                *count = block_count(msg.len());
+            }
+            let max_count = counts.iter().copied().max().unwrap_or(0);
+
+            let mut states = [H0; $lanes];
+            let mut blocks = [[0u32; 16]; $lanes];
+            for i in 0..max_count {
+                for (block, msg) in blocks.iter_mut().zip(msgs.iter()) {
+                    read_block(msg, i, block);
+                }
+                let prev = states;
+                $compress(&mut states, &blocks);
+                // restore the states of lanes which are already finished
+                for ((state, prev), &count) in states.iter_mut().zip(prev.iter()).zip(&counts) {
+                    if i >= count {
+                        *state = *prev;
+                    }
+                }
+            }
+
+            let mut out: [Output<Sm3>; $lanes] = Default::default();
+            for (o, state) in out.iter_mut().zip(states.iter()) {
+                for (chunk, v) in o.chunks_exact_mut(4).zip(state.iter()) {
+                    chunk.copy_from_slice(&v.to_be_bytes());
+                }
+            }
+            out
+        }
+    };
+}
+
+digest_lanes!(
+    digest4,
+    4,
+    compress4,
+    "Compute SM3 digests of 4 messages at once."
+);
+digest_lanes!(
+    digest8,
+    8,
+    compress8,
+    "Compute SM3 digests of 8 messages at once."
+);
@@ -0,0 +1,119 @@
+//! Signer identity digest used by SM2 signatures (GB/T 32918.2-2016).
+//!
+//! Before signing or verifying, the message is prefixed with
+//!
+//! ```text
+//! Z_A = SM3(ENTL_A || ID_A || a || b || x_G || y_G || x_A || y_A)
+//! ```
+//!
+//! where `ENTL_A` is the bit length of the signer identity `ID_A` as a
+//! 16-bit big-endian integer, `a`, `b`, `x_G` and `y_G` are the curve
+//! parameters and `(x_A, y_A)` is the signer public key. Curve elements are
+//! passed as big-endian byte strings, so no elliptic curve arithmetic is
+//! needed here.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use sm3::{sm2::{self, DEFAULT_ID, SM2P256V1}, Digest};
+//! # let (x_a, y_a) = ([0u8; 32], [0u8; 32]);
+//!
+//! let mut hasher = sm2::message_hasher(DEFAULT_ID, &SM2P256V1, &x_a, &y_a).unwrap();
+//! hasher.update(b"message digest");
+//! let e = hasher.finalize();
+//! ```
+
+use crate::Sm3;
+use core::convert::TryFrom;
+use digest::{crypto_common::InvalidLength, FixedOutput, Output, Update};
+
+/// Default signer identity defined in GM/T 0009-2012.
+pub const DEFAULT_ID: &[u8] = b"1234567812345678";
+
+/// Elliptic curve parameters which enter the `Z_A` computation.
+///
+/// All values are big-endian byte strings of the field element size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Curve<'a> {
+    /// Coefficient `a` of the curve equation.
+    pub a: &'a [u8],
+    /// Coefficient `b` of the curve equation.
+    pub b: &'a [u8],
+    /// Affine x-coordinate of the base point.
+    pub x_g: &'a [u8],
+    /// Affine y-coordinate of the base point.
+    pub y_g: &'a [u8],
+}
+
+/// Recommended 256-bit curve from GB/T 32918.5-2017.
+#[rustfmt::skip]
+pub const SM2P256V1: Curve<'static> = Curve {
+    a: &[
+        0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+    ],
+    b: &[
+        0x28, 0xe9, 0xfa, 0x9e, 0x9d, 0x9f, 0x5e, 0x34,
+        0x4d, 0x5a, 0x9e, 0x4b, 0xcf, 0x65, 0x09, 0xa7,
+        0xf3, 0x97, 0x89, 0xf5, 0x15, 0xab, 0x8f, 0x92,
+        0xdd, 0xbc, 0xbd, 0x41, 0x4d, 0x94, 0x0e, 0x93,
+    ],
+    x_g: &[
+        0x32, 0xc4, 0xae, 0x2c, 0x1f, 0x19, 0x81, 0x19,
+        0x5f, 0x99, 0x04, 0x46, 0x6a, 0x39, 0xc9, 0x94,
+        0x8f, 0xe3, 0x0b, 0xbf, 0xf2, 0x66, 0x0b, 0xe1,
+        0x71, 0x5a, 0x45, 0x89, 0x33, 0x4c, 0x74, 0xc7,
+    ],
+    y_g: &[
+        0xbc, 0x37, 0x36, 0xa2, 0xf4, 0xf6, 0x77, 0x9c,
+        0x59, 0xbd, 0xce, 0xe3, 0x6b, 0x69, 0x21, 0x53,
+        0xd0, 0xa9, 0x87, 0x7c, 0xc6, 0x2a, 0x47, 0x40,
+        0x02, 0xdf, 0x32, 0xe5, 0x21, 0x39, 0xf0, 0xa0,
+    ],
+};
+
+/// Compute `Z_A` for the signer identity `id` and public key `(x_a, y_a)`.
+///
+/// Returns an error if `id` is 8192 bytes or longer, as its bit length
+/// does not fit into `ENTL_A`.
+pub fn z_a(
+    id: &[u8],
+    curve: &Curve<'_>,
+    x_a: &[u8],
+    y_a: &[u8],
+) -> Result<Output<Sm3>, InvalidLength> {
+    let entl = id
+        .len()
+        .checked_mul(8)
+        .and_then(|bits| u16::try_from(bits).ok())
+        .ok_or(InvalidLength)?;
+
+    let mut hasher = Sm3::default();
+    hasher.update(&entl.to_be_bytes());
+    hasher.update(id);
+    hasher.update(curve.a);
+    hasher.update(curve.b);
+    hasher.update(curve.x_g);
+    hasher.update(curve.y_g);
+    hasher.update(x_a);
+    hasher.update(y_a);
+    Ok(hasher.finalize_fixed())
+}
+
+/// Create an [`Sm3`] hasher which has already absorbed `Z_A`, ready to
+/// process the message to be signed or verified.
+///
+/// See [`z_a`] for the description of arguments and errors.
+pub fn message_hasher(
+    id: &[u8],
+    curve: &Curve<'_>,
+    x_a: &[u8],
+    y_a: &[u8],
+) -> Result<Sm3, InvalidLength> {
+    let z_a = z_a(id, curve, x_a, y_a)?;
+    let mut hasher = Sm3::default();
+    hasher.update(&z_a);
+    Ok(hasher)
+}
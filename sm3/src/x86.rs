@@ -0,0 +1,285 @@
+//! `x86`/`x86_64` backends.
+//!
+//! A single stream uses AVX2 for the message expansion, which is computed
+//! four words at a time in vector registers interleaved with the scalar
+//! rounds. The rounds themselves are inherently sequential.
+//!
+//! The multi-buffer functions process one block of 4 (SSE2) or 8 (AVX2)
+//! independent streams at once, with every vector lane holding the state
+//! of one stream.
+#![allow(unsafe_code)]
+#![allow(clippy::many_single_char_names)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{
+    compress::{self as soft},
+    consts::T32,
+    Block, Sm3Core,
+};
+
+cpufeatures::new!(avx2_cpuid, "avx2");
+cpufeatures::new!(sse2_cpuid, "sse2");
+
+pub(crate) fn compress(state: &mut [u32; 8], blocks: &[Block<Sm3Core>]) {
+    // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
+    // after stabilization
+    if avx2_cpuid::get() {
+        unsafe { compress_avx2(state, blocks) }
+    } else {
+        soft::compress(state, blocks)
+    }
+}
+
+pub(crate) fn compress4(states: &mut [[u32; 8]; 4], blocks: &[[u32; 16]; 4]) {
+    if sse2_cpuid::get() {
+        unsafe { compress4_sse2(states, blocks) }
+    } else {
+        soft::compress4(states, blocks)
+    }
+}
+
+pub(crate) fn compress8(states: &mut [[u32; 8]; 8], blocks: &[[u32; 16]; 8]) {
+    if avx2_cpuid::get() {
+        unsafe { compress8_avx2(states, blocks) }
+    } else if sse2_cpuid::get() {
+        let mut lo_states = [[0; 8]; 4];
+        let mut hi_states = [[0; 8]; 4];
+        let mut lo_blocks = [[0; 16]; 4];
+        let mut hi_blocks = [[0; 16]; 4];
+        lo_states.copy_from_slice(&states[..4]);
+        hi_states.copy_from_slice(&states[4..]);
+        lo_blocks.copy_from_slice(&blocks[..4]);
+        hi_blocks.copy_from_slice(&blocks[4..]);
+        unsafe {
+            compress4_sse2(&mut lo_states, &lo_blocks);
+            compress4_sse2(&mut hi_states, &hi_blocks);
+        }
+        states[..4].copy_from_slice(&lo_states);
+        states[4..].copy_from_slice(&hi_states);
+    } else {
+        soft::compress8(states, blocks)
+    }
+}
+
+macro_rules! backend {
+    ($name:ident, $cpuid:ident, $f:ident, $state:ty, $blocks:ty) => {
+        /// Run the backend if the CPU supports it, returns `false` otherwise.
+        pub fn $name(state: &mut $state, blocks: &$blocks) -> bool {
+            let supported = $cpuid::get();
+            if supported {
+                unsafe { $f(state, blocks) }
+            }
+            supported
+        }
+    };
+}
+
+backend!(avx2, avx2_cpuid, compress_avx2, [u32; 8], [Block<Sm3Core>]);
+backend!(
+    sse2_x4,
+    sse2_cpuid,
+    compress4_sse2,
+    [[u32; 8]; 4],
+    [[u32; 16]; 4]
+);
+backend!(
+    avx2_x8,
+    avx2_cpuid,
+    compress8_avx2,
+    [[u32; 8]; 8],
+    [[u32; 16]; 8]
+);
+
+macro_rules! rotl {
+    ($x:expr, $n:expr) => {
+        _mm_or_si128(_mm_slli_epi32($x, $n), _mm_srli_epi32($x, 32 - $n))
+    };
+}
+
+/// Compute `W[j..j + 4]` from `x0 = W[j - 16..j - 12]` up to
+/// `x3 = W[j - 4..j]`.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn schedule(x0: __m128i, x1: __m128i, x2: __m128i, x3: __m128i) -> __m128i {
+    let w9 = _mm_alignr_epi8(x2, x1, 12);
+    let w13 = _mm_alignr_epi8(x1, x0, 12);
+    let w6 = _mm_alignr_epi8(x3, x2, 8);
+    // `W[j]` is not known yet, so the highest lane uses zero instead
+    let w3 = _mm_srli_si128(x3, 4);
+
+    let t = _mm_xor_si128(_mm_xor_si128(x0, w9), rotl!(w3, 15));
+    let t = _mm_xor_si128(_mm_xor_si128(t, rotl!(t, 15)), rotl!(t, 23));
+    let r = _mm_xor_si128(_mm_xor_si128(t, rotl!(w13, 7)), w6);
+
+    // P1 is linear, so the missing `W[j] <<< 15` term can be added to the
+    // highest lane afterwards
+    let u = _mm_slli_si128(r, 12);
+    let u = rotl!(u, 15);
+    let u = _mm_xor_si128(_mm_xor_si128(u, rotl!(u, 15)), rotl!(u, 23));
+    _mm_xor_si128(r, u)
+}
+
+macro_rules! rounds4 {
+    ($round:path, $v:ident, $j:expr, $w:ident, $w4:ident) => {
+        for k in 0..4 {
+            let [a, b, c, d, e, f, g, h] = $v;
+            let s = $round(a, b, c, d, e, f, g, h, T32[$j + k], $w[k], $w4[k]);
+            $v = [s[3], s[0], s[1], s[2], s[7], s[4], s[5], s[6]];
+        }
+    };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn compress_avx2(state: &mut [u32; 8], blocks: &[Block<Sm3Core>]) {
+    let bswap = _mm_set_epi8(12, 13, 14, 15, 8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3);
+    for block in blocks {
+        let mut x = [_mm_setzero_si128(); 4];
+        for (i, xi) in x.iter_mut().enumerate() {
+            let m = _mm_loadu_si128(block.as_ptr().add(16 * i) as *const __m128i);
+            *xi = _mm_shuffle_epi8(m, bswap);
+        }
+
+        let mut v = *state;
+        for g in 0..16 {
+            // words for the next four rounds: `W[j..j + 4]` and `W[j + 4..j + 8]`
+            let mut w = [0u32; 4];
+            let mut w4 = [0u32; 4];
+            _mm_storeu_si128(w.as_mut_ptr() as *mut __m128i, x[0]);
+            _mm_storeu_si128(w4.as_mut_ptr() as *mut __m128i, x[1]);
+
+            let next = if g < 13 {
+                schedule(x[0], x[1], x[2], x[3])
+            } else {
+                _mm_setzero_si128()
+            };
+            x = [x[1], x[2], x[3], next];
+
+            if g < 4 {
+                rounds4!(soft::sm3_round1, v, 4 * g, w, w4);
+            } else {
+                rounds4!(soft::sm3_round2, v, 4 * g, w, w4);
+            }
+        }
+
+        for (s, v) in state.iter_mut().zip(v.iter()) {
+            *s ^= v;
+        }
+    }
+}
+
+macro_rules! impl_lanes {
+    (
+        $name:ident, $feature:literal, $lanes:literal, $vec:ident,
+        $set1:ident, $add:ident, $xor:ident, $and:ident, $or:ident, $andnot:ident,
+        $sll:ident, $srl:ident, $loadu:ident, $storeu:ident
+    ) => {
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(states: &mut [[u32; 8]; $lanes], blocks: &[[u32; 16]; $lanes]) {
+            #[inline]
+            #[target_feature(enable = $feature)]
+            unsafe fn rotl(x: $vec, n: i32) -> $vec {
+                let l = $sll(x, _mm_cvtsi32_si128(n));
+                let r = $srl(x, _mm_cvtsi32_si128(32 - n));
+                $or(l, r)
+            }
+
+            #[inline]
+            #[target_feature(enable = $feature)]
+            unsafe fn round(s: &mut [$vec; 8], t: u32, w1: $vec, w2: $vec, first: bool) {
+                let [a, b, c, d, e, f, g, h] = *s;
+                let a12 = rotl(a, 12);
+                let ss1 = rotl($add($add(a12, e), $set1(t as i32)), 7);
+                let ss2 = $xor(ss1, a12);
+                let (ff, gg) = if first {
+                    ($xor($xor(a, b), c), $xor($xor(e, f), g))
+                } else {
+                    (
+                        $or($or($and(a, b), $and(a, c)), $and(b, c)),
+                        $or($and(e, f), $andnot(e, g)),
+                    )
+                };
+                let tt1 = $add($add($add(ff, d), ss2), $xor(w1, w2));
+                let tt2 = $add($add($add(gg, h), ss1), w1);
+                let p0 = $xor($xor(tt2, rotl(tt2, 9)), rotl(tt2, 17));
+                *s = [tt1, a, rotl(b, 9), c, p0, e, rotl(f, 19), g];
+            }
+
+            let mut w = [$set1(0); 68];
+            for j in 0..16 {
+                let mut words = [0u32; $lanes];
+                for (word, block) in words.iter_mut().zip(blocks.iter()) {
+                    *word = block[j];
+                }
+                w[j] = $loadu(words.as_ptr() as *const $vec);
+            }
+            for j in 16..68 {
+                let t = $xor($xor(w[j - 16], w[j - 9]), rotl(w[j - 3], 15));
+                let t = $xor($xor(t, rotl(t, 15)), rotl(t, 23));
+                w[j] = $xor($xor(t, rotl(w[j - 13], 7)), w[j - 6]);
+            }
+
+            let mut v = [$set1(0); 8];
+            for (i, vi) in v.iter_mut().enumerate() {
+                let mut words = [0u32; $lanes];
+                for (word, state) in words.iter_mut().zip(states.iter()) {
+                    *word = state[i];
+                }
+                *vi = $loadu(words.as_ptr() as *const $vec);
+            }
+
+            let mut s = v;
+            for j in 0..16 {
+                round(&mut s, T32[j], w[j], w[j + 4], true);
+            }
+            for j in 16..64 {
+                round(&mut s, T32[j], w[j], w[j + 4], false);
+            }
+
+            for i in 0..8 {
+                let mut words = [0u32; $lanes];
+                $storeu(words.as_mut_ptr() as *mut $vec, $xor(v[i], s[i]));
+                for (word, state) in words.iter().zip(states.iter_mut()) {
+                    state[i] = *word;
+                }
+            }
+        }
+    };
+}
+
+impl_lanes!(
+    compress4_sse2,
+    "sse2",
+    4,
+    __m128i,
+    _mm_set1_epi32,
+    _mm_add_epi32,
+    _mm_xor_si128,
+    _mm_and_si128,
+    _mm_or_si128,
+    _mm_andnot_si128,
+    _mm_sll_epi32,
+    _mm_srl_epi32,
+    _mm_loadu_si128,
+    _mm_storeu_si128
+);
+
+impl_lanes!(
+    compress8_avx2,
+    "avx2",
+    8,
+    __m256i,
+    _mm256_set1_epi32,
+    _mm256_add_epi32,
+    _mm256_xor_si256,
+    _mm256_and_si256,
+    _mm256_or_si256,
+    _mm256_andnot_si256,
+    _mm256_sll_epi32,
+    _mm256_srl_epi32,
+    _mm256_loadu_si256,
+    _mm256_storeu_si256
+);
@@ -0,0 +1,126 @@
+//! Equivalence tests of the SIMD backends against the scalar compression
+//! function. Backends not supported by the CPU running the tests are skipped
+//! with a message.
+#![cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "force-soft")
+))]
+
+use sm3::backends;
+use sm3::digest::generic_array::GenericArray;
+
+fn rand_u32(seed: &mut u64) -> u32 {
+    // xorshift64
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed as u32
+}
+
+fn rand_state(seed: &mut u64) -> [u32; 8] {
+    let mut state = [0u32; 8];
+    for v in state.iter_mut() {
+        *v = rand_u32(seed);
+    }
+    state
+}
+
+/// Random inputs of `lanes` streams with unequal block counts below 10.
+fn rand_streams(seed: &mut u64, lanes: usize) -> Vec<Vec<[u32; 16]>> {
+    (0..lanes)
+        .map(|_| {
+            let n = rand_u32(seed) as usize % 10;
+            (0..n)
+                .map(|_| {
+                    let mut block = [0u32; 16];
+                    for w in block.iter_mut() {
+                        *w = rand_u32(seed);
+                    }
+                    block
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn soft_streams(states: &mut [[u32; 8]], streams: &[Vec<[u32; 16]>]) {
+    for (state, stream) in states.iter_mut().zip(streams.iter()) {
+        for block in stream {
+            backends::soft(state, block);
+        }
+    }
+}
+
+#[test]
+fn avx2_matches_soft() {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    for _ in 0..16 {
+        let blocks = &rand_streams(&mut seed, 1)[0];
+        let bytes: Vec<_> = blocks
+            .iter()
+            .map(|block| {
+                let mut b = GenericArray::default();
+                for (chunk, w) in b.chunks_exact_mut(4).zip(block.iter()) {
+                    chunk.copy_from_slice(&w.to_be_bytes());
+                }
+                b
+            })
+            .collect();
+
+        let state = rand_state(&mut seed);
+        let mut expected = [state];
+        soft_streams(&mut expected, &[blocks.clone()]);
+        let mut res = state;
+        if !backends::avx2(&mut res, &bytes) {
+            eprintln!("AVX2 is not supported by the CPU, skipping");
+            return;
+        }
+        assert_eq!(res, expected[0], "{} blocks", blocks.len());
+    }
+}
+
+macro_rules! lanes_test {
+    ($test:ident, $f:ident, $name:literal, $lanes:literal) => {
+        #[test]
+        fn $test() {
+            let mut seed = 0x0123_4567_89ab_cdef;
+            for _ in 0..16 {
+                let streams = rand_streams(&mut seed, $lanes);
+                let mut states = [[0u32; 8]; $lanes];
+                for state in states.iter_mut() {
+                    *state = rand_state(&mut seed);
+                }
+                let mut expected = states;
+                soft_streams(&mut expected, &streams);
+
+                // lanes of exhausted streams compress zero blocks, their
+                // states are restored afterwards
+                let max = streams.iter().map(|s| s.len()).max().unwrap();
+                for i in 0..max {
+                    let mut blocks = [[0u32; 16]; $lanes];
+                    for (block, stream) in blocks.iter_mut().zip(streams.iter()) {
+                        if let Some(b) = stream.get(i) {
+                            *block = *b;
+                        }
+                    }
+                    let prev = states;
+                    if !backends::$f(&mut states, &blocks) {
+                        eprintln!("{} is not supported by the CPU, skipping", $name);
+                        return;
+                    }
+                    for ((state, prev), stream) in
+                        states.iter_mut().zip(prev.iter()).zip(streams.iter())
+                    {
+                        if i >= stream.len() {
+                            *state = *prev;
+                        }
+                    }
+                }
+                assert_eq!(states, expected);
+            }
+        }
+    };
+}
+
+lanes_test!(sse2_x4_matches_soft, sse2_x4, "SSE2", 4);
+lanes_test!(avx2_x8_matches_soft, avx2_x8, "AVX2", 8);
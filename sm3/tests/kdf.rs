@@ -0,0 +1,67 @@
+use hex_literal::hex;
+use sm3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    kdf::{kdf, Kdf},
+};
+
+/// `x2 || y2` from the SM2 encryption example on the recommended curve
+/// in GB/T 32918.5-2017, with `klen = 152` bits.
+#[test]
+fn gbt32918_encryption() {
+    let z = hex!(
+        "
+        335e18d751e51f040e27d468138b7ab1dc86ad7f981d7d416222fd6ab3ed230d
+        ab743ebcfb22d64f7b6ab791f70658f25b48fa93e54064fdbfbed3f0bd847ac9
+    "
+    );
+    let mut t = [0u8; 19];
+    kdf(&z, &mut t);
+    assert_eq!(t, hex!("44e60fdbf0bae81437665374bef26749046c9e"));
+
+    // C2 = M ^ t
+    let c2: Vec<u8> = b"encryption standard"
+        .iter()
+        .zip(t.iter())
+        .map(|(m, t)| m ^ t)
+        .collect();
+    assert_eq!(c2[..], hex!("21886ca989ca9c7d58087307ca93092d651efa")[..]);
+}
+
+// The vectors below were computed with the OpenSSL SM3 implementation.
+#[test]
+fn multi_block() {
+    let mut out = [0u8; 33];
+    kdf(b"", &mut out);
+    assert_eq!(
+        out[..],
+        hex!("88c0cffa4c713446a03f1fff1630aa6353bdb53e2a9272146be7a82fde06afa3da")[..]
+    );
+}
+
+#[test]
+fn streaming() {
+    let z: Vec<u8> = (0..100).map(|i| i as u8).collect();
+    let expected = hex!(
+        "
+        7256be0931ee006a0c2abf0f301fb3d16be504ed417238dae0bdb3fdfa90a934
+        21191b6a9a887b460789f30e9bbeb322289ed0f5900df20d3bb23ca40c6b844d
+        2aa736a520a953e7ff9fc85481c32459df2032e1f3f756d658d054dd28dffa19
+        c9b864a4
+    "
+    );
+
+    let mut out = [0u8; 100];
+    kdf(&z, &mut out);
+    assert_eq!(out[..], expected[..]);
+
+    let mut h = Kdf::default();
+    for chunk in z.chunks(7) {
+        h.update(chunk);
+    }
+    let mut reader = h.finalize_xof();
+    let mut out = [0u8; 100];
+    for chunk in out.chunks_mut(13) {
+        reader.read(chunk);
+    }
+    assert_eq!(out[..], expected[..]);
+}
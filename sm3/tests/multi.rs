@@ -0,0 +1,49 @@
+use sm3::{multi, Digest, Sm3};
+
+fn msg(len: usize, seed: u8) -> Vec<u8> {
+    (0..len)
+        .map(|i| (i as u8).wrapping_mul(31) ^ seed)
+        .collect()
+}
+
+#[test]
+fn digest4_matches_sm3() {
+    let lens = [0, 1, 55, 56, 63, 64, 65, 119, 120, 128, 200, 1000];
+    for (i, &len) in lens.iter().enumerate() {
+        let msgs: Vec<Vec<u8>> = (0..4)
+            .map(|j| msg(lens[(i + 3 * j) % lens.len()] + j, j as u8))
+            .collect();
+        let refs = [&msgs[0][..], &msgs[1][..], &msgs[2][..], &msgs[3][..]];
+        let digests = multi::digest4(refs);
+        for (m, d) in msgs.iter().zip(digests.iter()) {
+            assert_eq!(d, &Sm3::digest(m), "len: {}", len);
+        }
+    }
+}
+
+#[test]
+fn digest8_matches_sm3() {
+    for len in 0..300 {
+        let msgs: Vec<Vec<u8>> = (0..8).map(|j| msg(len + 7 * j, j as u8)).collect();
+        let mut refs: [&[u8]; 8] = Default::default();
+        for (r, m) in refs.iter_mut().zip(msgs.iter()) {
+            *r = m;
+        }
+        let digests = multi::digest8(refs);
+        for (m, d) in msgs.iter().zip(digests.iter()) {
+            assert_eq!(d, &Sm3::digest(m), "len: {}", m.len());
+        }
+    }
+}
+
+#[test]
+fn equal_lengths() {
+    let m = msg(4096, 0x5a);
+    let expected = Sm3::digest(&m);
+    for d in multi::digest8([&m[..]; 8]).iter() {
+        assert_eq!(d, &expected);
+    }
+    for d in multi::digest4([&m[..]; 4]).iter() {
+        assert_eq!(d, &expected);
+    }
+}
@@ -0,0 +1,61 @@
+use hex_literal::hex;
+use sm3::{
+    sm2::{self, Curve, DEFAULT_ID, SM2P256V1},
+    Digest,
+};
+
+/// Signature example over the 256-bit test curve from GM/T 0003.2-2012.
+#[test]
+fn gmt0003_example() {
+    let curve = Curve {
+        a: &hex!("787968B4FA32C3FD2417842E73BBFEFF2F3C848B6831D7E0EC65228B3937E498"),
+        b: &hex!("63E4C6D3B23B0C849CF84241484BFE48F61D59A5B16BA06E6E12D1DA27C5249A"),
+        x_g: &hex!("421DEBD61B62EAB6746434EBC3CC315E32220B3BADD50BDC4C4E6C147FEDD43D"),
+        y_g: &hex!("0680512BCBB42C07D47349D2153B70C4E5D7FDFCBFA36EA1A85841B9E46E09A2"),
+    };
+    let x_a = hex!("0AE4C7798AA0F119471BEE11825BE46202BB79E2A5844495E97C04FF4DF2548A");
+    let y_a = hex!("7C0240F88F1CD4E16352A73C17B7F16F07353E53A176D684A9FE0C6BB798E857");
+    let id = b"ALICE123@YAHOO.COM";
+
+    let z_a = sm2::z_a(id, &curve, &x_a, &y_a).unwrap();
+    assert_eq!(
+        z_a[..],
+        hex!("F4A38489E32B45B6F876E3AC2168CA392362DC8F23459C1D1146FC3DBFB7BC9A")[..]
+    );
+
+    let mut hasher = sm2::message_hasher(id, &curve, &x_a, &y_a).unwrap();
+    hasher.update(b"message digest");
+    assert_eq!(
+        hasher.finalize()[..],
+        hex!("B524F552CD82B8B028476E005C377FB19A87E6FC682D48BB5D42E3D9B9EFFE76")[..]
+    );
+}
+
+/// Default identity on the recommended curve from GM/T 0003.5-2012.
+#[test]
+fn default_id() {
+    let x_a = hex!("09F9DF311E5421A150DD7D161E4BC5C672179FAD1833FC076BB08FF356F35020");
+    let y_a = hex!("CCEA490CE26775A52DC6EA718CC1AA600AED05FBF35E084A6632F6072DA9AD13");
+
+    let z_a = sm2::z_a(DEFAULT_ID, &SM2P256V1, &x_a, &y_a).unwrap();
+    assert_eq!(
+        z_a[..],
+        hex!("B2E14C5C79C6DF5B85F4FE7ED8DB7A262B9DA7E07CCB0EA9F4747B8CCDA8A4F3")[..]
+    );
+
+    let mut hasher = sm2::message_hasher(DEFAULT_ID, &SM2P256V1, &x_a, &y_a).unwrap();
+    hasher.update(b"message digest");
+    assert_eq!(
+        hasher.finalize()[..],
+        hex!("F0B43E94BA45ACCAACE692ED534382EB17E6AB5A19CE7B31F4486FDFC0D28640")[..]
+    );
+}
+
+#[test]
+fn id_too_long() {
+    let id = [0u8; 8192];
+    let (x_a, y_a) = ([0u8; 32], [0u8; 32]);
+    assert!(sm2::z_a(&id[..8191], &SM2P256V1, &x_a, &y_a).is_ok());
+    assert!(sm2::z_a(&id, &SM2P256V1, &x_a, &y_a).is_err());
+    assert!(sm2::message_hasher(&id, &SM2P256V1, &x_a, &y_a).is_err());
+}
@@ -1,12 +1,12 @@
 //! Constants defined in the Streebog specification
 //!
 //! In the code for optimization purposes `table::SHUFFLED_LIN_TABLE` is used
-//! instead of `A` and `P`
+//! instead of `A` and `P`, unless the `size_opt` feature is enabled
 
 pub const BLOCK_SIZE: usize = 64;
 
 /// Linear transformation matrix
-#[cfg(test)]
+#[cfg(any(test, feature = "size_opt"))]
 pub const A: [u64; BLOCK_SIZE] = [
     0x641c314b2b8ee083,
     0xc83862965601dd1b,
@@ -75,7 +75,7 @@ pub const A: [u64; BLOCK_SIZE] = [
 ];
 
 /// Substitution table
-#[cfg(test)]
+#[cfg(any(test, feature = "size_opt"))]
 pub const P: [u8; 256] = [
     252, 238, 221, 17, 207, 110, 49, 22, 251, 196, 250, 218, 35, 197, 4, 77, 233, 119, 240, 219,
     147, 46, 153, 186, 23, 54, 241, 187, 20, 205, 95, 193, 249, 24, 101, 90, 226, 92, 239, 33, 129,
@@ -10,7 +10,6 @@ use digest::{
 };
 
 use crate::consts::{BLOCK_SIZE, C};
-use crate::table::SHUFFLED_LIN_TABLE;
 
 type Block = [u8; 64];
 
@@ -25,8 +24,11 @@ pub struct StreebogVarCore {
     sigma: [u64; 8],
 }
 
+#[cfg(not(feature = "size_opt"))]
 #[inline(always)]
 fn lps(h: &mut Block, n: &Block) {
+    use crate::table::SHUFFLED_LIN_TABLE;
+
     for i in 0..64 {
         h[i] ^= n[i];
     }
@@ -45,6 +47,27 @@ fn lps(h: &mut Block, n: &Block) {
     *h = to_bytes(&buf);
 }
 
+/// Compact version of LPS which applies the S-box, the byte transposition
+/// and multiplication by the matrix `A` directly.
+#[cfg(feature = "size_opt")]
+fn lps(h: &mut Block, n: &Block) {
+    use crate::consts::{A, P};
+
+    let mut buf = [0u64; 8];
+
+    for (i, v) in buf.iter_mut().enumerate() {
+        for j in 0..8 {
+            let b = P[(h[i + 8 * j] ^ n[i + 8 * j]) as usize];
+            for k in 0..8 {
+                let mask = 0u64.wrapping_sub(((b >> k) & 1) as u64);
+                *v ^= A[8 * j + k] & mask;
+            }
+        }
+    }
+
+    *h = to_bytes(&buf);
+}
+
 impl StreebogVarCore {
     fn g(&mut self, n: &Block, m: &Block) {
         let mut key = [0u8; 64];
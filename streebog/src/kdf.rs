@@ -0,0 +1,69 @@
+//! Key derivation functions defined in R 50.1.113-2016.
+
+use crate::HmacStreebog256;
+use digest::{crypto_common::InvalidLength, Output};
+use hmac::Mac;
+
+/// `KDF_256` function.
+///
+/// Computes `HMAC_GOSTR3411_2012_256(key, 0x01 | label | 0x00 | seed | 0x01 | 0x00)`.
+pub fn kdf_256(key: &[u8], label: &[u8], seed: &[u8]) -> Output<HmacStreebog256> {
+    let mut mac = new_mac(key);
+    mac.update(&[0x01]);
+    mac.update(label);
+    mac.update(&[0x00]);
+    mac.update(seed);
+    mac.update(&[0x01, 0x00]);
+    mac.finalize().into_bytes()
+}
+
+/// `KDF_TREE_GOSTR3411_2012_256` function.
+///
+/// Fills `out` with `K(1) | K(2) | ...`, where
+/// `K(i) = HMAC_GOSTR3411_2012_256(key, [i]_R | label | 0x00 | seed | [L]_l_len)`.
+/// The block counter `i` is encoded as a big-endian number of `r` bytes,
+/// and the output length `L` in bits as a big-endian number of `l_len`
+/// bytes. The test examples of R 50.1.113-2016 use `r = 1` and `l_len = 2`.
+///
+/// Returns an error if `out` is empty, `r` is not in the range `1..=4`,
+/// `l_len` is not in the range `1..=8`, or either `L` or the number of
+/// blocks does not fit into its encoding.
+pub fn kdf_tree_256(
+    key: &[u8],
+    label: &[u8],
+    seed: &[u8],
+    r: usize,
+    l_len: usize,
+    out: &mut [u8],
+) -> Result<(), InvalidLength> {
+    const BLOCK: usize = 32;
+
+    if out.is_empty() || !(1..=4).contains(&r) || !(1..=8).contains(&l_len) {
+        return Err(InvalidLength);
+    }
+    let l = (out.len() as u64).checked_mul(8).ok_or(InvalidLength)?;
+    let blocks = ((out.len() - 1) / BLOCK + 1) as u64;
+    if l_len < 8 && l >> (8 * l_len) != 0 || blocks >> (8 * r) != 0 {
+        return Err(InvalidLength);
+    }
+
+    let l = l.to_be_bytes();
+    let mac = new_mac(key);
+    for (i, chunk) in out.chunks_mut(BLOCK).enumerate() {
+        let i = (i as u64 + 1).to_be_bytes();
+        let mut mac = mac.clone();
+        mac.update(&i[8 - r..]);
+        mac.update(label);
+        mac.update(&[0x00]);
+        mac.update(seed);
+        mac.update(&l[8 - l_len..]);
+        let k = mac.finalize().into_bytes();
+        chunk.copy_from_slice(&k[..chunk.len()]);
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn new_mac(key: &[u8]) -> HmacStreebog256 {
+    Mac::new_from_slice(key).expect("HMAC accepts keys of any length")
+}
@@ -28,6 +28,23 @@
 //! ")[..]);
 //! ```
 //!
+//! With the `hmac` feature enabled, HMAC and key derivation functions from
+//! R 50.1.113-2016 are provided by [`HmacStreebog256`], [`HmacStreebog512`]
+//! and the [`kdf`] module:
+//! ```rust
+//! # #[cfg(feature = "hmac")] {
+//! use streebog::{digest::Mac, kdf, HmacStreebog256};
+//!
+//! let key = [0x42; 32];
+//! let mut mac = HmacStreebog256::new_from_slice(&key).unwrap();
+//! mac.update(b"message");
+//! let tag = mac.finalize().into_bytes();
+//!
+//! let mut keys = [0u8; 64];
+//! kdf::kdf_tree_256(&key, b"label", b"seed", 1, 2, &mut keys).unwrap();
+//! # }
+//! ```
+//!
 //! See [RustCrypto/hashes][1] readme for additional examples.
 //!
 //! [Streebog]: https://en.wikipedia.org/wiki/Streebog
@@ -54,10 +71,16 @@ use digest::{
 
 mod consts;
 mod core_api;
+#[cfg(any(test, not(feature = "size_opt")))]
 mod table;
 
+#[cfg(feature = "hmac")]
+pub mod kdf;
+
 pub use core_api::StreebogVarCore;
 pub use digest::{self, Digest};
+#[cfg(feature = "hmac")]
+pub use hmac;
 
 impl_oid_carrier!(Oid256, "1.2.643.7.1.1.2.2");
 impl_oid_carrier!(Oid512, "1.2.643.7.1.1.2.3");
@@ -66,3 +89,10 @@ impl_oid_carrier!(Oid512, "1.2.643.7.1.1.2.3");
 pub type Streebog256 = CoreWrapper<CtVariableCoreWrapper<StreebogVarCore, U32, Oid256>>;
 /// Streebog512 hasher.
 pub type Streebog512 = CoreWrapper<CtVariableCoreWrapper<StreebogVarCore, U64, Oid512>>;
+
+/// HMAC_GOSTR3411_2012_256 function.
+#[cfg(feature = "hmac")]
+pub type HmacStreebog256 = hmac::Hmac<Streebog256>;
+/// HMAC_GOSTR3411_2012_512 function.
+#[cfg(feature = "hmac")]
+pub type HmacStreebog512 = hmac::Hmac<Streebog512>;
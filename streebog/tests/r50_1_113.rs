@@ -0,0 +1,91 @@
+//! Test examples from R 50.1.113-2016.
+#![cfg(feature = "hmac")]
+
+use hex_literal::hex;
+use streebog::{digest::Mac, kdf, HmacStreebog256, HmacStreebog512};
+
+const KEY: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+const DATA: [u8; 16] = hex!("0126bdb87800af214341456563780100");
+const LABEL: [u8; 4] = hex!("26bdb878");
+const SEED: [u8; 8] = hex!("af21434145656378");
+
+#[test]
+fn hmac_256() {
+    let mut mac = HmacStreebog256::new_from_slice(&KEY).unwrap();
+    mac.update(&DATA);
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!("a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9")[..],
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn hmac_512() {
+    let mut mac = HmacStreebog512::new_from_slice(&KEY).unwrap();
+    mac.update(&DATA);
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!("
+            a59bab22ecae19c65fbde6e5f4e9f5d8549d31f037f9df9b905500e171923a77
+            3d5f1530f2ed7e964cb2eedc29e9ad2f3afe93b2814f79f5000ffc0366c251e6
+        ")[..],
+    );
+}
+
+#[test]
+fn hmac_reset() {
+    let mut mac = HmacStreebog256::new_from_slice(&KEY).unwrap();
+    mac.update(b"garbage");
+    mac.reset();
+    mac.update(&DATA);
+    let tag = mac.finalize_reset().into_bytes();
+    mac.update(&DATA);
+    assert_eq!(tag, mac.finalize().into_bytes());
+}
+
+#[test]
+fn kdf_256() {
+    assert_eq!(
+        kdf::kdf_256(&KEY, &LABEL, &SEED)[..],
+        hex!("a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9")[..],
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn kdf_tree_256() {
+    let mut out = [0u8; 64];
+    kdf::kdf_tree_256(&KEY, &LABEL, &SEED, 1, 2, &mut out).unwrap();
+    assert_eq!(
+        out[..],
+        hex!("
+            22b6837845c6bef65ea71672b265831086d3c76aebe6dae91cad51d83f79d16b
+            074c9330599d7f8d712fca54392f4ddde93751206b3584c8f43f9e6dc51531f9
+        ")[..],
+    );
+
+    // Truncated output uses a different `L`, so it is not a prefix
+    let mut short = [0u8; 32];
+    kdf::kdf_tree_256(&KEY, &LABEL, &SEED, 1, 2, &mut short).unwrap();
+    assert_ne!(short[..], out[..32]);
+}
+
+#[test]
+fn kdf_tree_256_params() {
+    let mut out = [0u8; 64];
+    for &(r, l_len) in &[(0, 2), (5, 2), (1, 0), (1, 9), (1, 1)] {
+        assert!(kdf::kdf_tree_256(&KEY, &LABEL, &SEED, r, l_len, &mut out).is_err());
+    }
+    assert!(kdf::kdf_tree_256(&KEY, &LABEL, &SEED, 1, 2, &mut []).is_err());
+    // 256 blocks do not fit into a single byte counter
+    let mut long = [0u8; 32 * 256];
+    assert!(kdf::kdf_tree_256(&KEY, &LABEL, &SEED, 1, 4, &mut long).is_err());
+    assert!(kdf::kdf_tree_256(&KEY, &LABEL, &SEED, 2, 4, &mut long).is_ok());
+
+    let mut a = [0u8; 64];
+    kdf::kdf_tree_256(&KEY, &LABEL, &SEED, 4, 8, &mut a).unwrap();
+    let mut b = [0u8; 64];
+    kdf::kdf_tree_256(&KEY, &LABEL, &SEED, 1, 2, &mut b).unwrap();
+    assert_ne!(a, b);
+}
@@ -50,7 +50,9 @@ fn key_schedule(x: &mut [u64; 8]) {
     x[7] = x[7].wrapping_sub(x[6] ^ 0x0123_4567_89AB_CDEF);
 }
 
-pub(crate) fn compress(state: &mut State, raw_block: &[u8; 64]) {
+/// Compression function with `passes` passes, which must be at least three.
+pub(crate) fn compress(state: &mut State, raw_block: &[u8; 64], passes: usize) {
+    debug_assert!(passes >= 3);
     let mut block: [u64; 8] = Default::default();
     for (o, chunk) in block.iter_mut().zip(raw_block.chunks_exact(8)) {
         *o = u64::from_le_bytes(chunk.try_into().unwrap());
@@ -62,6 +64,14 @@ pub(crate) fn compress(state: &mut State, raw_block: &[u8; 64]) {
     pass(&mut c, &mut a, &mut b, &block, 7);
     key_schedule(&mut block);
     pass(&mut b, &mut c, &mut a, &block, 9);
+    for _ in 3..passes {
+        key_schedule(&mut block);
+        pass(&mut a, &mut b, &mut c, &block, 9);
+        let t = a;
+        a = c;
+        c = b;
+        b = t;
+    }
 
     state[0] ^= a;
     state[1] = b.wrapping_sub(state[1]);
@@ -2,6 +2,11 @@
 //!
 //! Tiger2 is a variant of the original Tiger with a small padding tweak.
 //!
+//! The truncated Tiger/128 and Tiger/160 as well as the 4-pass variants
+//! found in legacy formats (e.g. PHP's `tiger128,4`) are also provided,
+//! together with their Tiger2 counterparts. Other pass counts can be selected with the `P` parameter of
+//! [`TigerCore`] and [`Tiger2Core`].
+//!
 //! # Usage
 //!
 //! ```rust
@@ -20,6 +25,19 @@
 //! assert_eq!(result[..], hex!("4c8fbddae0b6f25832af45e7c62811bb64ec3e43691e9cc3"));
 //! ```
 //!
+//! The Tiger Tree Hash used by file sharing protocols is provided by
+//! [`TigerTree`]:
+//!
+//! ```rust
+//! use tiger::{Digest, TigerTree, TthBase32};
+//!
+//! let root = TigerTree::digest(b"");
+//! assert_eq!(
+//!     TthBase32::new(&root).as_str(),
+//!     "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ",
+//! );
+//! ```
+//!
 //! Also see [RustCrypto/hashes][2] readme.
 //!
 //! [1]: https://en.wikipedia.org/wiki/Tiger_(hash_function)
@@ -33,23 +51,30 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use digest::{self, Digest};
 
-use core::fmt;
+use core::{fmt, marker::PhantomData};
 use digest::{
     block_buffer::Eager,
     core_api::{
-        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
-        OutputSizeUser, Reset, UpdateCore,
+        AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper,
+        CtVariableCoreWrapper, FixedOutputCore, OutputSizeUser, Reset, TruncSide, UpdateCore,
+        VariableOutputCore,
     },
-    typenum::{Unsigned, U24, U64},
-    HashMarker, Output,
+    typenum::{IsGreaterOrEqual, True, Unsigned, U16, U20, U24, U3, U4, U64},
+    HashMarker, InvalidOutputSize, Output,
 };
 
 mod compress;
 mod tables;
+mod tree;
 use compress::compress;
 
+pub use tree::{TigerTree, TthBase32};
+
 type State = [u64; 3];
 const S0: State = [
     0x0123_4567_89AB_CDEF,
@@ -57,38 +82,47 @@ const S0: State = [
     0xF096_A5B4_C3B2_E187,
 ];
 
-/// Core Tiger hasher state.
-#[derive(Clone)]
-pub struct TigerCore {
+/// Core Tiger hasher state with `P` passes of the compression function.
+///
+/// The specification defines Tiger with three passes, which is the default.
+/// Fewer passes are rejected at compile time:
+///
+/// ```compile_fail
+/// use tiger::{digest::{core_api::CoreWrapper, typenum::U2}, Digest, TigerCore};
+///
+/// CoreWrapper::<TigerCore<U2>>::digest(b"");
+/// ```
+pub struct TigerCore<P = U3> {
     block_len: u64,
     state: State,
+    passes: PhantomData<P>,
 }
 
-impl HashMarker for TigerCore {}
+impl<P> HashMarker for TigerCore<P> {}
 
-impl BlockSizeUser for TigerCore {
+impl<P> BlockSizeUser for TigerCore<P> {
     type BlockSize = U64;
 }
 
-impl BufferKindUser for TigerCore {
+impl<P> BufferKindUser for TigerCore<P> {
     type BufferKind = Eager;
 }
 
-impl OutputSizeUser for TigerCore {
+impl<P> OutputSizeUser for TigerCore<P> {
     type OutputSize = U24;
 }
 
-impl UpdateCore for TigerCore {
+impl<P: Unsigned + IsGreaterOrEqual<U3, Output = True>> UpdateCore for TigerCore<P> {
     #[inline]
     fn update_blocks(&mut self, blocks: &[Block<Self>]) {
         self.block_len += blocks.len() as u64;
         for block in blocks {
-            compress(&mut self.state, block.as_ref());
+            compress(&mut self.state, block.as_ref(), P::USIZE);
         }
     }
 }
 
-impl FixedOutputCore for TigerCore {
+impl<P: Unsigned + IsGreaterOrEqual<U3, Output = True>> FixedOutputCore for TigerCore<P> {
     #[inline]
     fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
         let bs = Self::BlockSize::U64 as u64;
@@ -96,7 +130,7 @@ impl FixedOutputCore for TigerCore {
         let bit_len = 8 * (pos + bs * self.block_len);
 
         buffer.digest_pad(1, &bit_len.to_le_bytes(), |b| {
-            compress(&mut self.state, b.as_ref())
+            compress(&mut self.state, b.as_ref(), P::USIZE)
         });
         for (chunk, v) in out.chunks_exact_mut(8).zip(self.state.iter()) {
             chunk.copy_from_slice(&v.to_le_bytes());
@@ -104,79 +138,130 @@ impl FixedOutputCore for TigerCore {
     }
 }
 
-impl Default for TigerCore {
+impl<P: Unsigned + IsGreaterOrEqual<U3, Output = True>> VariableOutputCore for TigerCore<P> {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    #[inline]
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size > Self::OutputSize::USIZE {
+            return Err(InvalidOutputSize);
+        }
+        Ok(Default::default())
+    }
+
+    #[inline]
+    fn finalize_variable_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        self.finalize_fixed_core(buffer, out);
+    }
+}
+
+impl<P> Default for TigerCore<P> {
     fn default() -> Self {
         Self {
             block_len: 0,
             state: S0,
+            passes: PhantomData,
+        }
+    }
+}
+
+impl<P> Clone for TigerCore<P> {
+    fn clone(&self) -> Self {
+        Self {
+            block_len: self.block_len,
+            state: self.state,
+            passes: PhantomData,
         }
     }
 }
 
-impl Reset for TigerCore {
+impl<P> Reset for TigerCore<P> {
     fn reset(&mut self) {
         *self = Default::default();
     }
 }
 
-impl AlgorithmName for TigerCore {
+impl<P: Unsigned> AlgorithmName for TigerCore<P> {
     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("Tiger")
+        f.write_str("Tiger")?;
+        if P::USIZE != 3 {
+            write!(f, "_{}pass", P::USIZE)?;
+        }
+        Ok(())
     }
 }
 
-impl fmt::Debug for TigerCore {
+impl<P> fmt::Debug for TigerCore<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("TigerCore { ... }")
     }
 }
 
-/// Core Tiger2 hasher state.
-#[derive(Clone)]
-pub struct Tiger2Core {
+/// Core Tiger2 hasher state with `P` passes of the compression function.
+///
+/// See [`TigerCore`] for the meaning of `P`.
+pub struct Tiger2Core<P = U3> {
     block_len: u64,
     state: State,
+    passes: PhantomData<P>,
 }
 
-impl HashMarker for Tiger2Core {}
+impl<P> HashMarker for Tiger2Core<P> {}
 
-impl BlockSizeUser for Tiger2Core {
+impl<P> BlockSizeUser for Tiger2Core<P> {
     type BlockSize = U64;
 }
 
-impl BufferKindUser for Tiger2Core {
+impl<P> BufferKindUser for Tiger2Core<P> {
     type BufferKind = Eager;
 }
 
-impl OutputSizeUser for Tiger2Core {
+impl<P> OutputSizeUser for Tiger2Core<P> {
     type OutputSize = U24;
 }
 
-impl UpdateCore for Tiger2Core {
+impl<P: Unsigned + IsGreaterOrEqual<U3, Output = True>> UpdateCore for Tiger2Core<P> {
     #[inline]
     fn update_blocks(&mut self, blocks: &[Block<Self>]) {
         self.block_len += blocks.len() as u64;
         for block in blocks {
-            compress(&mut self.state, block.as_ref());
+            compress(&mut self.state, block.as_ref(), P::USIZE);
         }
     }
 }
 
-impl FixedOutputCore for Tiger2Core {
+impl<P: Unsigned + IsGreaterOrEqual<U3, Output = True>> FixedOutputCore for Tiger2Core<P> {
     #[inline]
     fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
         let bs = Self::BlockSize::U64 as u64;
         let pos = buffer.get_pos() as u64;
         let bit_len = 8 * (pos + bs * self.block_len);
 
-        buffer.len64_padding_le(bit_len, |b| compress(&mut self.state, b.as_ref()));
+        buffer.len64_padding_le(bit_len, |b| compress(&mut self.state, b.as_ref(), P::USIZE));
         for (chunk, v) in out.chunks_exact_mut(8).zip(self.state.iter()) {
             chunk.copy_from_slice(&v.to_le_bytes());
         }
     }
 }
 
-impl Default for Tiger2Core {
+impl<P: Unsigned + IsGreaterOrEqual<U3, Output = True>> VariableOutputCore for Tiger2Core<P> {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    #[inline]
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size > Self::OutputSize::USIZE {
+            return Err(InvalidOutputSize);
+        }
+        Ok(Default::default())
+    }
+
+    #[inline]
+    fn finalize_variable_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        self.finalize_fixed_core(buffer, out);
+    }
+}
+
+impl<P> Default for Tiger2Core<P> {
     fn default() -> Self {
         Self {
             block_len: 0,
@@ -185,24 +270,39 @@ impl Default for Tiger2Core {
                 0xFEDC_BA98_7654_3210,
                 0xF096_A5B4_C3B2_E187,
             ],
+            passes: PhantomData,
+        }
+    }
+}
+
+impl<P> Clone for Tiger2Core<P> {
+    fn clone(&self) -> Self {
+        Self {
+            block_len: self.block_len,
+            state: self.state,
+            passes: PhantomData,
         }
     }
 }
 
-impl Reset for Tiger2Core {
+impl<P> Reset for Tiger2Core<P> {
     #[inline]
     fn reset(&mut self) {
         *self = Default::default();
     }
 }
 
-impl AlgorithmName for Tiger2Core {
+impl<P: Unsigned> AlgorithmName for Tiger2Core<P> {
     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("Tiger2")
+        f.write_str("Tiger2")?;
+        if P::USIZE != 3 {
+            write!(f, "_{}pass", P::USIZE)?;
+        }
+        Ok(())
     }
 }
 
-impl fmt::Debug for Tiger2Core {
+impl<P> fmt::Debug for Tiger2Core<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Tiger2Core { ... }")
     }
@@ -212,3 +312,21 @@ impl fmt::Debug for Tiger2Core {
 pub type Tiger = CoreWrapper<TigerCore>;
 /// Tiger2 hasher state.
 pub type Tiger2 = CoreWrapper<Tiger2Core>;
+
+/// Tiger/128 hasher state, i.e. Tiger truncated to 128 bits (`tiger128,3`).
+pub type Tiger128 = CoreWrapper<CtVariableCoreWrapper<TigerCore, U16>>;
+/// Tiger/160 hasher state, i.e. Tiger truncated to 160 bits (`tiger160,3`).
+pub type Tiger160 = CoreWrapper<CtVariableCoreWrapper<TigerCore, U20>>;
+/// 4-pass Tiger truncated to 128 bits (`tiger128,4`).
+pub type Tiger128_4 = CoreWrapper<CtVariableCoreWrapper<TigerCore<U4>, U16>>;
+/// 4-pass Tiger truncated to 160 bits (`tiger160,4`).
+pub type Tiger160_4 = CoreWrapper<CtVariableCoreWrapper<TigerCore<U4>, U20>>;
+/// 4-pass Tiger hasher state (`tiger192,4`).
+pub type Tiger192_4 = CoreWrapper<TigerCore<U4>>;
+
+/// Tiger2 truncated to 128 bits.
+pub type Tiger2_128 = CoreWrapper<CtVariableCoreWrapper<Tiger2Core, U16>>;
+/// Tiger2 truncated to 160 bits.
+pub type Tiger2_160 = CoreWrapper<CtVariableCoreWrapper<Tiger2Core, U20>>;
+/// 4-pass Tiger2 hasher state.
+pub type Tiger2_192_4 = CoreWrapper<Tiger2Core<U4>>;
@@ -0,0 +1,248 @@
+//! Tiger Tree Hash (TTH) as specified by the Tree Hash EXchange format
+//! (THEX).
+//!
+//! The input is split into 1024-byte leaves, which are hashed as
+//! `Tiger(0x00 || leaf)`. Internal nodes are `Tiger(0x01 || left || right)`,
+//! and a node without a sibling is promoted to the next level unchanged.
+//! The empty input consists of a single empty leaf.
+
+use crate::Tiger;
+use core::{cmp::min, fmt, str};
+use digest::{
+    typenum::U24, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+const LEAF_SIZE: usize = 1024;
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Tiger Tree Hash hasher.
+///
+/// Produces the THEX root hash, which is usually rendered with
+/// [`TthBase32`].
+#[derive(Clone)]
+pub struct TigerTree {
+    leaf: Tiger,
+    leaf_len: usize,
+    /// Number of finished leaves. Bit `i` is set if `stack[i]` holds the
+    /// root of a complete subtree with `2^i` leaves.
+    leaves: u64,
+    stack: [Output<Tiger>; 64],
+    #[cfg(feature = "std")]
+    leaf_hashes: Option<Vec<Output<Tiger>>>,
+}
+
+fn new_leaf() -> Tiger {
+    let mut leaf = Tiger::default();
+    leaf.update(&[LEAF_PREFIX]);
+    leaf
+}
+
+fn node_hash(left: &Output<Tiger>, right: &Output<Tiger>) -> Output<Tiger> {
+    let mut h = Tiger::default();
+    h.update(&[NODE_PREFIX]);
+    h.update(left);
+    h.update(right);
+    h.finalize_fixed()
+}
+
+impl TigerTree {
+    /// Create a hasher which also records the leaf hashes, so that the full
+    /// tree can be obtained with [`TigerTree::finalize_tree`].
+    ///
+    /// This requires 24 bytes of memory per 1024 bytes of input.
+    #[cfg(feature = "std")]
+    pub fn new_with_tree() -> Self {
+        Self {
+            leaf_hashes: Some(Vec::new()),
+            ..Default::default()
+        }
+    }
+
+    /// Finalize the hasher and return all levels of the hash tree, starting
+    /// with the level containing only the root and ending with the leaves.
+    ///
+    /// Returns `None` if the hasher was not created with
+    /// [`TigerTree::new_with_tree`].
+    #[cfg(feature = "std")]
+    pub fn finalize_tree(mut self) -> Option<Vec<Vec<Output<Tiger>>>> {
+        self.finish_leaf();
+        let mut level = self.leaf_hashes.take()?;
+        let mut levels = Vec::new();
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    _ => pair[0],
+                })
+                .collect();
+            levels.push(level);
+            level = next;
+        }
+        levels.push(level);
+        levels.reverse();
+        Some(levels)
+    }
+
+    /// Hash the current leaf if it contains data, or if it is the single
+    /// empty leaf of the empty input.
+    fn finish_leaf(&mut self) {
+        if self.leaf_len != 0 || self.leaves == 0 {
+            let leaf = core::mem::replace(&mut self.leaf, new_leaf());
+            self.leaf_len = 0;
+            self.push_leaf(leaf.finalize_fixed());
+        }
+    }
+
+    fn push_leaf(&mut self, hash: Output<Tiger>) {
+        #[cfg(feature = "std")]
+        {
+            if let Some(hashes) = self.leaf_hashes.as_mut() {
+                hashes.push(hash);
+            }
+        }
+
+        let mut node = hash;
+        let mut level = 0;
+        while (self.leaves >> level) & 1 == 1 {
+            node = node_hash(&self.stack[level], &node);
+            level += 1;
+        }
+        self.stack[level] = node;
+        self.leaves += 1;
+    }
+
+    fn root(&self) -> Output<Tiger> {
+        // Subtrees on lower levels are located to the right of the ones on
+        // higher levels, so the root is computed from right to left.
+        let mut root: Option<Output<Tiger>> = None;
+        for (level, node) in self.stack.iter().enumerate() {
+            if (self.leaves >> level) & 1 == 1 {
+                root = Some(match root {
+                    Some(right) => node_hash(node, &right),
+                    None => *node,
+                });
+            }
+        }
+        root.expect("tree has at least one leaf")
+    }
+}
+
+impl Default for TigerTree {
+    fn default() -> Self {
+        Self {
+            leaf: new_leaf(),
+            leaf_len: 0,
+            leaves: 0,
+            stack: [Default::default(); 64],
+            #[cfg(feature = "std")]
+            leaf_hashes: None,
+        }
+    }
+}
+
+impl HashMarker for TigerTree {}
+
+impl OutputSizeUser for TigerTree {
+    type OutputSize = U24;
+}
+
+impl Update for TigerTree {
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let n = min(LEAF_SIZE - self.leaf_len, data.len());
+            self.leaf.update(&data[..n]);
+            self.leaf_len += n;
+            data = &data[n..];
+            if self.leaf_len == LEAF_SIZE {
+                self.finish_leaf();
+            }
+        }
+    }
+}
+
+impl FixedOutput for TigerTree {
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        self.finish_leaf();
+        *out = self.root();
+    }
+}
+
+impl Reset for TigerTree {
+    fn reset(&mut self) {
+        #[cfg(feature = "std")]
+        let leaf_hashes = self.leaf_hashes.take().map(|mut hashes| {
+            hashes.clear();
+            hashes
+        });
+        *self = Default::default();
+        #[cfg(feature = "std")]
+        {
+            self.leaf_hashes = leaf_hashes;
+        }
+    }
+}
+
+impl FixedOutputReset for TigerTree {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.finish_leaf();
+        *out = self.root();
+        Reset::reset(self);
+    }
+}
+
+impl fmt::Debug for TigerTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TigerTree { ... }")
+    }
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Base32 rendering of a Tiger Tree Hash (RFC 4648 alphabet without
+/// padding), as used in `urn:tree:tiger:` URNs and magnet links.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TthBase32([u8; 39]);
+
+impl TthBase32 {
+    /// Encode the given hash.
+    pub fn new(hash: &Output<TigerTree>) -> Self {
+        let mut out = [0u8; 39];
+        let mut acc = 0u16;
+        let mut bits = 0;
+        let mut chars = out.iter_mut();
+        for &b in hash.iter() {
+            acc = (acc << 8) | u16::from(b);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                *chars.next().unwrap() = BASE32_ALPHABET[usize::from((acc >> bits) & 0x1f)];
+            }
+        }
+        // 192 bits leave 2 bits for the last character
+        *chars.next().unwrap() = BASE32_ALPHABET[usize::from((acc << (5 - bits)) & 0x1f)];
+        Self(out)
+    }
+
+    /// Return the encoded hash as a string slice.
+    pub fn as_str(&self) -> &str {
+        // the alphabet only contains ASCII characters
+        str::from_utf8(&self.0).unwrap()
+    }
+}
+
+impl fmt::Display for TthBase32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for TthBase32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TthBase32({})", self.as_str())
+    }
+}
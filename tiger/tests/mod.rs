@@ -1,7 +1,10 @@
 use digest::dev::{feed_rand_16mib, fixed_reset_test};
 use digest::new_test;
 use hex_literal::hex;
-use tiger::{Digest, Tiger, Tiger2};
+use tiger::{
+    Digest, Tiger, Tiger128, Tiger128_4, Tiger160, Tiger160_4, Tiger192_4, Tiger2, Tiger2_128,
+    Tiger2_160, Tiger2_192_4,
+};
 
 new_test!(tiger, "tiger", tiger::Tiger, fixed_reset_test);
 new_test!(tiger2, "tiger2", tiger::Tiger2, fixed_reset_test);
@@ -25,3 +28,67 @@ fn tiger2_rand() {
         hex!("1bb7a80144c97f831fdefb635477776dd6c164048ce5895d")[..]
     );
 }
+
+// Reference outputs of PHP's `hash()`
+#[test]
+fn tiger_truncated() {
+    assert_eq!(
+        Tiger128::digest(b"")[..],
+        hex!("3293ac630c13f0245f92bbb1766e1616")[..]
+    );
+    assert_eq!(
+        Tiger160::digest(b"")[..],
+        hex!("3293ac630c13f0245f92bbb1766e16167a4e5849")[..]
+    );
+    assert_eq!(
+        Tiger128::digest(b"abc")[..],
+        hex!("2aab1484e8c158f2bfb8c5ff41b57a52")[..]
+    );
+    assert_eq!(
+        Tiger160::digest(b"abc")[..],
+        hex!("2aab1484e8c158f2bfb8c5ff41b57a525129131c")[..]
+    );
+}
+
+#[test]
+fn tiger_4_passes() {
+    assert_eq!(
+        Tiger128_4::digest(b"")[..],
+        hex!("24cc78a7f6ff3546e7984e59695ca13d")[..]
+    );
+    assert_eq!(
+        Tiger160_4::digest(b"")[..],
+        hex!("24cc78a7f6ff3546e7984e59695ca13d804e0b68")[..]
+    );
+    assert_eq!(
+        Tiger192_4::digest(b"")[..],
+        hex!("24cc78a7f6ff3546e7984e59695ca13d804e0b686e255194")[..]
+    );
+
+    let msg = [0x5a; 1000];
+    let full = Tiger192_4::digest(&msg[..]);
+    assert_ne!(full, Tiger::digest(&msg[..]));
+    assert_eq!(Tiger128_4::digest(&msg[..])[..], full[..16]);
+    assert_eq!(Tiger160_4::digest(&msg[..])[..], full[..20]);
+}
+
+#[test]
+fn tiger2_truncated_and_4_passes() {
+    for len in [0, 1, 55, 56, 64, 1000].iter() {
+        let msg = vec![0x5a; *len];
+        let full = Tiger2::digest(&msg);
+        assert_eq!(Tiger2_128::digest(&msg)[..], full[..16]);
+        assert_eq!(Tiger2_160::digest(&msg)[..], full[..20]);
+
+        let four = Tiger2_192_4::digest(&msg);
+        assert_ne!(four, full);
+        assert_ne!(four, Tiger192_4::digest(&msg));
+    }
+}
+
+#[test]
+fn pass_count_in_name() {
+    assert_eq!(format!("{:?}", Tiger::new()), "Tiger { .. }");
+    assert_eq!(format!("{:?}", Tiger192_4::new()), "Tiger_4pass { .. }");
+    assert_eq!(format!("{:?}", Tiger2_192_4::new()), "Tiger2_4pass { .. }");
+}
@@ -0,0 +1,68 @@
+//! Test vectors from the THEX specification.
+use tiger::{Digest, TigerTree, TthBase32};
+
+fn tth(data: &[u8]) -> String {
+    TthBase32::new(&TigerTree::digest(data)).to_string()
+}
+
+#[test]
+fn thex_vectors() {
+    assert_eq!(tth(b""), "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ");
+    assert_eq!(tth(&[0]), "VK54ZIEEVTWNAUI5D5RDFIL37LX2IQNSTAXFKSA");
+    assert_eq!(
+        tth(&[b'A'; 1024]),
+        "L66Q4YVNAFWVS23X2HJIRA5ZJ7WXR3F26RSASFA"
+    );
+    assert_eq!(
+        tth(&[b'A'; 1025]),
+        "PZMRYHGY6LTBEH63ZWAHDORHSYTLO4LEFUIKHWY"
+    );
+}
+
+#[test]
+fn chunked_update() {
+    let data: Vec<u8> = (0..10_000).map(|i| i as u8).collect();
+    let expected = TigerTree::digest(&data);
+    for &chunk in &[1, 7, 1023, 1024, 1025, 4096] {
+        let mut h = TigerTree::new();
+        for c in data.chunks(chunk) {
+            h.update(c);
+        }
+        assert_eq!(h.finalize_reset(), expected);
+        h.update(&data);
+        assert_eq!(h.finalize(), expected);
+    }
+}
+
+#[test]
+fn full_tree() {
+    for &len in &[0, 1, 1024, 1025, 3 * 1024, 7 * 1024 + 1, 10_000] {
+        let data: Vec<u8> = (0..len).map(|i| (i * 7) as u8).collect();
+        let mut h = TigerTree::new_with_tree();
+        h.update(&data);
+        let levels = h.clone().finalize_tree().unwrap();
+        let root = h.finalize();
+
+        assert_eq!(levels[0], [root]);
+        let leaves = levels.last().unwrap();
+        let leaf_count = if len == 0 { 1 } else { (len - 1) / 1024 + 1 };
+        assert_eq!(leaves.len(), leaf_count);
+        for pair in levels.windows(2) {
+            assert_eq!(pair[0].len(), pair[1].len() / 2 + pair[1].len() % 2);
+        }
+    }
+
+    assert!(TigerTree::new().finalize_tree().is_none());
+
+    // reset keeps recording the tree
+    let mut h = TigerTree::new_with_tree();
+    h.update([0u8; 5000]);
+    h.reset();
+    h.update([b'A'; 1025]);
+    let levels = h.finalize_tree().unwrap();
+    assert_eq!(levels.len(), 2);
+    assert_eq!(
+        TthBase32::new(&levels[0][0]).as_str(),
+        "PZMRYHGY6LTBEH63ZWAHDORHSYTLO4LEFUIKHWY"
+    );
+}
@@ -3,9 +3,35 @@ use core::convert::TryInto;
 
 #[path = "consts.rs"]
 mod consts;
+#[path = "consts_legacy.rs"]
+mod consts_legacy;
 use consts::*;
 
-fn compress_block(state: &mut [u64; 8], b: &[u8; BLOCK_SIZE]) {
+#[cfg(all(
+    feature = "asm",
+    target_arch = "x86_64",
+    not(feature = "constant-time")
+))]
+mod asm;
+// 32-bit x86 uses the `whirlpool-asm` crate
+#[cfg(all(feature = "asm", target_arch = "x86", not(feature = "constant-time")))]
+mod asm {
+    use crate::BLOCK_SIZE;
+
+    #[inline(always)]
+    pub fn compress_block(state: &mut [u64; 8], b: &[u8; BLOCK_SIZE]) {
+        whirlpool_asm::compress(state, core::slice::from_ref(b));
+    }
+}
+#[cfg(feature = "constant-time")]
+mod ct;
+
+/// Lookup tables `C0`..`C7` laid out contiguously, so a single base register
+/// can address all of them.
+static TABLES: [[u64; 256]; 8] = [C0, C1, C2, C3, C4, C5, C6, C7];
+
+#[inline(always)]
+fn compress_block(state: &mut [u64; 8], b: &[u8; BLOCK_SIZE], c: &[[u64; 256]; 8], rc: &[u64; R]) {
     let mut k = [0u64; 8];
     let mut block = [0u64; 8];
     let mut s = [0u64; 8];
@@ -23,26 +49,26 @@ fn compress_block(state: &mut [u64; 8], b: &[u8; BLOCK_SIZE]) {
     #[allow(clippy::needless_range_loop)]
     for r in 0..R {
         for i in 0..8 {
-            l[i] = C0[(k[(i) % 8] & 0xff) as usize]
-                ^ C1[((k[(7 + i) % 8] >> 8) & 0xff) as usize]
-                ^ C2[((k[(6 + i) % 8] >> 16) & 0xff) as usize]
-                ^ C3[((k[(5 + i) % 8] >> 24) & 0xff) as usize]
-                ^ C4[((k[(4 + i) % 8] >> 32) & 0xff) as usize]
-                ^ C5[((k[(3 + i) % 8] >> 40) & 0xff) as usize]
-                ^ C6[((k[(2 + i) % 8] >> 48) & 0xff) as usize]
-                ^ C7[((k[(1 + i) % 8] >> 56) & 0xff) as usize]
-                ^ if i == 0 { RC[r] } else { 0 };
+            l[i] = c[0][(k[(i) % 8] & 0xff) as usize]
+                ^ c[1][((k[(7 + i) % 8] >> 8) & 0xff) as usize]
+                ^ c[2][((k[(6 + i) % 8] >> 16) & 0xff) as usize]
+                ^ c[3][((k[(5 + i) % 8] >> 24) & 0xff) as usize]
+                ^ c[4][((k[(4 + i) % 8] >> 32) & 0xff) as usize]
+                ^ c[5][((k[(3 + i) % 8] >> 40) & 0xff) as usize]
+                ^ c[6][((k[(2 + i) % 8] >> 48) & 0xff) as usize]
+                ^ c[7][((k[(1 + i) % 8] >> 56) & 0xff) as usize]
+                ^ if i == 0 { rc[r] } else { 0 };
         }
         k = l;
         for i in 0..8 {
-            l[i] = C0[(s[(i) % 8] & 0xff) as usize]
-                ^ C1[((s[(7 + i) % 8] >> 8) & 0xff) as usize]
-                ^ C2[((s[(6 + i) % 8] >> 16) & 0xff) as usize]
-                ^ C3[((s[(5 + i) % 8] >> 24) & 0xff) as usize]
-                ^ C4[((s[(4 + i) % 8] >> 32) & 0xff) as usize]
-                ^ C5[((s[(3 + i) % 8] >> 40) & 0xff) as usize]
-                ^ C6[((s[(2 + i) % 8] >> 48) & 0xff) as usize]
-                ^ C7[((s[(1 + i) % 8] >> 56) & 0xff) as usize]
+            l[i] = c[0][(s[(i) % 8] & 0xff) as usize]
+                ^ c[1][((s[(7 + i) % 8] >> 8) & 0xff) as usize]
+                ^ c[2][((s[(6 + i) % 8] >> 16) & 0xff) as usize]
+                ^ c[3][((s[(5 + i) % 8] >> 24) & 0xff) as usize]
+                ^ c[4][((s[(4 + i) % 8] >> 32) & 0xff) as usize]
+                ^ c[5][((s[(3 + i) % 8] >> 40) & 0xff) as usize]
+                ^ c[6][((s[(2 + i) % 8] >> 48) & 0xff) as usize]
+                ^ c[7][((s[(1 + i) % 8] >> 56) & 0xff) as usize]
                 ^ k[i];
         }
         s = l;
@@ -55,6 +81,74 @@ fn compress_block(state: &mut [u64; 8], b: &[u8; BLOCK_SIZE]) {
 
 pub(crate) fn compress(state: &mut [u64; 8], blocks: &[[u8; BLOCK_SIZE]]) {
     for block in blocks {
-        compress_block(state, block);
+        #[cfg(feature = "constant-time")]
+        ct::compress_block(state, block, &ct::MIX);
+        #[cfg(all(
+            feature = "asm",
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "constant-time")
+        ))]
+        asm::compress_block(state, block);
+        #[cfg(not(any(
+            feature = "constant-time",
+            all(feature = "asm", any(target_arch = "x86", target_arch = "x86_64"))
+        )))]
+        compress_block(state, block, &TABLES, &RC);
+    }
+}
+
+/// Compression function of Whirlpool-T.
+pub(crate) fn compress_t(state: &mut [u64; 8], blocks: &[[u8; BLOCK_SIZE]]) {
+    for block in blocks {
+        #[cfg(feature = "constant-time")]
+        ct::compress_block(state, block, &ct::MIX_T);
+        #[cfg(not(feature = "constant-time"))]
+        compress_block(state, block, &consts_legacy::TABLES_T, &RC);
+    }
+}
+
+/// Compression function of Whirlpool-0.
+///
+/// The Whirlpool-0 S-box has no algebraic structure, so it is always
+/// computed with lookup tables.
+pub(crate) fn compress_0(state: &mut [u64; 8], blocks: &[[u8; BLOCK_SIZE]]) {
+    for block in blocks {
+        compress_block(state, block, &consts_legacy::TABLES_0, &consts_legacy::RC_0);
+    }
+}
+
+/// Whirlpool backends compiled for the target, see [`crate::backends`].
+pub mod backends {
+    use crate::BLOCK_SIZE;
+
+    /// Compress a single block into `state` with the lookup tables.
+    pub fn table(state: &mut [u64; 8], block: &[u8; BLOCK_SIZE]) {
+        super::compress_block(state, block, &super::TABLES, &super::RC);
+    }
+
+    /// Compress a single Whirlpool-T block into `state` with the lookup
+    /// tables.
+    pub fn table_t(state: &mut [u64; 8], block: &[u8; BLOCK_SIZE]) {
+        super::compress_block(state, block, &super::consts_legacy::TABLES_T, &super::RC);
+    }
+
+    /// Compress a single block into `state` without lookup tables.
+    #[cfg(feature = "constant-time")]
+    pub fn ct(state: &mut [u64; 8], block: &[u8; BLOCK_SIZE]) {
+        super::ct::compress_block(state, block, &super::ct::MIX);
     }
+
+    /// Compress a single Whirlpool-T block into `state` without lookup
+    /// tables.
+    #[cfg(feature = "constant-time")]
+    pub fn ct_t(state: &mut [u64; 8], block: &[u8; BLOCK_SIZE]) {
+        super::ct::compress_block(state, block, &super::ct::MIX_T);
+    }
+
+    #[cfg(all(
+        feature = "asm",
+        target_arch = "x86_64",
+        not(feature = "constant-time")
+    ))]
+    pub use super::asm::compress_block as asm;
 }
@@ -0,0 +1,97 @@
+//! Whirlpool compression function written with inline assembly for `x86_64`.
+
+use super::{consts::*, TABLES};
+use crate::BLOCK_SIZE;
+use core::{arch::asm, convert::TryInto};
+
+// Byte `j` of input word `m` is looked up in table `Cj` and contributes to
+// output row `(m + j) % 8`, so the accumulator registers are rotated by one
+// for every input word. After seven shifts only the top byte is left in `x`,
+// which can be used as an index directly.
+#[rustfmt::skip]
+macro_rules! word {
+    ($off:literal, $l0:literal, $l1:literal, $l2:literal, $l3:literal, $l4:literal, $l5:literal, $l6:literal, $l7:literal) => {
+        concat!(
+            "mov {x}, qword ptr [{src} + ", $off, "]\n",
+            "movzx {t:e}, {x:l}\n",
+            "xor ", $l0, ", qword ptr [{tbl} + 8*{t}]\n",
+            "shr {x}, 8\n",
+            "movzx {t:e}, {x:l}\n",
+            "xor ", $l1, ", qword ptr [{tbl} + 8*{t} + 2048]\n",
+            "shr {x}, 8\n",
+            "movzx {t:e}, {x:l}\n",
+            "xor ", $l2, ", qword ptr [{tbl} + 8*{t} + 4096]\n",
+            "shr {x}, 8\n",
+            "movzx {t:e}, {x:l}\n",
+            "xor ", $l3, ", qword ptr [{tbl} + 8*{t} + 6144]\n",
+            "shr {x}, 8\n",
+            "movzx {t:e}, {x:l}\n",
+            "xor ", $l4, ", qword ptr [{tbl} + 8*{t} + 8192]\n",
+            "shr {x}, 8\n",
+            "movzx {t:e}, {x:l}\n",
+            "xor ", $l5, ", qword ptr [{tbl} + 8*{t} + 10240]\n",
+            "shr {x}, 8\n",
+            "movzx {t:e}, {x:l}\n",
+            "xor ", $l6, ", qword ptr [{tbl} + 8*{t} + 12288]\n",
+            "shr {x}, 8\n",
+            "xor ", $l7, ", qword ptr [{tbl} + 8*{x} + 14336]\n",
+        )
+    };
+}
+
+/// Applies the round function to `src` and XORs the result into `acc`.
+#[inline(always)]
+fn round(src: &[u64; 8], acc: [u64; 8]) -> [u64; 8] {
+    let [mut l0, mut l1, mut l2, mut l3, mut l4, mut l5, mut l6, mut l7] = acc;
+    // SAFETY: the block only reads `src` and `TABLES`, all indices are
+    // single bytes
+    unsafe {
+        asm!(
+            word!(0, "{l0}", "{l1}", "{l2}", "{l3}", "{l4}", "{l5}", "{l6}", "{l7}"),
+            word!(8, "{l1}", "{l2}", "{l3}", "{l4}", "{l5}", "{l6}", "{l7}", "{l0}"),
+            word!(16, "{l2}", "{l3}", "{l4}", "{l5}", "{l6}", "{l7}", "{l0}", "{l1}"),
+            word!(24, "{l3}", "{l4}", "{l5}", "{l6}", "{l7}", "{l0}", "{l1}", "{l2}"),
+            word!(32, "{l4}", "{l5}", "{l6}", "{l7}", "{l0}", "{l1}", "{l2}", "{l3}"),
+            word!(40, "{l5}", "{l6}", "{l7}", "{l0}", "{l1}", "{l2}", "{l3}", "{l4}"),
+            word!(48, "{l6}", "{l7}", "{l0}", "{l1}", "{l2}", "{l3}", "{l4}", "{l5}"),
+            word!(56, "{l7}", "{l0}", "{l1}", "{l2}", "{l3}", "{l4}", "{l5}", "{l6}"),
+            src = in(reg) src.as_ptr(),
+            tbl = in(reg) TABLES.as_ptr(),
+            x = out(reg) _,
+            t = out(reg) _,
+            l0 = inout(reg) l0,
+            l1 = inout(reg) l1,
+            l2 = inout(reg) l2,
+            l3 = inout(reg) l3,
+            l4 = inout(reg) l4,
+            l5 = inout(reg) l5,
+            l6 = inout(reg) l6,
+            l7 = inout(reg) l7,
+            options(pure, readonly, nostack),
+        );
+    }
+    [l0, l1, l2, l3, l4, l5, l6, l7]
+}
+
+/// Compress a single block into `state` with the inline assembly rounds.
+pub fn compress_block(state: &mut [u64; 8], b: &[u8; BLOCK_SIZE]) {
+    let mut block = [0u64; 8];
+    for (o, chunk) in block.iter_mut().zip(b.chunks_exact(8)) {
+        *o = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    let mut k = *state;
+    let mut s = [0u64; 8];
+    for i in 0..8 {
+        s[i] = block[i] ^ k[i];
+    }
+
+    for &rc in RC.iter() {
+        k = round(&k, [rc, 0, 0, 0, 0, 0, 0, 0]);
+        s = round(&s, k);
+    }
+
+    for i in 0..8 {
+        state[i] ^= s[i] ^ block[i];
+    }
+}
@@ -0,0 +1,115 @@
+//! Table-free constant-time Whirlpool compression function.
+//!
+//! The S-box is evaluated through its decomposition into the 4-bit
+//! mini-boxes `E`, `E^-1` and `R`, each of which is computed as a boolean
+//! function of the nibble bits for all bytes of a row at once. MixRows is
+//! computed with multiplications by `x` in GF(2^8). Neither memory accesses
+//! nor branches depend on the processed data.
+
+use super::consts::RC;
+use crate::BLOCK_SIZE;
+use core::convert::TryInto;
+
+const LSB: u64 = 0x0101_0101_0101_0101;
+
+/// Algebraic normal form of the output bits of the mini-box `E`: bit `s` of
+/// entry `b` is set if output bit `b` contains the product of the input bits
+/// set in `s`.
+const E_ANF: [u16; 4] = [0x2529, 0x294a, 0x6318, 0x7bde];
+/// Algebraic normal form of the mini-box `E^-1`.
+const E_INV_ANF: [u16; 4] = [0x0c8b, 0x7da7, 0x37db, 0x10e3];
+/// Algebraic normal form of the mini-box `R`.
+const R_ANF: [u16; 4] = [0x31fb, 0x6e43, 0x520d, 0x1a5e];
+
+/// First row of the Whirlpool diffusion matrix `cir(1, 1, 4, 1, 8, 5, 2, 9)`.
+pub(super) const MIX: [u8; 8] = [1, 1, 4, 1, 8, 5, 2, 9];
+/// First row of the Whirlpool-T diffusion matrix `cir(1, 1, 3, 1, 5, 8, 9, 5)`.
+pub(super) const MIX_T: [u8; 8] = [1, 1, 3, 1, 5, 8, 9, 5];
+
+/// Apply a mini-box to the low nibble of every byte of `w`. The high nibbles
+/// are ignored and zero in the result.
+#[inline(always)]
+fn mini_box(w: u64, anf: &[u16; 4]) -> u64 {
+    // all products of the input bits, with the constant 1 for `s = 0`
+    let mut m = [LSB; 16];
+    for s in 1..16 {
+        m[s] = m[s & (s - 1)] & (w >> s.trailing_zeros());
+    }
+
+    let mut out = 0;
+    for (b, &a) in anf.iter().enumerate() {
+        let mut y = 0;
+        for (s, &ms) in m.iter().enumerate() {
+            if (a >> s) & 1 == 1 {
+                y ^= ms;
+            }
+        }
+        out |= y << b;
+    }
+    out
+}
+
+/// Apply the S-box to every byte of `w`.
+#[inline(always)]
+fn sub_bytes(w: u64) -> u64 {
+    let a = mini_box(w >> 4, &E_ANF);
+    let b = mini_box(w, &E_INV_ANF);
+    let r = mini_box(a ^ b, &R_ANF);
+    (mini_box(a ^ r, &E_ANF) << 4) | mini_box(b ^ r, &E_INV_ANF)
+}
+
+/// Multiply every byte of `w` by `x` modulo `x^8 + x^4 + x^3 + x^2 + 1`.
+#[inline(always)]
+fn mul_x(w: u64) -> u64 {
+    ((w << 1) & !LSB) ^ (((w >> 7) & LSB) * 0x1d)
+}
+
+/// Round function: ShiftColumns, SubBytes, MixRows and the key addition.
+#[inline(always)]
+fn round(a: &[u64; 8], key: &[u64; 8], mix: &[u8; 8]) -> [u64; 8] {
+    let mut out = [0u64; 8];
+    for (i, o) in out.iter_mut().enumerate() {
+        // byte `j` of row `i` comes from row `i - j`
+        let mut t = 0;
+        for j in 0..8 {
+            t |= a[(8 + i - j) % 8] & (0xff << (8 * j));
+        }
+        let t = sub_bytes(t);
+
+        // group the rotated rows by the powers of `x` in their coefficients
+        // and evaluate the sum with Horner's rule
+        let mut acc = [0u64; 4];
+        for (k, &c) in mix.iter().enumerate() {
+            let r = t.rotate_left(8 * k as u32);
+            for (p, acc) in acc.iter_mut().enumerate() {
+                if (c >> p) & 1 == 1 {
+                    *acc ^= r;
+                }
+            }
+        }
+        *o = acc[0] ^ mul_x(acc[1] ^ mul_x(acc[2] ^ mul_x(acc[3]))) ^ key[i];
+    }
+    out
+}
+
+pub(super) fn compress_block(state: &mut [u64; 8], b: &[u8; BLOCK_SIZE], mix: &[u8; 8]) {
+    let mut block = [0u64; 8];
+    for (o, chunk) in block.iter_mut().zip(b.chunks_exact(8)) {
+        *o = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    let mut k = *state;
+    let mut s = [0u64; 8];
+    for i in 0..8 {
+        s[i] = block[i] ^ k[i];
+    }
+
+    for &rc in RC.iter() {
+        k = round(&k, &[rc, 0, 0, 0, 0, 0, 0, 0], mix);
+        s = round(&s, &k, mix);
+    }
+
+    for i in 0..8 {
+        state[i] ^= s[i] ^ block[i];
+    }
+}
@@ -0,0 +1,4156 @@
+//! Tables of the legacy Whirlpool-0 and Whirlpool-T variants.
+//!
+//! Both use the diffusion matrix `cir(1, 1, 3, 1, 5, 8, 9, 5)`. Whirlpool-T
+//! shares the S-box, and therefore the round constants, with the final
+//! Whirlpool, while Whirlpool-0 uses a randomly generated S-box.
+#![allow(clippy::unreadable_literal)]
+
+use super::consts::R;
+
+pub const RC_0: [u64; R] = [
+    0xe46a9d482bebd068,
+    0x9e85f17d8156a3e3,
+    0xd561a917ca788e2c,
+    0x422251773c8c0b5d,
+    0x18b386cc8041543f,
+    0x6bd136f46206572e,
+    0xf92649da1075651b,
+    0xab5250aebae766cb,
+    0xfe20043b730df005,
+    0xa0c0b50a5fb4f5dd,
+];
+
+pub static TABLES_0: [[u64; 256]; 8] = [
+    [
+        0xd50f67d568b86868,
+        0xb71eceb7d06dd0d0,
+        0x60e00b60eb20ebeb,
+        0x876e45872b7d2b2b,
+        0x75327a7548d84848,
+        0xd3019cd39dba9d9d,
+        0xdf1d77df6abe6a6a,
+        0x53977353e431e4e4,
+        0x48a84b48e338e3e3,
+        0x15d27115a3f8a3a3,
+        0x13dc8a1356fa5656,
+        0xbffd7cbf819e8181,
+        0x94b2cf947d877d7d,
+        0x122adb12f10ef1f1,
+        0xabd95cab85928585,
+        0xdc1a84dc9ebf9e9e,
+        0x9c517d9c2c742c2c,
+        0x8c8a048c8e8f8e8e,
+        0x859fe78578887878,
+        0xc5d41ec5ca43caca,
+        0x4bafb84b17391717,
+        0x37882137a9e6a9a9,
+        0xf84e2ff861a36161,
+        0xa633e6a6d562d5d5,
+        0x348fd2345de75d5d,
+        0x275358270b1d0b0b,
+        0x869814868c898c8c,
+        0xccc1fdcc3c443c3c,
+        0xb6e89fb677997777,
+        0x08e3b20851f35151,
+        0xaa2f0daa22662222,
+        0x57682a5742c64242,
+        0xc3dae5c33f413f3f,
+        0x19ce9a1954fc5454,
+        0x5873325841c34141,
+        0xbaf474ba809d8080,
+        0xdbe22edbcc49cccc,
+        0xa4c244a486978686,
+        0x4542f145b3c8b3b3,
+        0x78d8c07818281818,
+        0x96436d962e722e2e,
+        0x16d5821657f95757,
+        0x1e36301e060a0606,
+        0xf75537f762a66262,
+        0x0307f303f401f4f4,
+        0xee9badee365a3636,
+        0xb217c6b2d16ed1d1,
+        0xda147fda6bbd6b6b,
+        0x77c3d8771b2d1b1b,
+        0xec6a0fec65af6565,
+        0xbcfa8fbc759f7575,
+        0x5090805010301010,
+        0x95449e95da73dada,
+        0x703b727049db4949,
+        0xbe0b2dbe266a2626,
+        0x3a629b3af916f9f9,
+        0xc0dd16c0cb40cbcb,
+        0xe37117e366aa6666,
+        0x5c8c6b5ce734e7e7,
+        0x6803b968bad3baba,
+        0x2cb7192caeefaeae,
+        0x0deaba0d50f05050,
+        0x07f8aa0752f65252,
+        0x3d9a313dabe0abab,
+        0x112d2811050f0505,
+        0x1723d317f00df0f0,
+        0x396568390d170d0d,
+        0xa2ccbfa273957373,
+        0xd7fec5d73b4d3b3b,
+        0x14242014040c0404,
+        0xa03d1da020602020,
+        0x215da321fe1ffefe,
+        0x8e7ba68edd7adddd,
+        0x060efb06f502f5f5,
+        0x5e7dc95eb4c1b4b4,
+        0x3e9dc23e5fe15f5f,
+        0x225a50220a1e0a0a,
+        0x5b74c15bb5c2b5b5,
+        0xe78e4ee7c05dc0c0,
+        0x1ac9691aa0fda0a0,
+        0xa8deafa871937171,
+        0x0be4410ba5f2a5a5,
+        0x995875992d772d2d,
+        0xfd4727fd60a06060,
+        0xa7c5b7a772967272,
+        0xe57fece593a89393,
+        0xddecd5dd394b3939,
+        0x2848402808180808,
+        0xb5ef6cb583988383,
+        0xa53415a521632121,
+        0x3186da315ce45c5c,
+        0xa1cb4ca187948787,
+        0x4f50e14fb1ceb1b1,
+        0x47b35347e03de0e0,
+        0x0000000000000000,
+        0xe89556e8c358c3c3,
+        0x5a82905a12361212,
+        0xef6dfcef91ae9191,
+        0x98ae24988a838a8a,
+        0x0a12100a02060202,
+        0x6cfce06c1c241c1c,
+        0x59856359e637e6e6,
+        0x4c57124c45cf4545,
+        0xed9c5eedc25bc2c2,
+        0xf3aa6ef3c451c4c4,
+        0x2e46bb2efd1afdfd,
+        0x792e9179bfdcbfbf,
+        0x495e1a4944cc4444,
+        0x1fc0611fa1fea1a1,
+        0x61165a614cd44c4c,
+        0xffb685ff33553333,
+        0xf6a366f6c552c5c5,
+        0xaed054ae84918484,
+        0xaf2605af23652323,
+        0x91bbc7917c847c7c,
+        0x4a59e94ab0cdb0b0,
+        0xb11035b1256f2525,
+        0x41bda841153f1515,
+        0xe180b5e1355f3535,
+        0xd0066fd069bb6969,
+        0x2454ab24ff1cffff,
+        0xfe40d4fe94a19494,
+        0x641f52644dd74d4d,
+        0xadd7a7ad70907070,
+        0x10db7910a2fba2a2,
+        0x29be1129afecafaf,
+        0xdeeb26decd4acdcd,
+        0xa928fea9d667d6d6,
+        0xc12b47c16cb46c6c,
+        0x5166d151b7c4b7b7,
+        0x3f6b933ff815f8f8,
+        0x2d41482d091b0909,
+        0x1838cb18f308f3f3,
+        0xe6781fe667a96767,
+        0x0eed490ea4f1a4a4,
+        0x65e90365ea23eaea,
+        0x7bdf337bec29ecec,
+        0x546fd954b6c7b6b6,
+        0xa33aeea3d461d4d4,
+        0xbd0cdebdd26bd2d2,
+        0x44b4a044143c1414,
+        0x66eef0661e221e1e,
+        0x42ba5b42e13ee1e1,
+        0xb4193db4246c2424,
+        0xd8e5ddd838483838,
+        0xf9b87ef9c657c6c6,
+        0x904d9690db70dbdb,
+        0x7a29627a4bdd4b4b,
+        0x8f8df78f7a8e7a7a,
+        0xd2f7cdd23a4e3a3a,
+        0x8160be81de7fdede,
+        0x3b94ca3b5ee25e5e,
+        0x8469b684df7cdfdf,
+        0xfb49dcfb95a29595,
+        0x2b4fb32bfc19fcfc,
+        0x38933938aae3aaaa,
+        0xac21f6acd764d7d7,
+        0xd1f03ed1ce4fcece,
+        0x1b3f381b07090707,
+        0x337778330f110f0f,
+        0xc9c8f5c93d473d3d,
+        0x25a2fa2558e85858,
+        0xc83ea4c89ab39a9a,
+        0xc22cb4c298b59898,
+        0xd60894d69cb99c9c,
+        0x1d31c31df20bf2f2,
+        0x01f65101a7f4a7a7,
+        0x5599885511331111,
+        0x9ba9d79b7e827e7e,
+        0x9da72c9d8b808b8b,
+        0x5261225243c54343,
+        0x0f1b180f03050303,
+        0x4da1434de23be2e2,
+        0x8b72ae8bdc79dcdc,
+        0x569e7b56e532e5e5,
+        0x404bf940b2cbb2b2,
+        0x6b044a6b4ed24e4e,
+        0xfcb176fcc754c7c7,
+        0xc4224fc46db76d6d,
+        0x6af21b6ae926e9e9,
+        0xbb0225bb27692727,
+        0x5d7a3a5d40c04040,
+        0x9f568e9fd875d8d8,
+        0xeb92a5eb37593737,
+        0xe076e4e092ab9292,
+        0x89830c898f8c8f8f,
+        0x0509080501030101,
+        0x69f5e8691d271d1d,
+        0x02f1a20253f55353,
+        0xc6d3edc63e423e3e,
+        0x20abf22059eb5959,
+        0xe28746e2c15ec1c1,
+        0x6e0d426e4fd14f4f,
+        0xfabf8dfa32563232,
+        0x4ea6b04e163a1616,
+        0x35798335fa13fafa,
+        0xb9f387b9749c7474,
+        0x30708b30fb10fbfb,
+        0xf25c3ff263a56363,
+        0xd9138cd99fbc9f9f,
+        0xe489bde4345c3434,
+        0x72cad0721a2e1a1a,
+        0x82674d822a7e2a2a,
+        0x2fb0ea2f5aee5a5a,
+        0x83911c838d8a8d8d,
+        0xcacf06cac946c9c9,
+        0xd4f936d4cf4ccfcf,
+        0x0915e309f607f6f6,
+        0xea64f4ea90ad9090,
+        0x88755d8828782828,
+        0x92bc349288858888,
+        0xcd37accd9bb09b9b,
+        0xf5a495f531533131,
+        0x367e70360e120e0e,
+        0x733c8173bddabdbd,
+        0x7f206a7f4ade4a4a,
+        0x6ffb136fe825e8e8,
+        0xf452c4f496a79696,
+        0x04ff5904a6f7a6a6,
+        0x3c6c603c0c140c0c,
+        0xcfc60ecfc845c8c8,
+        0x8096ef80798b7979,
+        0x76358976bcd9bcbc,
+        0x7c27997cbedfbebe,
+        0x74c42b74ef2cefef,
+        0xcb3957cb6eb26e6e,
+        0x434c0a4346ca4646,
+        0xf15bccf197a49797,
+        0x2ab9e22a5bed5b5b,
+        0x7ed63b7eed2aeded,
+        0x7dd1c87d192b1919,
+        0x9a5f869ad976d9d9,
+        0x26a50926ace9acac,
+        0xc725bcc799b69999,
+        0x32812932a8e5a8a8,
+        0x8d7c558d297b2929,
+        0xe96307e964ac6464,
+        0x63e7f8631f211f1f,
+        0x23ac0123adeaadad,
+        0x1cc7921c55ff5555,
+        0x5f8b985f13351313,
+        0x6d0ab16dbbd0bbbb,
+        0x0c1ceb0cf704f7f7,
+        0xce305fce6fb16f6f,
+        0x6718a167b9d6b9b9,
+        0x4645024647c94747,
+        0x934a65932f712f2f,
+        0x71cd2371ee2feeee,
+        0x6211a962b8d5b8b8,
+        0x8a84ff8a7b8d7b7b,
+        0x97b53c9789868989,
+        0xf0ad9df030503030,
+        0xb805d6b8d368d3d3,
+        0x9ea0df9e7f817f7f,
+        0xb3e197b3769a7676,
+        0xb0e664b0829b8282,
+    ],
+    [
+        0x0f67d568b86868d5,
+        0x1eceb7d06dd0d0b7,
+        0xe00b60eb20ebeb60,
+        0x6e45872b7d2b2b87,
+        0x327a7548d8484875,
+        0x019cd39dba9d9dd3,
+        0x1d77df6abe6a6adf,
+        0x977353e431e4e453,
+        0xa84b48e338e3e348,
+        0xd27115a3f8a3a315,
+        0xdc8a1356fa565613,
+        0xfd7cbf819e8181bf,
+        0xb2cf947d877d7d94,
+        0x2adb12f10ef1f112,
+        0xd95cab85928585ab,
+        0x1a84dc9ebf9e9edc,
+        0x517d9c2c742c2c9c,
+        0x8a048c8e8f8e8e8c,
+        0x9fe7857888787885,
+        0xd41ec5ca43cacac5,
+        0xafb84b173917174b,
+        0x882137a9e6a9a937,
+        0x4e2ff861a36161f8,
+        0x33e6a6d562d5d5a6,
+        0x8fd2345de75d5d34,
+        0x5358270b1d0b0b27,
+        0x9814868c898c8c86,
+        0xc1fdcc3c443c3ccc,
+        0xe89fb677997777b6,
+        0xe3b20851f3515108,
+        0x2f0daa22662222aa,
+        0x682a5742c6424257,
+        0xdae5c33f413f3fc3,
+        0xce9a1954fc545419,
+        0x73325841c3414158,
+        0xf474ba809d8080ba,
+        0xe22edbcc49ccccdb,
+        0xc244a486978686a4,
+        0x42f145b3c8b3b345,
+        0xd8c0781828181878,
+        0x436d962e722e2e96,
+        0xd5821657f9575716,
+        0x36301e060a06061e,
+        0x5537f762a66262f7,
+        0x07f303f401f4f403,
+        0x9badee365a3636ee,
+        0x17c6b2d16ed1d1b2,
+        0x147fda6bbd6b6bda,
+        0xc3d8771b2d1b1b77,
+        0x6a0fec65af6565ec,
+        0xfa8fbc759f7575bc,
+        0x9080501030101050,
+        0x449e95da73dada95,
+        0x3b727049db494970,
+        0x0b2dbe266a2626be,
+        0x629b3af916f9f93a,
+        0xdd16c0cb40cbcbc0,
+        0x7117e366aa6666e3,
+        0x8c6b5ce734e7e75c,
+        0x03b968bad3baba68,
+        0xb7192caeefaeae2c,
+        0xeaba0d50f050500d,
+        0xf8aa0752f6525207,
+        0x9a313dabe0abab3d,
+        0x2d2811050f050511,
+        0x23d317f00df0f017,
+        0x6568390d170d0d39,
+        0xccbfa273957373a2,
+        0xfec5d73b4d3b3bd7,
+        0x242014040c040414,
+        0x3d1da020602020a0,
+        0x5da321fe1ffefe21,
+        0x7ba68edd7adddd8e,
+        0x0efb06f502f5f506,
+        0x7dc95eb4c1b4b45e,
+        0x9dc23e5fe15f5f3e,
+        0x5a50220a1e0a0a22,
+        0x74c15bb5c2b5b55b,
+        0x8e4ee7c05dc0c0e7,
+        0xc9691aa0fda0a01a,
+        0xdeafa871937171a8,
+        0xe4410ba5f2a5a50b,
+        0x5875992d772d2d99,
+        0x4727fd60a06060fd,
+        0xc5b7a772967272a7,
+        0x7fece593a89393e5,
+        0xecd5dd394b3939dd,
+        0x4840280818080828,
+        0xef6cb583988383b5,
+        0x3415a521632121a5,
+        0x86da315ce45c5c31,
+        0xcb4ca187948787a1,
+        0x50e14fb1ceb1b14f,
+        0xb35347e03de0e047,
+        0x0000000000000000,
+        0x9556e8c358c3c3e8,
+        0x82905a123612125a,
+        0x6dfcef91ae9191ef,
+        0xae24988a838a8a98,
+        0x12100a020602020a,
+        0xfce06c1c241c1c6c,
+        0x856359e637e6e659,
+        0x57124c45cf45454c,
+        0x9c5eedc25bc2c2ed,
+        0xaa6ef3c451c4c4f3,
+        0x46bb2efd1afdfd2e,
+        0x2e9179bfdcbfbf79,
+        0x5e1a4944cc444449,
+        0xc0611fa1fea1a11f,
+        0x165a614cd44c4c61,
+        0xb685ff33553333ff,
+        0xa366f6c552c5c5f6,
+        0xd054ae84918484ae,
+        0x2605af23652323af,
+        0xbbc7917c847c7c91,
+        0x59e94ab0cdb0b04a,
+        0x1035b1256f2525b1,
+        0xbda841153f151541,
+        0x80b5e1355f3535e1,
+        0x066fd069bb6969d0,
+        0x54ab24ff1cffff24,
+        0x40d4fe94a19494fe,
+        0x1f52644dd74d4d64,
+        0xd7a7ad70907070ad,
+        0xdb7910a2fba2a210,
+        0xbe1129afecafaf29,
+        0xeb26decd4acdcdde,
+        0x28fea9d667d6d6a9,
+        0x2b47c16cb46c6cc1,
+        0x66d151b7c4b7b751,
+        0x6b933ff815f8f83f,
+        0x41482d091b09092d,
+        0x38cb18f308f3f318,
+        0x781fe667a96767e6,
+        0xed490ea4f1a4a40e,
+        0xe90365ea23eaea65,
+        0xdf337bec29ecec7b,
+        0x6fd954b6c7b6b654,
+        0x3aeea3d461d4d4a3,
+        0x0cdebdd26bd2d2bd,
+        0xb4a044143c141444,
+        0xeef0661e221e1e66,
+        0xba5b42e13ee1e142,
+        0x193db4246c2424b4,
+        0xe5ddd838483838d8,
+        0xb87ef9c657c6c6f9,
+        0x4d9690db70dbdb90,
+        0x29627a4bdd4b4b7a,
+        0x8df78f7a8e7a7a8f,
+        0xf7cdd23a4e3a3ad2,
+        0x60be81de7fdede81,
+        0x94ca3b5ee25e5e3b,
+        0x69b684df7cdfdf84,
+        0x49dcfb95a29595fb,
+        0x4fb32bfc19fcfc2b,
+        0x933938aae3aaaa38,
+        0x21f6acd764d7d7ac,
+        0xf03ed1ce4fceced1,
+        0x3f381b070907071b,
+        0x7778330f110f0f33,
+        0xc8f5c93d473d3dc9,
+        0xa2fa2558e8585825,
+        0x3ea4c89ab39a9ac8,
+        0x2cb4c298b59898c2,
+        0x0894d69cb99c9cd6,
+        0x31c31df20bf2f21d,
+        0xf65101a7f4a7a701,
+        0x9988551133111155,
+        0xa9d79b7e827e7e9b,
+        0xa72c9d8b808b8b9d,
+        0x61225243c5434352,
+        0x1b180f030503030f,
+        0xa1434de23be2e24d,
+        0x72ae8bdc79dcdc8b,
+        0x9e7b56e532e5e556,
+        0x4bf940b2cbb2b240,
+        0x044a6b4ed24e4e6b,
+        0xb176fcc754c7c7fc,
+        0x224fc46db76d6dc4,
+        0xf21b6ae926e9e96a,
+        0x0225bb27692727bb,
+        0x7a3a5d40c040405d,
+        0x568e9fd875d8d89f,
+        0x92a5eb37593737eb,
+        0x76e4e092ab9292e0,
+        0x830c898f8c8f8f89,
+        0x0908050103010105,
+        0xf5e8691d271d1d69,
+        0xf1a20253f5535302,
+        0xd3edc63e423e3ec6,
+        0xabf22059eb595920,
+        0x8746e2c15ec1c1e2,
+        0x0d426e4fd14f4f6e,
+        0xbf8dfa32563232fa,
+        0xa6b04e163a16164e,
+        0x798335fa13fafa35,
+        0xf387b9749c7474b9,
+        0x708b30fb10fbfb30,
+        0x5c3ff263a56363f2,
+        0x138cd99fbc9f9fd9,
+        0x89bde4345c3434e4,
+        0xcad0721a2e1a1a72,
+        0x674d822a7e2a2a82,
+        0xb0ea2f5aee5a5a2f,
+        0x911c838d8a8d8d83,
+        0xcf06cac946c9c9ca,
+        0xf936d4cf4ccfcfd4,
+        0x15e309f607f6f609,
+        0x64f4ea90ad9090ea,
+        0x755d882878282888,
+        0xbc34928885888892,
+        0x37accd9bb09b9bcd,
+        0xa495f531533131f5,
+        0x7e70360e120e0e36,
+        0x3c8173bddabdbd73,
+        0x206a7f4ade4a4a7f,
+        0xfb136fe825e8e86f,
+        0x52c4f496a79696f4,
+        0xff5904a6f7a6a604,
+        0x6c603c0c140c0c3c,
+        0xc60ecfc845c8c8cf,
+        0x96ef80798b797980,
+        0x358976bcd9bcbc76,
+        0x27997cbedfbebe7c,
+        0xc42b74ef2cefef74,
+        0x3957cb6eb26e6ecb,
+        0x4c0a4346ca464643,
+        0x5bccf197a49797f1,
+        0xb9e22a5bed5b5b2a,
+        0xd63b7eed2aeded7e,
+        0xd1c87d192b19197d,
+        0x5f869ad976d9d99a,
+        0xa50926ace9acac26,
+        0x25bcc799b69999c7,
+        0x812932a8e5a8a832,
+        0x7c558d297b29298d,
+        0x6307e964ac6464e9,
+        0xe7f8631f211f1f63,
+        0xac0123adeaadad23,
+        0xc7921c55ff55551c,
+        0x8b985f133513135f,
+        0x0ab16dbbd0bbbb6d,
+        0x1ceb0cf704f7f70c,
+        0x305fce6fb16f6fce,
+        0x18a167b9d6b9b967,
+        0x45024647c9474746,
+        0x4a65932f712f2f93,
+        0xcd2371ee2feeee71,
+        0x11a962b8d5b8b862,
+        0x84ff8a7b8d7b7b8a,
+        0xb53c978986898997,
+        0xad9df030503030f0,
+        0x05d6b8d368d3d3b8,
+        0xa0df9e7f817f7f9e,
+        0xe197b3769a7676b3,
+        0xe664b0829b8282b0,
+    ],
+    [
+        0x67d568b86868d50f,
+        0xceb7d06dd0d0b71e,
+        0x0b60eb20ebeb60e0,
+        0x45872b7d2b2b876e,
+        0x7a7548d848487532,
+        0x9cd39dba9d9dd301,
+        0x77df6abe6a6adf1d,
+        0x7353e431e4e45397,
+        0x4b48e338e3e348a8,
+        0x7115a3f8a3a315d2,
+        0x8a1356fa565613dc,
+        0x7cbf819e8181bffd,
+        0xcf947d877d7d94b2,
+        0xdb12f10ef1f1122a,
+        0x5cab85928585abd9,
+        0x84dc9ebf9e9edc1a,
+        0x7d9c2c742c2c9c51,
+        0x048c8e8f8e8e8c8a,
+        0xe78578887878859f,
+        0x1ec5ca43cacac5d4,
+        0xb84b173917174baf,
+        0x2137a9e6a9a93788,
+        0x2ff861a36161f84e,
+        0xe6a6d562d5d5a633,
+        0xd2345de75d5d348f,
+        0x58270b1d0b0b2753,
+        0x14868c898c8c8698,
+        0xfdcc3c443c3cccc1,
+        0x9fb677997777b6e8,
+        0xb20851f3515108e3,
+        0x0daa22662222aa2f,
+        0x2a5742c642425768,
+        0xe5c33f413f3fc3da,
+        0x9a1954fc545419ce,
+        0x325841c341415873,
+        0x74ba809d8080baf4,
+        0x2edbcc49ccccdbe2,
+        0x44a486978686a4c2,
+        0xf145b3c8b3b34542,
+        0xc0781828181878d8,
+        0x6d962e722e2e9643,
+        0x821657f9575716d5,
+        0x301e060a06061e36,
+        0x37f762a66262f755,
+        0xf303f401f4f40307,
+        0xadee365a3636ee9b,
+        0xc6b2d16ed1d1b217,
+        0x7fda6bbd6b6bda14,
+        0xd8771b2d1b1b77c3,
+        0x0fec65af6565ec6a,
+        0x8fbc759f7575bcfa,
+        0x8050103010105090,
+        0x9e95da73dada9544,
+        0x727049db4949703b,
+        0x2dbe266a2626be0b,
+        0x9b3af916f9f93a62,
+        0x16c0cb40cbcbc0dd,
+        0x17e366aa6666e371,
+        0x6b5ce734e7e75c8c,
+        0xb968bad3baba6803,
+        0x192caeefaeae2cb7,
+        0xba0d50f050500dea,
+        0xaa0752f6525207f8,
+        0x313dabe0abab3d9a,
+        0x2811050f0505112d,
+        0xd317f00df0f01723,
+        0x68390d170d0d3965,
+        0xbfa273957373a2cc,
+        0xc5d73b4d3b3bd7fe,
+        0x2014040c04041424,
+        0x1da020602020a03d,
+        0xa321fe1ffefe215d,
+        0xa68edd7adddd8e7b,
+        0xfb06f502f5f5060e,
+        0xc95eb4c1b4b45e7d,
+        0xc23e5fe15f5f3e9d,
+        0x50220a1e0a0a225a,
+        0xc15bb5c2b5b55b74,
+        0x4ee7c05dc0c0e78e,
+        0x691aa0fda0a01ac9,
+        0xafa871937171a8de,
+        0x410ba5f2a5a50be4,
+        0x75992d772d2d9958,
+        0x27fd60a06060fd47,
+        0xb7a772967272a7c5,
+        0xece593a89393e57f,
+        0xd5dd394b3939ddec,
+        0x4028081808082848,
+        0x6cb583988383b5ef,
+        0x15a521632121a534,
+        0xda315ce45c5c3186,
+        0x4ca187948787a1cb,
+        0xe14fb1ceb1b14f50,
+        0x5347e03de0e047b3,
+        0x0000000000000000,
+        0x56e8c358c3c3e895,
+        0x905a123612125a82,
+        0xfcef91ae9191ef6d,
+        0x24988a838a8a98ae,
+        0x100a020602020a12,
+        0xe06c1c241c1c6cfc,
+        0x6359e637e6e65985,
+        0x124c45cf45454c57,
+        0x5eedc25bc2c2ed9c,
+        0x6ef3c451c4c4f3aa,
+        0xbb2efd1afdfd2e46,
+        0x9179bfdcbfbf792e,
+        0x1a4944cc4444495e,
+        0x611fa1fea1a11fc0,
+        0x5a614cd44c4c6116,
+        0x85ff33553333ffb6,
+        0x66f6c552c5c5f6a3,
+        0x54ae84918484aed0,
+        0x05af23652323af26,
+        0xc7917c847c7c91bb,
+        0xe94ab0cdb0b04a59,
+        0x35b1256f2525b110,
+        0xa841153f151541bd,
+        0xb5e1355f3535e180,
+        0x6fd069bb6969d006,
+        0xab24ff1cffff2454,
+        0xd4fe94a19494fe40,
+        0x52644dd74d4d641f,
+        0xa7ad70907070add7,
+        0x7910a2fba2a210db,
+        0x1129afecafaf29be,
+        0x26decd4acdcddeeb,
+        0xfea9d667d6d6a928,
+        0x47c16cb46c6cc12b,
+        0xd151b7c4b7b75166,
+        0x933ff815f8f83f6b,
+        0x482d091b09092d41,
+        0xcb18f308f3f31838,
+        0x1fe667a96767e678,
+        0x490ea4f1a4a40eed,
+        0x0365ea23eaea65e9,
+        0x337bec29ecec7bdf,
+        0xd954b6c7b6b6546f,
+        0xeea3d461d4d4a33a,
+        0xdebdd26bd2d2bd0c,
+        0xa044143c141444b4,
+        0xf0661e221e1e66ee,
+        0x5b42e13ee1e142ba,
+        0x3db4246c2424b419,
+        0xddd838483838d8e5,
+        0x7ef9c657c6c6f9b8,
+        0x9690db70dbdb904d,
+        0x627a4bdd4b4b7a29,
+        0xf78f7a8e7a7a8f8d,
+        0xcdd23a4e3a3ad2f7,
+        0xbe81de7fdede8160,
+        0xca3b5ee25e5e3b94,
+        0xb684df7cdfdf8469,
+        0xdcfb95a29595fb49,
+        0xb32bfc19fcfc2b4f,
+        0x3938aae3aaaa3893,
+        0xf6acd764d7d7ac21,
+        0x3ed1ce4fceced1f0,
+        0x381b070907071b3f,
+        0x78330f110f0f3377,
+        0xf5c93d473d3dc9c8,
+        0xfa2558e8585825a2,
+        0xa4c89ab39a9ac83e,
+        0xb4c298b59898c22c,
+        0x94d69cb99c9cd608,
+        0xc31df20bf2f21d31,
+        0x5101a7f4a7a701f6,
+        0x8855113311115599,
+        0xd79b7e827e7e9ba9,
+        0x2c9d8b808b8b9da7,
+        0x225243c543435261,
+        0x180f030503030f1b,
+        0x434de23be2e24da1,
+        0xae8bdc79dcdc8b72,
+        0x7b56e532e5e5569e,
+        0xf940b2cbb2b2404b,
+        0x4a6b4ed24e4e6b04,
+        0x76fcc754c7c7fcb1,
+        0x4fc46db76d6dc422,
+        0x1b6ae926e9e96af2,
+        0x25bb27692727bb02,
+        0x3a5d40c040405d7a,
+        0x8e9fd875d8d89f56,
+        0xa5eb37593737eb92,
+        0xe4e092ab9292e076,
+        0x0c898f8c8f8f8983,
+        0x0805010301010509,
+        0xe8691d271d1d69f5,
+        0xa20253f5535302f1,
+        0xedc63e423e3ec6d3,
+        0xf22059eb595920ab,
+        0x46e2c15ec1c1e287,
+        0x426e4fd14f4f6e0d,
+        0x8dfa32563232fabf,
+        0xb04e163a16164ea6,
+        0x8335fa13fafa3579,
+        0x87b9749c7474b9f3,
+        0x8b30fb10fbfb3070,
+        0x3ff263a56363f25c,
+        0x8cd99fbc9f9fd913,
+        0xbde4345c3434e489,
+        0xd0721a2e1a1a72ca,
+        0x4d822a7e2a2a8267,
+        0xea2f5aee5a5a2fb0,
+        0x1c838d8a8d8d8391,
+        0x06cac946c9c9cacf,
+        0x36d4cf4ccfcfd4f9,
+        0xe309f607f6f60915,
+        0xf4ea90ad9090ea64,
+        0x5d88287828288875,
+        0x34928885888892bc,
+        0xaccd9bb09b9bcd37,
+        0x95f531533131f5a4,
+        0x70360e120e0e367e,
+        0x8173bddabdbd733c,
+        0x6a7f4ade4a4a7f20,
+        0x136fe825e8e86ffb,
+        0xc4f496a79696f452,
+        0x5904a6f7a6a604ff,
+        0x603c0c140c0c3c6c,
+        0x0ecfc845c8c8cfc6,
+        0xef80798b79798096,
+        0x8976bcd9bcbc7635,
+        0x997cbedfbebe7c27,
+        0x2b74ef2cefef74c4,
+        0x57cb6eb26e6ecb39,
+        0x0a4346ca4646434c,
+        0xccf197a49797f15b,
+        0xe22a5bed5b5b2ab9,
+        0x3b7eed2aeded7ed6,
+        0xc87d192b19197dd1,
+        0x869ad976d9d99a5f,
+        0x0926ace9acac26a5,
+        0xbcc799b69999c725,
+        0x2932a8e5a8a83281,
+        0x558d297b29298d7c,
+        0x07e964ac6464e963,
+        0xf8631f211f1f63e7,
+        0x0123adeaadad23ac,
+        0x921c55ff55551cc7,
+        0x985f133513135f8b,
+        0xb16dbbd0bbbb6d0a,
+        0xeb0cf704f7f70c1c,
+        0x5fce6fb16f6fce30,
+        0xa167b9d6b9b96718,
+        0x024647c947474645,
+        0x65932f712f2f934a,
+        0x2371ee2feeee71cd,
+        0xa962b8d5b8b86211,
+        0xff8a7b8d7b7b8a84,
+        0x3c978986898997b5,
+        0x9df030503030f0ad,
+        0xd6b8d368d3d3b805,
+        0xdf9e7f817f7f9ea0,
+        0x97b3769a7676b3e1,
+        0x64b0829b8282b0e6,
+    ],
+    [
+        0xd568b86868d50f67,
+        0xb7d06dd0d0b71ece,
+        0x60eb20ebeb60e00b,
+        0x872b7d2b2b876e45,
+        0x7548d8484875327a,
+        0xd39dba9d9dd3019c,
+        0xdf6abe6a6adf1d77,
+        0x53e431e4e4539773,
+        0x48e338e3e348a84b,
+        0x15a3f8a3a315d271,
+        0x1356fa565613dc8a,
+        0xbf819e8181bffd7c,
+        0x947d877d7d94b2cf,
+        0x12f10ef1f1122adb,
+        0xab85928585abd95c,
+        0xdc9ebf9e9edc1a84,
+        0x9c2c742c2c9c517d,
+        0x8c8e8f8e8e8c8a04,
+        0x8578887878859fe7,
+        0xc5ca43cacac5d41e,
+        0x4b173917174bafb8,
+        0x37a9e6a9a9378821,
+        0xf861a36161f84e2f,
+        0xa6d562d5d5a633e6,
+        0x345de75d5d348fd2,
+        0x270b1d0b0b275358,
+        0x868c898c8c869814,
+        0xcc3c443c3cccc1fd,
+        0xb677997777b6e89f,
+        0x0851f3515108e3b2,
+        0xaa22662222aa2f0d,
+        0x5742c6424257682a,
+        0xc33f413f3fc3dae5,
+        0x1954fc545419ce9a,
+        0x5841c34141587332,
+        0xba809d8080baf474,
+        0xdbcc49ccccdbe22e,
+        0xa486978686a4c244,
+        0x45b3c8b3b34542f1,
+        0x781828181878d8c0,
+        0x962e722e2e96436d,
+        0x1657f9575716d582,
+        0x1e060a06061e3630,
+        0xf762a66262f75537,
+        0x03f401f4f40307f3,
+        0xee365a3636ee9bad,
+        0xb2d16ed1d1b217c6,
+        0xda6bbd6b6bda147f,
+        0x771b2d1b1b77c3d8,
+        0xec65af6565ec6a0f,
+        0xbc759f7575bcfa8f,
+        0x5010301010509080,
+        0x95da73dada95449e,
+        0x7049db4949703b72,
+        0xbe266a2626be0b2d,
+        0x3af916f9f93a629b,
+        0xc0cb40cbcbc0dd16,
+        0xe366aa6666e37117,
+        0x5ce734e7e75c8c6b,
+        0x68bad3baba6803b9,
+        0x2caeefaeae2cb719,
+        0x0d50f050500deaba,
+        0x0752f6525207f8aa,
+        0x3dabe0abab3d9a31,
+        0x11050f0505112d28,
+        0x17f00df0f01723d3,
+        0x390d170d0d396568,
+        0xa273957373a2ccbf,
+        0xd73b4d3b3bd7fec5,
+        0x14040c0404142420,
+        0xa020602020a03d1d,
+        0x21fe1ffefe215da3,
+        0x8edd7adddd8e7ba6,
+        0x06f502f5f5060efb,
+        0x5eb4c1b4b45e7dc9,
+        0x3e5fe15f5f3e9dc2,
+        0x220a1e0a0a225a50,
+        0x5bb5c2b5b55b74c1,
+        0xe7c05dc0c0e78e4e,
+        0x1aa0fda0a01ac969,
+        0xa871937171a8deaf,
+        0x0ba5f2a5a50be441,
+        0x992d772d2d995875,
+        0xfd60a06060fd4727,
+        0xa772967272a7c5b7,
+        0xe593a89393e57fec,
+        0xdd394b3939ddecd5,
+        0x2808180808284840,
+        0xb583988383b5ef6c,
+        0xa521632121a53415,
+        0x315ce45c5c3186da,
+        0xa187948787a1cb4c,
+        0x4fb1ceb1b14f50e1,
+        0x47e03de0e047b353,
+        0x0000000000000000,
+        0xe8c358c3c3e89556,
+        0x5a123612125a8290,
+        0xef91ae9191ef6dfc,
+        0x988a838a8a98ae24,
+        0x0a020602020a1210,
+        0x6c1c241c1c6cfce0,
+        0x59e637e6e6598563,
+        0x4c45cf45454c5712,
+        0xedc25bc2c2ed9c5e,
+        0xf3c451c4c4f3aa6e,
+        0x2efd1afdfd2e46bb,
+        0x79bfdcbfbf792e91,
+        0x4944cc4444495e1a,
+        0x1fa1fea1a11fc061,
+        0x614cd44c4c61165a,
+        0xff33553333ffb685,
+        0xf6c552c5c5f6a366,
+        0xae84918484aed054,
+        0xaf23652323af2605,
+        0x917c847c7c91bbc7,
+        0x4ab0cdb0b04a59e9,
+        0xb1256f2525b11035,
+        0x41153f151541bda8,
+        0xe1355f3535e180b5,
+        0xd069bb6969d0066f,
+        0x24ff1cffff2454ab,
+        0xfe94a19494fe40d4,
+        0x644dd74d4d641f52,
+        0xad70907070add7a7,
+        0x10a2fba2a210db79,
+        0x29afecafaf29be11,
+        0xdecd4acdcddeeb26,
+        0xa9d667d6d6a928fe,
+        0xc16cb46c6cc12b47,
+        0x51b7c4b7b75166d1,
+        0x3ff815f8f83f6b93,
+        0x2d091b09092d4148,
+        0x18f308f3f31838cb,
+        0xe667a96767e6781f,
+        0x0ea4f1a4a40eed49,
+        0x65ea23eaea65e903,
+        0x7bec29ecec7bdf33,
+        0x54b6c7b6b6546fd9,
+        0xa3d461d4d4a33aee,
+        0xbdd26bd2d2bd0cde,
+        0x44143c141444b4a0,
+        0x661e221e1e66eef0,
+        0x42e13ee1e142ba5b,
+        0xb4246c2424b4193d,
+        0xd838483838d8e5dd,
+        0xf9c657c6c6f9b87e,
+        0x90db70dbdb904d96,
+        0x7a4bdd4b4b7a2962,
+        0x8f7a8e7a7a8f8df7,
+        0xd23a4e3a3ad2f7cd,
+        0x81de7fdede8160be,
+        0x3b5ee25e5e3b94ca,
+        0x84df7cdfdf8469b6,
+        0xfb95a29595fb49dc,
+        0x2bfc19fcfc2b4fb3,
+        0x38aae3aaaa389339,
+        0xacd764d7d7ac21f6,
+        0xd1ce4fceced1f03e,
+        0x1b070907071b3f38,
+        0x330f110f0f337778,
+        0xc93d473d3dc9c8f5,
+        0x2558e8585825a2fa,
+        0xc89ab39a9ac83ea4,
+        0xc298b59898c22cb4,
+        0xd69cb99c9cd60894,
+        0x1df20bf2f21d31c3,
+        0x01a7f4a7a701f651,
+        0x5511331111559988,
+        0x9b7e827e7e9ba9d7,
+        0x9d8b808b8b9da72c,
+        0x5243c54343526122,
+        0x0f030503030f1b18,
+        0x4de23be2e24da143,
+        0x8bdc79dcdc8b72ae,
+        0x56e532e5e5569e7b,
+        0x40b2cbb2b2404bf9,
+        0x6b4ed24e4e6b044a,
+        0xfcc754c7c7fcb176,
+        0xc46db76d6dc4224f,
+        0x6ae926e9e96af21b,
+        0xbb27692727bb0225,
+        0x5d40c040405d7a3a,
+        0x9fd875d8d89f568e,
+        0xeb37593737eb92a5,
+        0xe092ab9292e076e4,
+        0x898f8c8f8f89830c,
+        0x0501030101050908,
+        0x691d271d1d69f5e8,
+        0x0253f5535302f1a2,
+        0xc63e423e3ec6d3ed,
+        0x2059eb595920abf2,
+        0xe2c15ec1c1e28746,
+        0x6e4fd14f4f6e0d42,
+        0xfa32563232fabf8d,
+        0x4e163a16164ea6b0,
+        0x35fa13fafa357983,
+        0xb9749c7474b9f387,
+        0x30fb10fbfb30708b,
+        0xf263a56363f25c3f,
+        0xd99fbc9f9fd9138c,
+        0xe4345c3434e489bd,
+        0x721a2e1a1a72cad0,
+        0x822a7e2a2a82674d,
+        0x2f5aee5a5a2fb0ea,
+        0x838d8a8d8d83911c,
+        0xcac946c9c9cacf06,
+        0xd4cf4ccfcfd4f936,
+        0x09f607f6f60915e3,
+        0xea90ad9090ea64f4,
+        0x882878282888755d,
+        0x928885888892bc34,
+        0xcd9bb09b9bcd37ac,
+        0xf531533131f5a495,
+        0x360e120e0e367e70,
+        0x73bddabdbd733c81,
+        0x7f4ade4a4a7f206a,
+        0x6fe825e8e86ffb13,
+        0xf496a79696f452c4,
+        0x04a6f7a6a604ff59,
+        0x3c0c140c0c3c6c60,
+        0xcfc845c8c8cfc60e,
+        0x80798b79798096ef,
+        0x76bcd9bcbc763589,
+        0x7cbedfbebe7c2799,
+        0x74ef2cefef74c42b,
+        0xcb6eb26e6ecb3957,
+        0x4346ca4646434c0a,
+        0xf197a49797f15bcc,
+        0x2a5bed5b5b2ab9e2,
+        0x7eed2aeded7ed63b,
+        0x7d192b19197dd1c8,
+        0x9ad976d9d99a5f86,
+        0x26ace9acac26a509,
+        0xc799b69999c725bc,
+        0x32a8e5a8a8328129,
+        0x8d297b29298d7c55,
+        0xe964ac6464e96307,
+        0x631f211f1f63e7f8,
+        0x23adeaadad23ac01,
+        0x1c55ff55551cc792,
+        0x5f133513135f8b98,
+        0x6dbbd0bbbb6d0ab1,
+        0x0cf704f7f70c1ceb,
+        0xce6fb16f6fce305f,
+        0x67b9d6b9b96718a1,
+        0x4647c94747464502,
+        0x932f712f2f934a65,
+        0x71ee2feeee71cd23,
+        0x62b8d5b8b86211a9,
+        0x8a7b8d7b7b8a84ff,
+        0x978986898997b53c,
+        0xf030503030f0ad9d,
+        0xb8d368d3d3b805d6,
+        0x9e7f817f7f9ea0df,
+        0xb3769a7676b3e197,
+        0xb0829b8282b0e664,
+    ],
+    [
+        0x68b86868d50f67d5,
+        0xd06dd0d0b71eceb7,
+        0xeb20ebeb60e00b60,
+        0x2b7d2b2b876e4587,
+        0x48d8484875327a75,
+        0x9dba9d9dd3019cd3,
+        0x6abe6a6adf1d77df,
+        0xe431e4e453977353,
+        0xe338e3e348a84b48,
+        0xa3f8a3a315d27115,
+        0x56fa565613dc8a13,
+        0x819e8181bffd7cbf,
+        0x7d877d7d94b2cf94,
+        0xf10ef1f1122adb12,
+        0x85928585abd95cab,
+        0x9ebf9e9edc1a84dc,
+        0x2c742c2c9c517d9c,
+        0x8e8f8e8e8c8a048c,
+        0x78887878859fe785,
+        0xca43cacac5d41ec5,
+        0x173917174bafb84b,
+        0xa9e6a9a937882137,
+        0x61a36161f84e2ff8,
+        0xd562d5d5a633e6a6,
+        0x5de75d5d348fd234,
+        0x0b1d0b0b27535827,
+        0x8c898c8c86981486,
+        0x3c443c3cccc1fdcc,
+        0x77997777b6e89fb6,
+        0x51f3515108e3b208,
+        0x22662222aa2f0daa,
+        0x42c6424257682a57,
+        0x3f413f3fc3dae5c3,
+        0x54fc545419ce9a19,
+        0x41c3414158733258,
+        0x809d8080baf474ba,
+        0xcc49ccccdbe22edb,
+        0x86978686a4c244a4,
+        0xb3c8b3b34542f145,
+        0x1828181878d8c078,
+        0x2e722e2e96436d96,
+        0x57f9575716d58216,
+        0x060a06061e36301e,
+        0x62a66262f75537f7,
+        0xf401f4f40307f303,
+        0x365a3636ee9badee,
+        0xd16ed1d1b217c6b2,
+        0x6bbd6b6bda147fda,
+        0x1b2d1b1b77c3d877,
+        0x65af6565ec6a0fec,
+        0x759f7575bcfa8fbc,
+        0x1030101050908050,
+        0xda73dada95449e95,
+        0x49db4949703b7270,
+        0x266a2626be0b2dbe,
+        0xf916f9f93a629b3a,
+        0xcb40cbcbc0dd16c0,
+        0x66aa6666e37117e3,
+        0xe734e7e75c8c6b5c,
+        0xbad3baba6803b968,
+        0xaeefaeae2cb7192c,
+        0x50f050500deaba0d,
+        0x52f6525207f8aa07,
+        0xabe0abab3d9a313d,
+        0x050f0505112d2811,
+        0xf00df0f01723d317,
+        0x0d170d0d39656839,
+        0x73957373a2ccbfa2,
+        0x3b4d3b3bd7fec5d7,
+        0x040c040414242014,
+        0x20602020a03d1da0,
+        0xfe1ffefe215da321,
+        0xdd7adddd8e7ba68e,
+        0xf502f5f5060efb06,
+        0xb4c1b4b45e7dc95e,
+        0x5fe15f5f3e9dc23e,
+        0x0a1e0a0a225a5022,
+        0xb5c2b5b55b74c15b,
+        0xc05dc0c0e78e4ee7,
+        0xa0fda0a01ac9691a,
+        0x71937171a8deafa8,
+        0xa5f2a5a50be4410b,
+        0x2d772d2d99587599,
+        0x60a06060fd4727fd,
+        0x72967272a7c5b7a7,
+        0x93a89393e57fece5,
+        0x394b3939ddecd5dd,
+        0x0818080828484028,
+        0x83988383b5ef6cb5,
+        0x21632121a53415a5,
+        0x5ce45c5c3186da31,
+        0x87948787a1cb4ca1,
+        0xb1ceb1b14f50e14f,
+        0xe03de0e047b35347,
+        0x0000000000000000,
+        0xc358c3c3e89556e8,
+        0x123612125a82905a,
+        0x91ae9191ef6dfcef,
+        0x8a838a8a98ae2498,
+        0x020602020a12100a,
+        0x1c241c1c6cfce06c,
+        0xe637e6e659856359,
+        0x45cf45454c57124c,
+        0xc25bc2c2ed9c5eed,
+        0xc451c4c4f3aa6ef3,
+        0xfd1afdfd2e46bb2e,
+        0xbfdcbfbf792e9179,
+        0x44cc4444495e1a49,
+        0xa1fea1a11fc0611f,
+        0x4cd44c4c61165a61,
+        0x33553333ffb685ff,
+        0xc552c5c5f6a366f6,
+        0x84918484aed054ae,
+        0x23652323af2605af,
+        0x7c847c7c91bbc791,
+        0xb0cdb0b04a59e94a,
+        0x256f2525b11035b1,
+        0x153f151541bda841,
+        0x355f3535e180b5e1,
+        0x69bb6969d0066fd0,
+        0xff1cffff2454ab24,
+        0x94a19494fe40d4fe,
+        0x4dd74d4d641f5264,
+        0x70907070add7a7ad,
+        0xa2fba2a210db7910,
+        0xafecafaf29be1129,
+        0xcd4acdcddeeb26de,
+        0xd667d6d6a928fea9,
+        0x6cb46c6cc12b47c1,
+        0xb7c4b7b75166d151,
+        0xf815f8f83f6b933f,
+        0x091b09092d41482d,
+        0xf308f3f31838cb18,
+        0x67a96767e6781fe6,
+        0xa4f1a4a40eed490e,
+        0xea23eaea65e90365,
+        0xec29ecec7bdf337b,
+        0xb6c7b6b6546fd954,
+        0xd461d4d4a33aeea3,
+        0xd26bd2d2bd0cdebd,
+        0x143c141444b4a044,
+        0x1e221e1e66eef066,
+        0xe13ee1e142ba5b42,
+        0x246c2424b4193db4,
+        0x38483838d8e5ddd8,
+        0xc657c6c6f9b87ef9,
+        0xdb70dbdb904d9690,
+        0x4bdd4b4b7a29627a,
+        0x7a8e7a7a8f8df78f,
+        0x3a4e3a3ad2f7cdd2,
+        0xde7fdede8160be81,
+        0x5ee25e5e3b94ca3b,
+        0xdf7cdfdf8469b684,
+        0x95a29595fb49dcfb,
+        0xfc19fcfc2b4fb32b,
+        0xaae3aaaa38933938,
+        0xd764d7d7ac21f6ac,
+        0xce4fceced1f03ed1,
+        0x070907071b3f381b,
+        0x0f110f0f33777833,
+        0x3d473d3dc9c8f5c9,
+        0x58e8585825a2fa25,
+        0x9ab39a9ac83ea4c8,
+        0x98b59898c22cb4c2,
+        0x9cb99c9cd60894d6,
+        0xf20bf2f21d31c31d,
+        0xa7f4a7a701f65101,
+        0x1133111155998855,
+        0x7e827e7e9ba9d79b,
+        0x8b808b8b9da72c9d,
+        0x43c5434352612252,
+        0x030503030f1b180f,
+        0xe23be2e24da1434d,
+        0xdc79dcdc8b72ae8b,
+        0xe532e5e5569e7b56,
+        0xb2cbb2b2404bf940,
+        0x4ed24e4e6b044a6b,
+        0xc754c7c7fcb176fc,
+        0x6db76d6dc4224fc4,
+        0xe926e9e96af21b6a,
+        0x27692727bb0225bb,
+        0x40c040405d7a3a5d,
+        0xd875d8d89f568e9f,
+        0x37593737eb92a5eb,
+        0x92ab9292e076e4e0,
+        0x8f8c8f8f89830c89,
+        0x0103010105090805,
+        0x1d271d1d69f5e869,
+        0x53f5535302f1a202,
+        0x3e423e3ec6d3edc6,
+        0x59eb595920abf220,
+        0xc15ec1c1e28746e2,
+        0x4fd14f4f6e0d426e,
+        0x32563232fabf8dfa,
+        0x163a16164ea6b04e,
+        0xfa13fafa35798335,
+        0x749c7474b9f387b9,
+        0xfb10fbfb30708b30,
+        0x63a56363f25c3ff2,
+        0x9fbc9f9fd9138cd9,
+        0x345c3434e489bde4,
+        0x1a2e1a1a72cad072,
+        0x2a7e2a2a82674d82,
+        0x5aee5a5a2fb0ea2f,
+        0x8d8a8d8d83911c83,
+        0xc946c9c9cacf06ca,
+        0xcf4ccfcfd4f936d4,
+        0xf607f6f60915e309,
+        0x90ad9090ea64f4ea,
+        0x2878282888755d88,
+        0x8885888892bc3492,
+        0x9bb09b9bcd37accd,
+        0x31533131f5a495f5,
+        0x0e120e0e367e7036,
+        0xbddabdbd733c8173,
+        0x4ade4a4a7f206a7f,
+        0xe825e8e86ffb136f,
+        0x96a79696f452c4f4,
+        0xa6f7a6a604ff5904,
+        0x0c140c0c3c6c603c,
+        0xc845c8c8cfc60ecf,
+        0x798b79798096ef80,
+        0xbcd9bcbc76358976,
+        0xbedfbebe7c27997c,
+        0xef2cefef74c42b74,
+        0x6eb26e6ecb3957cb,
+        0x46ca4646434c0a43,
+        0x97a49797f15bccf1,
+        0x5bed5b5b2ab9e22a,
+        0xed2aeded7ed63b7e,
+        0x192b19197dd1c87d,
+        0xd976d9d99a5f869a,
+        0xace9acac26a50926,
+        0x99b69999c725bcc7,
+        0xa8e5a8a832812932,
+        0x297b29298d7c558d,
+        0x64ac6464e96307e9,
+        0x1f211f1f63e7f863,
+        0xadeaadad23ac0123,
+        0x55ff55551cc7921c,
+        0x133513135f8b985f,
+        0xbbd0bbbb6d0ab16d,
+        0xf704f7f70c1ceb0c,
+        0x6fb16f6fce305fce,
+        0xb9d6b9b96718a167,
+        0x47c9474746450246,
+        0x2f712f2f934a6593,
+        0xee2feeee71cd2371,
+        0xb8d5b8b86211a962,
+        0x7b8d7b7b8a84ff8a,
+        0x8986898997b53c97,
+        0x30503030f0ad9df0,
+        0xd368d3d3b805d6b8,
+        0x7f817f7f9ea0df9e,
+        0x769a7676b3e197b3,
+        0x829b8282b0e664b0,
+    ],
+    [
+        0xb86868d50f67d568,
+        0x6dd0d0b71eceb7d0,
+        0x20ebeb60e00b60eb,
+        0x7d2b2b876e45872b,
+        0xd8484875327a7548,
+        0xba9d9dd3019cd39d,
+        0xbe6a6adf1d77df6a,
+        0x31e4e453977353e4,
+        0x38e3e348a84b48e3,
+        0xf8a3a315d27115a3,
+        0xfa565613dc8a1356,
+        0x9e8181bffd7cbf81,
+        0x877d7d94b2cf947d,
+        0x0ef1f1122adb12f1,
+        0x928585abd95cab85,
+        0xbf9e9edc1a84dc9e,
+        0x742c2c9c517d9c2c,
+        0x8f8e8e8c8a048c8e,
+        0x887878859fe78578,
+        0x43cacac5d41ec5ca,
+        0x3917174bafb84b17,
+        0xe6a9a937882137a9,
+        0xa36161f84e2ff861,
+        0x62d5d5a633e6a6d5,
+        0xe75d5d348fd2345d,
+        0x1d0b0b275358270b,
+        0x898c8c869814868c,
+        0x443c3cccc1fdcc3c,
+        0x997777b6e89fb677,
+        0xf3515108e3b20851,
+        0x662222aa2f0daa22,
+        0xc6424257682a5742,
+        0x413f3fc3dae5c33f,
+        0xfc545419ce9a1954,
+        0xc341415873325841,
+        0x9d8080baf474ba80,
+        0x49ccccdbe22edbcc,
+        0x978686a4c244a486,
+        0xc8b3b34542f145b3,
+        0x28181878d8c07818,
+        0x722e2e96436d962e,
+        0xf9575716d5821657,
+        0x0a06061e36301e06,
+        0xa66262f75537f762,
+        0x01f4f40307f303f4,
+        0x5a3636ee9badee36,
+        0x6ed1d1b217c6b2d1,
+        0xbd6b6bda147fda6b,
+        0x2d1b1b77c3d8771b,
+        0xaf6565ec6a0fec65,
+        0x9f7575bcfa8fbc75,
+        0x3010105090805010,
+        0x73dada95449e95da,
+        0xdb4949703b727049,
+        0x6a2626be0b2dbe26,
+        0x16f9f93a629b3af9,
+        0x40cbcbc0dd16c0cb,
+        0xaa6666e37117e366,
+        0x34e7e75c8c6b5ce7,
+        0xd3baba6803b968ba,
+        0xefaeae2cb7192cae,
+        0xf050500deaba0d50,
+        0xf6525207f8aa0752,
+        0xe0abab3d9a313dab,
+        0x0f0505112d281105,
+        0x0df0f01723d317f0,
+        0x170d0d396568390d,
+        0x957373a2ccbfa273,
+        0x4d3b3bd7fec5d73b,
+        0x0c04041424201404,
+        0x602020a03d1da020,
+        0x1ffefe215da321fe,
+        0x7adddd8e7ba68edd,
+        0x02f5f5060efb06f5,
+        0xc1b4b45e7dc95eb4,
+        0xe15f5f3e9dc23e5f,
+        0x1e0a0a225a50220a,
+        0xc2b5b55b74c15bb5,
+        0x5dc0c0e78e4ee7c0,
+        0xfda0a01ac9691aa0,
+        0x937171a8deafa871,
+        0xf2a5a50be4410ba5,
+        0x772d2d995875992d,
+        0xa06060fd4727fd60,
+        0x967272a7c5b7a772,
+        0xa89393e57fece593,
+        0x4b3939ddecd5dd39,
+        0x1808082848402808,
+        0x988383b5ef6cb583,
+        0x632121a53415a521,
+        0xe45c5c3186da315c,
+        0x948787a1cb4ca187,
+        0xceb1b14f50e14fb1,
+        0x3de0e047b35347e0,
+        0x0000000000000000,
+        0x58c3c3e89556e8c3,
+        0x3612125a82905a12,
+        0xae9191ef6dfcef91,
+        0x838a8a98ae24988a,
+        0x0602020a12100a02,
+        0x241c1c6cfce06c1c,
+        0x37e6e659856359e6,
+        0xcf45454c57124c45,
+        0x5bc2c2ed9c5eedc2,
+        0x51c4c4f3aa6ef3c4,
+        0x1afdfd2e46bb2efd,
+        0xdcbfbf792e9179bf,
+        0xcc4444495e1a4944,
+        0xfea1a11fc0611fa1,
+        0xd44c4c61165a614c,
+        0x553333ffb685ff33,
+        0x52c5c5f6a366f6c5,
+        0x918484aed054ae84,
+        0x652323af2605af23,
+        0x847c7c91bbc7917c,
+        0xcdb0b04a59e94ab0,
+        0x6f2525b11035b125,
+        0x3f151541bda84115,
+        0x5f3535e180b5e135,
+        0xbb6969d0066fd069,
+        0x1cffff2454ab24ff,
+        0xa19494fe40d4fe94,
+        0xd74d4d641f52644d,
+        0x907070add7a7ad70,
+        0xfba2a210db7910a2,
+        0xecafaf29be1129af,
+        0x4acdcddeeb26decd,
+        0x67d6d6a928fea9d6,
+        0xb46c6cc12b47c16c,
+        0xc4b7b75166d151b7,
+        0x15f8f83f6b933ff8,
+        0x1b09092d41482d09,
+        0x08f3f31838cb18f3,
+        0xa96767e6781fe667,
+        0xf1a4a40eed490ea4,
+        0x23eaea65e90365ea,
+        0x29ecec7bdf337bec,
+        0xc7b6b6546fd954b6,
+        0x61d4d4a33aeea3d4,
+        0x6bd2d2bd0cdebdd2,
+        0x3c141444b4a04414,
+        0x221e1e66eef0661e,
+        0x3ee1e142ba5b42e1,
+        0x6c2424b4193db424,
+        0x483838d8e5ddd838,
+        0x57c6c6f9b87ef9c6,
+        0x70dbdb904d9690db,
+        0xdd4b4b7a29627a4b,
+        0x8e7a7a8f8df78f7a,
+        0x4e3a3ad2f7cdd23a,
+        0x7fdede8160be81de,
+        0xe25e5e3b94ca3b5e,
+        0x7cdfdf8469b684df,
+        0xa29595fb49dcfb95,
+        0x19fcfc2b4fb32bfc,
+        0xe3aaaa38933938aa,
+        0x64d7d7ac21f6acd7,
+        0x4fceced1f03ed1ce,
+        0x0907071b3f381b07,
+        0x110f0f337778330f,
+        0x473d3dc9c8f5c93d,
+        0xe8585825a2fa2558,
+        0xb39a9ac83ea4c89a,
+        0xb59898c22cb4c298,
+        0xb99c9cd60894d69c,
+        0x0bf2f21d31c31df2,
+        0xf4a7a701f65101a7,
+        0x3311115599885511,
+        0x827e7e9ba9d79b7e,
+        0x808b8b9da72c9d8b,
+        0xc543435261225243,
+        0x0503030f1b180f03,
+        0x3be2e24da1434de2,
+        0x79dcdc8b72ae8bdc,
+        0x32e5e5569e7b56e5,
+        0xcbb2b2404bf940b2,
+        0xd24e4e6b044a6b4e,
+        0x54c7c7fcb176fcc7,
+        0xb76d6dc4224fc46d,
+        0x26e9e96af21b6ae9,
+        0x692727bb0225bb27,
+        0xc040405d7a3a5d40,
+        0x75d8d89f568e9fd8,
+        0x593737eb92a5eb37,
+        0xab9292e076e4e092,
+        0x8c8f8f89830c898f,
+        0x0301010509080501,
+        0x271d1d69f5e8691d,
+        0xf5535302f1a20253,
+        0x423e3ec6d3edc63e,
+        0xeb595920abf22059,
+        0x5ec1c1e28746e2c1,
+        0xd14f4f6e0d426e4f,
+        0x563232fabf8dfa32,
+        0x3a16164ea6b04e16,
+        0x13fafa35798335fa,
+        0x9c7474b9f387b974,
+        0x10fbfb30708b30fb,
+        0xa56363f25c3ff263,
+        0xbc9f9fd9138cd99f,
+        0x5c3434e489bde434,
+        0x2e1a1a72cad0721a,
+        0x7e2a2a82674d822a,
+        0xee5a5a2fb0ea2f5a,
+        0x8a8d8d83911c838d,
+        0x46c9c9cacf06cac9,
+        0x4ccfcfd4f936d4cf,
+        0x07f6f60915e309f6,
+        0xad9090ea64f4ea90,
+        0x78282888755d8828,
+        0x85888892bc349288,
+        0xb09b9bcd37accd9b,
+        0x533131f5a495f531,
+        0x120e0e367e70360e,
+        0xdabdbd733c8173bd,
+        0xde4a4a7f206a7f4a,
+        0x25e8e86ffb136fe8,
+        0xa79696f452c4f496,
+        0xf7a6a604ff5904a6,
+        0x140c0c3c6c603c0c,
+        0x45c8c8cfc60ecfc8,
+        0x8b79798096ef8079,
+        0xd9bcbc76358976bc,
+        0xdfbebe7c27997cbe,
+        0x2cefef74c42b74ef,
+        0xb26e6ecb3957cb6e,
+        0xca4646434c0a4346,
+        0xa49797f15bccf197,
+        0xed5b5b2ab9e22a5b,
+        0x2aeded7ed63b7eed,
+        0x2b19197dd1c87d19,
+        0x76d9d99a5f869ad9,
+        0xe9acac26a50926ac,
+        0xb69999c725bcc799,
+        0xe5a8a832812932a8,
+        0x7b29298d7c558d29,
+        0xac6464e96307e964,
+        0x211f1f63e7f8631f,
+        0xeaadad23ac0123ad,
+        0xff55551cc7921c55,
+        0x3513135f8b985f13,
+        0xd0bbbb6d0ab16dbb,
+        0x04f7f70c1ceb0cf7,
+        0xb16f6fce305fce6f,
+        0xd6b9b96718a167b9,
+        0xc947474645024647,
+        0x712f2f934a65932f,
+        0x2feeee71cd2371ee,
+        0xd5b8b86211a962b8,
+        0x8d7b7b8a84ff8a7b,
+        0x86898997b53c9789,
+        0x503030f0ad9df030,
+        0x68d3d3b805d6b8d3,
+        0x817f7f9ea0df9e7f,
+        0x9a7676b3e197b376,
+        0x9b8282b0e664b082,
+    ],
+    [
+        0x6868d50f67d568b8,
+        0xd0d0b71eceb7d06d,
+        0xebeb60e00b60eb20,
+        0x2b2b876e45872b7d,
+        0x484875327a7548d8,
+        0x9d9dd3019cd39dba,
+        0x6a6adf1d77df6abe,
+        0xe4e453977353e431,
+        0xe3e348a84b48e338,
+        0xa3a315d27115a3f8,
+        0x565613dc8a1356fa,
+        0x8181bffd7cbf819e,
+        0x7d7d94b2cf947d87,
+        0xf1f1122adb12f10e,
+        0x8585abd95cab8592,
+        0x9e9edc1a84dc9ebf,
+        0x2c2c9c517d9c2c74,
+        0x8e8e8c8a048c8e8f,
+        0x7878859fe7857888,
+        0xcacac5d41ec5ca43,
+        0x17174bafb84b1739,
+        0xa9a937882137a9e6,
+        0x6161f84e2ff861a3,
+        0xd5d5a633e6a6d562,
+        0x5d5d348fd2345de7,
+        0x0b0b275358270b1d,
+        0x8c8c869814868c89,
+        0x3c3cccc1fdcc3c44,
+        0x7777b6e89fb67799,
+        0x515108e3b20851f3,
+        0x2222aa2f0daa2266,
+        0x424257682a5742c6,
+        0x3f3fc3dae5c33f41,
+        0x545419ce9a1954fc,
+        0x41415873325841c3,
+        0x8080baf474ba809d,
+        0xccccdbe22edbcc49,
+        0x8686a4c244a48697,
+        0xb3b34542f145b3c8,
+        0x181878d8c0781828,
+        0x2e2e96436d962e72,
+        0x575716d5821657f9,
+        0x06061e36301e060a,
+        0x6262f75537f762a6,
+        0xf4f40307f303f401,
+        0x3636ee9badee365a,
+        0xd1d1b217c6b2d16e,
+        0x6b6bda147fda6bbd,
+        0x1b1b77c3d8771b2d,
+        0x6565ec6a0fec65af,
+        0x7575bcfa8fbc759f,
+        0x1010509080501030,
+        0xdada95449e95da73,
+        0x4949703b727049db,
+        0x2626be0b2dbe266a,
+        0xf9f93a629b3af916,
+        0xcbcbc0dd16c0cb40,
+        0x6666e37117e366aa,
+        0xe7e75c8c6b5ce734,
+        0xbaba6803b968bad3,
+        0xaeae2cb7192caeef,
+        0x50500deaba0d50f0,
+        0x525207f8aa0752f6,
+        0xabab3d9a313dabe0,
+        0x0505112d2811050f,
+        0xf0f01723d317f00d,
+        0x0d0d396568390d17,
+        0x7373a2ccbfa27395,
+        0x3b3bd7fec5d73b4d,
+        0x040414242014040c,
+        0x2020a03d1da02060,
+        0xfefe215da321fe1f,
+        0xdddd8e7ba68edd7a,
+        0xf5f5060efb06f502,
+        0xb4b45e7dc95eb4c1,
+        0x5f5f3e9dc23e5fe1,
+        0x0a0a225a50220a1e,
+        0xb5b55b74c15bb5c2,
+        0xc0c0e78e4ee7c05d,
+        0xa0a01ac9691aa0fd,
+        0x7171a8deafa87193,
+        0xa5a50be4410ba5f2,
+        0x2d2d995875992d77,
+        0x6060fd4727fd60a0,
+        0x7272a7c5b7a77296,
+        0x9393e57fece593a8,
+        0x3939ddecd5dd394b,
+        0x0808284840280818,
+        0x8383b5ef6cb58398,
+        0x2121a53415a52163,
+        0x5c5c3186da315ce4,
+        0x8787a1cb4ca18794,
+        0xb1b14f50e14fb1ce,
+        0xe0e047b35347e03d,
+        0x0000000000000000,
+        0xc3c3e89556e8c358,
+        0x12125a82905a1236,
+        0x9191ef6dfcef91ae,
+        0x8a8a98ae24988a83,
+        0x02020a12100a0206,
+        0x1c1c6cfce06c1c24,
+        0xe6e659856359e637,
+        0x45454c57124c45cf,
+        0xc2c2ed9c5eedc25b,
+        0xc4c4f3aa6ef3c451,
+        0xfdfd2e46bb2efd1a,
+        0xbfbf792e9179bfdc,
+        0x4444495e1a4944cc,
+        0xa1a11fc0611fa1fe,
+        0x4c4c61165a614cd4,
+        0x3333ffb685ff3355,
+        0xc5c5f6a366f6c552,
+        0x8484aed054ae8491,
+        0x2323af2605af2365,
+        0x7c7c91bbc7917c84,
+        0xb0b04a59e94ab0cd,
+        0x2525b11035b1256f,
+        0x151541bda841153f,
+        0x3535e180b5e1355f,
+        0x6969d0066fd069bb,
+        0xffff2454ab24ff1c,
+        0x9494fe40d4fe94a1,
+        0x4d4d641f52644dd7,
+        0x7070add7a7ad7090,
+        0xa2a210db7910a2fb,
+        0xafaf29be1129afec,
+        0xcdcddeeb26decd4a,
+        0xd6d6a928fea9d667,
+        0x6c6cc12b47c16cb4,
+        0xb7b75166d151b7c4,
+        0xf8f83f6b933ff815,
+        0x09092d41482d091b,
+        0xf3f31838cb18f308,
+        0x6767e6781fe667a9,
+        0xa4a40eed490ea4f1,
+        0xeaea65e90365ea23,
+        0xecec7bdf337bec29,
+        0xb6b6546fd954b6c7,
+        0xd4d4a33aeea3d461,
+        0xd2d2bd0cdebdd26b,
+        0x141444b4a044143c,
+        0x1e1e66eef0661e22,
+        0xe1e142ba5b42e13e,
+        0x2424b4193db4246c,
+        0x3838d8e5ddd83848,
+        0xc6c6f9b87ef9c657,
+        0xdbdb904d9690db70,
+        0x4b4b7a29627a4bdd,
+        0x7a7a8f8df78f7a8e,
+        0x3a3ad2f7cdd23a4e,
+        0xdede8160be81de7f,
+        0x5e5e3b94ca3b5ee2,
+        0xdfdf8469b684df7c,
+        0x9595fb49dcfb95a2,
+        0xfcfc2b4fb32bfc19,
+        0xaaaa38933938aae3,
+        0xd7d7ac21f6acd764,
+        0xceced1f03ed1ce4f,
+        0x07071b3f381b0709,
+        0x0f0f337778330f11,
+        0x3d3dc9c8f5c93d47,
+        0x585825a2fa2558e8,
+        0x9a9ac83ea4c89ab3,
+        0x9898c22cb4c298b5,
+        0x9c9cd60894d69cb9,
+        0xf2f21d31c31df20b,
+        0xa7a701f65101a7f4,
+        0x1111559988551133,
+        0x7e7e9ba9d79b7e82,
+        0x8b8b9da72c9d8b80,
+        0x43435261225243c5,
+        0x03030f1b180f0305,
+        0xe2e24da1434de23b,
+        0xdcdc8b72ae8bdc79,
+        0xe5e5569e7b56e532,
+        0xb2b2404bf940b2cb,
+        0x4e4e6b044a6b4ed2,
+        0xc7c7fcb176fcc754,
+        0x6d6dc4224fc46db7,
+        0xe9e96af21b6ae926,
+        0x2727bb0225bb2769,
+        0x40405d7a3a5d40c0,
+        0xd8d89f568e9fd875,
+        0x3737eb92a5eb3759,
+        0x9292e076e4e092ab,
+        0x8f8f89830c898f8c,
+        0x0101050908050103,
+        0x1d1d69f5e8691d27,
+        0x535302f1a20253f5,
+        0x3e3ec6d3edc63e42,
+        0x595920abf22059eb,
+        0xc1c1e28746e2c15e,
+        0x4f4f6e0d426e4fd1,
+        0x3232fabf8dfa3256,
+        0x16164ea6b04e163a,
+        0xfafa35798335fa13,
+        0x7474b9f387b9749c,
+        0xfbfb30708b30fb10,
+        0x6363f25c3ff263a5,
+        0x9f9fd9138cd99fbc,
+        0x3434e489bde4345c,
+        0x1a1a72cad0721a2e,
+        0x2a2a82674d822a7e,
+        0x5a5a2fb0ea2f5aee,
+        0x8d8d83911c838d8a,
+        0xc9c9cacf06cac946,
+        0xcfcfd4f936d4cf4c,
+        0xf6f60915e309f607,
+        0x9090ea64f4ea90ad,
+        0x282888755d882878,
+        0x888892bc34928885,
+        0x9b9bcd37accd9bb0,
+        0x3131f5a495f53153,
+        0x0e0e367e70360e12,
+        0xbdbd733c8173bdda,
+        0x4a4a7f206a7f4ade,
+        0xe8e86ffb136fe825,
+        0x9696f452c4f496a7,
+        0xa6a604ff5904a6f7,
+        0x0c0c3c6c603c0c14,
+        0xc8c8cfc60ecfc845,
+        0x79798096ef80798b,
+        0xbcbc76358976bcd9,
+        0xbebe7c27997cbedf,
+        0xefef74c42b74ef2c,
+        0x6e6ecb3957cb6eb2,
+        0x4646434c0a4346ca,
+        0x9797f15bccf197a4,
+        0x5b5b2ab9e22a5bed,
+        0xeded7ed63b7eed2a,
+        0x19197dd1c87d192b,
+        0xd9d99a5f869ad976,
+        0xacac26a50926ace9,
+        0x9999c725bcc799b6,
+        0xa8a832812932a8e5,
+        0x29298d7c558d297b,
+        0x6464e96307e964ac,
+        0x1f1f63e7f8631f21,
+        0xadad23ac0123adea,
+        0x55551cc7921c55ff,
+        0x13135f8b985f1335,
+        0xbbbb6d0ab16dbbd0,
+        0xf7f70c1ceb0cf704,
+        0x6f6fce305fce6fb1,
+        0xb9b96718a167b9d6,
+        0x47474645024647c9,
+        0x2f2f934a65932f71,
+        0xeeee71cd2371ee2f,
+        0xb8b86211a962b8d5,
+        0x7b7b8a84ff8a7b8d,
+        0x898997b53c978986,
+        0x3030f0ad9df03050,
+        0xd3d3b805d6b8d368,
+        0x7f7f9ea0df9e7f81,
+        0x7676b3e197b3769a,
+        0x8282b0e664b0829b,
+    ],
+    [
+        0x68d50f67d568b868,
+        0xd0b71eceb7d06dd0,
+        0xeb60e00b60eb20eb,
+        0x2b876e45872b7d2b,
+        0x4875327a7548d848,
+        0x9dd3019cd39dba9d,
+        0x6adf1d77df6abe6a,
+        0xe453977353e431e4,
+        0xe348a84b48e338e3,
+        0xa315d27115a3f8a3,
+        0x5613dc8a1356fa56,
+        0x81bffd7cbf819e81,
+        0x7d94b2cf947d877d,
+        0xf1122adb12f10ef1,
+        0x85abd95cab859285,
+        0x9edc1a84dc9ebf9e,
+        0x2c9c517d9c2c742c,
+        0x8e8c8a048c8e8f8e,
+        0x78859fe785788878,
+        0xcac5d41ec5ca43ca,
+        0x174bafb84b173917,
+        0xa937882137a9e6a9,
+        0x61f84e2ff861a361,
+        0xd5a633e6a6d562d5,
+        0x5d348fd2345de75d,
+        0x0b275358270b1d0b,
+        0x8c869814868c898c,
+        0x3cccc1fdcc3c443c,
+        0x77b6e89fb6779977,
+        0x5108e3b20851f351,
+        0x22aa2f0daa226622,
+        0x4257682a5742c642,
+        0x3fc3dae5c33f413f,
+        0x5419ce9a1954fc54,
+        0x415873325841c341,
+        0x80baf474ba809d80,
+        0xccdbe22edbcc49cc,
+        0x86a4c244a4869786,
+        0xb34542f145b3c8b3,
+        0x1878d8c078182818,
+        0x2e96436d962e722e,
+        0x5716d5821657f957,
+        0x061e36301e060a06,
+        0x62f75537f762a662,
+        0xf40307f303f401f4,
+        0x36ee9badee365a36,
+        0xd1b217c6b2d16ed1,
+        0x6bda147fda6bbd6b,
+        0x1b77c3d8771b2d1b,
+        0x65ec6a0fec65af65,
+        0x75bcfa8fbc759f75,
+        0x1050908050103010,
+        0xda95449e95da73da,
+        0x49703b727049db49,
+        0x26be0b2dbe266a26,
+        0xf93a629b3af916f9,
+        0xcbc0dd16c0cb40cb,
+        0x66e37117e366aa66,
+        0xe75c8c6b5ce734e7,
+        0xba6803b968bad3ba,
+        0xae2cb7192caeefae,
+        0x500deaba0d50f050,
+        0x5207f8aa0752f652,
+        0xab3d9a313dabe0ab,
+        0x05112d2811050f05,
+        0xf01723d317f00df0,
+        0x0d396568390d170d,
+        0x73a2ccbfa2739573,
+        0x3bd7fec5d73b4d3b,
+        0x0414242014040c04,
+        0x20a03d1da0206020,
+        0xfe215da321fe1ffe,
+        0xdd8e7ba68edd7add,
+        0xf5060efb06f502f5,
+        0xb45e7dc95eb4c1b4,
+        0x5f3e9dc23e5fe15f,
+        0x0a225a50220a1e0a,
+        0xb55b74c15bb5c2b5,
+        0xc0e78e4ee7c05dc0,
+        0xa01ac9691aa0fda0,
+        0x71a8deafa8719371,
+        0xa50be4410ba5f2a5,
+        0x2d995875992d772d,
+        0x60fd4727fd60a060,
+        0x72a7c5b7a7729672,
+        0x93e57fece593a893,
+        0x39ddecd5dd394b39,
+        0x0828484028081808,
+        0x83b5ef6cb5839883,
+        0x21a53415a5216321,
+        0x5c3186da315ce45c,
+        0x87a1cb4ca1879487,
+        0xb14f50e14fb1ceb1,
+        0xe047b35347e03de0,
+        0x0000000000000000,
+        0xc3e89556e8c358c3,
+        0x125a82905a123612,
+        0x91ef6dfcef91ae91,
+        0x8a98ae24988a838a,
+        0x020a12100a020602,
+        0x1c6cfce06c1c241c,
+        0xe659856359e637e6,
+        0x454c57124c45cf45,
+        0xc2ed9c5eedc25bc2,
+        0xc4f3aa6ef3c451c4,
+        0xfd2e46bb2efd1afd,
+        0xbf792e9179bfdcbf,
+        0x44495e1a4944cc44,
+        0xa11fc0611fa1fea1,
+        0x4c61165a614cd44c,
+        0x33ffb685ff335533,
+        0xc5f6a366f6c552c5,
+        0x84aed054ae849184,
+        0x23af2605af236523,
+        0x7c91bbc7917c847c,
+        0xb04a59e94ab0cdb0,
+        0x25b11035b1256f25,
+        0x1541bda841153f15,
+        0x35e180b5e1355f35,
+        0x69d0066fd069bb69,
+        0xff2454ab24ff1cff,
+        0x94fe40d4fe94a194,
+        0x4d641f52644dd74d,
+        0x70add7a7ad709070,
+        0xa210db7910a2fba2,
+        0xaf29be1129afecaf,
+        0xcddeeb26decd4acd,
+        0xd6a928fea9d667d6,
+        0x6cc12b47c16cb46c,
+        0xb75166d151b7c4b7,
+        0xf83f6b933ff815f8,
+        0x092d41482d091b09,
+        0xf31838cb18f308f3,
+        0x67e6781fe667a967,
+        0xa40eed490ea4f1a4,
+        0xea65e90365ea23ea,
+        0xec7bdf337bec29ec,
+        0xb6546fd954b6c7b6,
+        0xd4a33aeea3d461d4,
+        0xd2bd0cdebdd26bd2,
+        0x1444b4a044143c14,
+        0x1e66eef0661e221e,
+        0xe142ba5b42e13ee1,
+        0x24b4193db4246c24,
+        0x38d8e5ddd8384838,
+        0xc6f9b87ef9c657c6,
+        0xdb904d9690db70db,
+        0x4b7a29627a4bdd4b,
+        0x7a8f8df78f7a8e7a,
+        0x3ad2f7cdd23a4e3a,
+        0xde8160be81de7fde,
+        0x5e3b94ca3b5ee25e,
+        0xdf8469b684df7cdf,
+        0x95fb49dcfb95a295,
+        0xfc2b4fb32bfc19fc,
+        0xaa38933938aae3aa,
+        0xd7ac21f6acd764d7,
+        0xced1f03ed1ce4fce,
+        0x071b3f381b070907,
+        0x0f337778330f110f,
+        0x3dc9c8f5c93d473d,
+        0x5825a2fa2558e858,
+        0x9ac83ea4c89ab39a,
+        0x98c22cb4c298b598,
+        0x9cd60894d69cb99c,
+        0xf21d31c31df20bf2,
+        0xa701f65101a7f4a7,
+        0x1155998855113311,
+        0x7e9ba9d79b7e827e,
+        0x8b9da72c9d8b808b,
+        0x435261225243c543,
+        0x030f1b180f030503,
+        0xe24da1434de23be2,
+        0xdc8b72ae8bdc79dc,
+        0xe5569e7b56e532e5,
+        0xb2404bf940b2cbb2,
+        0x4e6b044a6b4ed24e,
+        0xc7fcb176fcc754c7,
+        0x6dc4224fc46db76d,
+        0xe96af21b6ae926e9,
+        0x27bb0225bb276927,
+        0x405d7a3a5d40c040,
+        0xd89f568e9fd875d8,
+        0x37eb92a5eb375937,
+        0x92e076e4e092ab92,
+        0x8f89830c898f8c8f,
+        0x0105090805010301,
+        0x1d69f5e8691d271d,
+        0x5302f1a20253f553,
+        0x3ec6d3edc63e423e,
+        0x5920abf22059eb59,
+        0xc1e28746e2c15ec1,
+        0x4f6e0d426e4fd14f,
+        0x32fabf8dfa325632,
+        0x164ea6b04e163a16,
+        0xfa35798335fa13fa,
+        0x74b9f387b9749c74,
+        0xfb30708b30fb10fb,
+        0x63f25c3ff263a563,
+        0x9fd9138cd99fbc9f,
+        0x34e489bde4345c34,
+        0x1a72cad0721a2e1a,
+        0x2a82674d822a7e2a,
+        0x5a2fb0ea2f5aee5a,
+        0x8d83911c838d8a8d,
+        0xc9cacf06cac946c9,
+        0xcfd4f936d4cf4ccf,
+        0xf60915e309f607f6,
+        0x90ea64f4ea90ad90,
+        0x2888755d88287828,
+        0x8892bc3492888588,
+        0x9bcd37accd9bb09b,
+        0x31f5a495f5315331,
+        0x0e367e70360e120e,
+        0xbd733c8173bddabd,
+        0x4a7f206a7f4ade4a,
+        0xe86ffb136fe825e8,
+        0x96f452c4f496a796,
+        0xa604ff5904a6f7a6,
+        0x0c3c6c603c0c140c,
+        0xc8cfc60ecfc845c8,
+        0x798096ef80798b79,
+        0xbc76358976bcd9bc,
+        0xbe7c27997cbedfbe,
+        0xef74c42b74ef2cef,
+        0x6ecb3957cb6eb26e,
+        0x46434c0a4346ca46,
+        0x97f15bccf197a497,
+        0x5b2ab9e22a5bed5b,
+        0xed7ed63b7eed2aed,
+        0x197dd1c87d192b19,
+        0xd99a5f869ad976d9,
+        0xac26a50926ace9ac,
+        0x99c725bcc799b699,
+        0xa832812932a8e5a8,
+        0x298d7c558d297b29,
+        0x64e96307e964ac64,
+        0x1f63e7f8631f211f,
+        0xad23ac0123adeaad,
+        0x551cc7921c55ff55,
+        0x135f8b985f133513,
+        0xbb6d0ab16dbbd0bb,
+        0xf70c1ceb0cf704f7,
+        0x6fce305fce6fb16f,
+        0xb96718a167b9d6b9,
+        0x474645024647c947,
+        0x2f934a65932f712f,
+        0xee71cd2371ee2fee,
+        0xb86211a962b8d5b8,
+        0x7b8a84ff8a7b8d7b,
+        0x8997b53c97898689,
+        0x30f0ad9df0305030,
+        0xd3b805d6b8d368d3,
+        0x7f9ea0df9e7f817f,
+        0x76b3e197b3769a76,
+        0x82b0e664b0829b82,
+    ],
+];
+
+#[cfg_attr(feature = "constant-time", allow(dead_code))]
+pub static TABLES_T: [[u64; 256]; 8] = [
+    [
+        0x78d8c07818281818,
+        0xaf2605af23652323,
+        0xf9b87ef9c657c6c6,
+        0x6ffb136fe825e8e8,
+        0xa1cb4ca187948787,
+        0x6211a962b8d5b8b8,
+        0x0509080501030101,
+        0x6e0d426e4fd14f4f,
+        0xee9badee365a3636,
+        0x04ff5904a6f7a6a6,
+        0xbd0cdebdd26bd2d2,
+        0x060efb06f502f5f5,
+        0x8096ef80798b7979,
+        0xce305fce6fb16f6f,
+        0xef6dfcef91ae9191,
+        0x07f8aa0752f65252,
+        0xfd4727fd60a06060,
+        0x76358976bcd9bcbc,
+        0xcd37accd9bb09b9b,
+        0x8c8a048c8e8f8e8e,
+        0x15d27115a3f8a3a3,
+        0x3c6c603c0c140c0c,
+        0x8a84ff8a7b8d7b7b,
+        0xe180b5e1355f3535,
+        0x69f5e8691d271d1d,
+        0x47b35347e03de0e0,
+        0xac21f6acd764d7d7,
+        0xed9c5eedc25bc2c2,
+        0x96436d962e722e2e,
+        0x7a29627a4bdd4b4b,
+        0x215da321fe1ffefe,
+        0x16d5821657f95757,
+        0x41bda841153f1515,
+        0xb6e89fb677997777,
+        0xeb92a5eb37593737,
+        0x569e7b56e532e5e5,
+        0xd9138cd99fbc9f9f,
+        0x1723d317f00df0f0,
+        0x7f206a7f4ade4a4a,
+        0x95449e95da73dada,
+        0x25a2fa2558e85858,
+        0xcacf06cac946c9c9,
+        0x8d7c558d297b2929,
+        0x225a50220a1e0a0a,
+        0x4f50e14fb1ceb1b1,
+        0x1ac9691aa0fda0a0,
+        0xda147fda6bbd6b6b,
+        0xabd95cab85928585,
+        0x733c8173bddabdbd,
+        0x348fd2345de75d5d,
+        0x5090805010301010,
+        0x0307f303f401f4f4,
+        0xc0dd16c0cb40cbcb,
+        0xc6d3edc63e423e3e,
+        0x112d2811050f0505,
+        0xe6781fe667a96767,
+        0x53977353e431e4e4,
+        0xbb0225bb27692727,
+        0x5873325841c34141,
+        0x9da72c9d8b808b8b,
+        0x01f65101a7f4a7a7,
+        0x94b2cf947d877d7d,
+        0xfb49dcfb95a29595,
+        0x9f568e9fd875d8d8,
+        0x30708b30fb10fbfb,
+        0x71cd2371ee2feeee,
+        0x91bbc7917c847c7c,
+        0xe37117e366aa6666,
+        0x8e7ba68edd7adddd,
+        0x4bafb84b17391717,
+        0x4645024647c94747,
+        0xdc1a84dc9ebf9e9e,
+        0xc5d41ec5ca43caca,
+        0x995875992d772d2d,
+        0x792e9179bfdcbfbf,
+        0x1b3f381b07090707,
+        0x23ac0123adeaadad,
+        0x2fb0ea2f5aee5a5a,
+        0xb5ef6cb583988383,
+        0xffb685ff33553333,
+        0xf25c3ff263a56363,
+        0x0a12100a02060202,
+        0x38933938aae3aaaa,
+        0xa8deafa871937171,
+        0xcfc60ecfc845c8c8,
+        0x7dd1c87d192b1919,
+        0x703b727049db4949,
+        0x9a5f869ad976d9d9,
+        0x1d31c31df20bf2f2,
+        0x48a84b48e338e3e3,
+        0x2ab9e22a5bed5b5b,
+        0x92bc349288858888,
+        0xc83ea4c89ab39a9a,
+        0xbe0b2dbe266a2626,
+        0xfabf8dfa32563232,
+        0x4a59e94ab0cdb0b0,
+        0x6af21b6ae926e9e9,
+        0x337778330f110f0f,
+        0xa633e6a6d562d5d5,
+        0xbaf474ba809d8080,
+        0x7c27997cbedfbebe,
+        0xdeeb26decd4acdcd,
+        0xe489bde4345c3434,
+        0x75327a7548d84848,
+        0x2454ab24ff1cffff,
+        0x8f8df78f7a8e7a7a,
+        0xea64f4ea90ad9090,
+        0x3e9dc23e5fe15f5f,
+        0xa03d1da020602020,
+        0xd50f67d568b86868,
+        0x72cad0721a2e1a1a,
+        0x2cb7192caeefaeae,
+        0x5e7dc95eb4c1b4b4,
+        0x19ce9a1954fc5454,
+        0xe57fece593a89393,
+        0xaa2f0daa22662222,
+        0xe96307e964ac6464,
+        0x122adb12f10ef1f1,
+        0xa2ccbfa273957373,
+        0x5a82905a12361212,
+        0x5d7a3a5d40c04040,
+        0x2848402808180808,
+        0xe89556e8c358c3c3,
+        0x7bdf337bec29ecec,
+        0x904d9690db70dbdb,
+        0x1fc0611fa1fea1a1,
+        0x83911c838d8a8d8d,
+        0xc9c8f5c93d473d3d,
+        0xf15bccf197a49797,
+        0x0000000000000000,
+        0xd4f936d4cf4ccfcf,
+        0x876e45872b7d2b2b,
+        0xb3e197b3769a7676,
+        0xb0e664b0829b8282,
+        0xa928fea9d667d6d6,
+        0x77c3d8771b2d1b1b,
+        0x5b74c15bb5c2b5b5,
+        0x29be1129afecafaf,
+        0xdf1d77df6abe6a6a,
+        0x0deaba0d50f05050,
+        0x4c57124c45cf4545,
+        0x1838cb18f308f3f3,
+        0xf0ad9df030503030,
+        0x74c42b74ef2cefef,
+        0xc3dae5c33f413f3f,
+        0x1cc7921c55ff5555,
+        0x10db7910a2fba2a2,
+        0x65e90365ea23eaea,
+        0xec6a0fec65af6565,
+        0x6803b968bad3baba,
+        0x934a65932f712f2f,
+        0xe78e4ee7c05dc0c0,
+        0x8160be81de7fdede,
+        0x6cfce06c1c241c1c,
+        0x2e46bb2efd1afdfd,
+        0x641f52644dd74d4d,
+        0xe076e4e092ab9292,
+        0xbcfa8fbc759f7575,
+        0x1e36301e060a0606,
+        0x98ae24988a838a8a,
+        0x404bf940b2cbb2b2,
+        0x59856359e637e6e6,
+        0x367e70360e120e0e,
+        0x63e7f8631f211f1f,
+        0xf75537f762a66262,
+        0xa33aeea3d461d4d4,
+        0x32812932a8e5a8a8,
+        0xf452c4f496a79696,
+        0x3a629b3af916f9f9,
+        0xf6a366f6c552c5c5,
+        0xb11035b1256f2525,
+        0x20abf22059eb5959,
+        0xaed054ae84918484,
+        0xa7c5b7a772967272,
+        0xddecd5dd394b3939,
+        0x61165a614cd44c4c,
+        0x3b94ca3b5ee25e5e,
+        0x859fe78578887878,
+        0xd8e5ddd838483838,
+        0x869814868c898c8c,
+        0xb217c6b2d16ed1d1,
+        0x0be4410ba5f2a5a5,
+        0x4da1434de23be2e2,
+        0xf84e2ff861a36161,
+        0x4542f145b3c8b3b3,
+        0xa53415a521632121,
+        0xd60894d69cb99c9c,
+        0x66eef0661e221e1e,
+        0x5261225243c54343,
+        0xfcb176fcc754c7c7,
+        0x2b4fb32bfc19fcfc,
+        0x14242014040c0404,
+        0x08e3b20851f35151,
+        0xc725bcc799b69999,
+        0xc4224fc46db76d6d,
+        0x396568390d170d0d,
+        0x35798335fa13fafa,
+        0x8469b684df7cdfdf,
+        0x9ba9d79b7e827e7e,
+        0xb4193db4246c2424,
+        0xd7fec5d73b4d3b3b,
+        0x3d9a313dabe0abab,
+        0xd1f03ed1ce4fcece,
+        0x5599885511331111,
+        0x89830c898f8c8f8f,
+        0x6b044a6b4ed24e4e,
+        0x5166d151b7c4b7b7,
+        0x60e00b60eb20ebeb,
+        0xccc1fdcc3c443c3c,
+        0xbffd7cbf819e8181,
+        0xfe40d4fe94a19494,
+        0x0c1ceb0cf704f7f7,
+        0x6718a167b9d6b9b9,
+        0x5f8b985f13351313,
+        0x9c517d9c2c742c2c,
+        0xb805d6b8d368d3d3,
+        0x5c8c6b5ce734e7e7,
+        0xcb3957cb6eb26e6e,
+        0xf3aa6ef3c451c4c4,
+        0x0f1b180f03050303,
+        0x13dc8a1356fa5656,
+        0x495e1a4944cc4444,
+        0x9ea0df9e7f817f7f,
+        0x37882137a9e6a9a9,
+        0x82674d822a7e2a2a,
+        0x6d0ab16dbbd0bbbb,
+        0xe28746e2c15ec1c1,
+        0x02f1a20253f55353,
+        0x8b72ae8bdc79dcdc,
+        0x275358270b1d0b0b,
+        0xd3019cd39dba9d9d,
+        0xc12b47c16cb46c6c,
+        0xf5a495f531533131,
+        0xb9f387b9749c7474,
+        0x0915e309f607f6f6,
+        0x434c0a4346ca4646,
+        0x26a50926ace9acac,
+        0x97b53c9789868989,
+        0x44b4a044143c1414,
+        0x42ba5b42e13ee1e1,
+        0x4ea6b04e163a1616,
+        0xd2f7cdd23a4e3a3a,
+        0xd0066fd069bb6969,
+        0x2d41482d091b0909,
+        0xadd7a7ad70907070,
+        0x546fd954b6c7b6b6,
+        0xb71eceb7d06dd0d0,
+        0x7ed63b7eed2aeded,
+        0xdbe22edbcc49cccc,
+        0x57682a5742c64242,
+        0xc22cb4c298b59898,
+        0x0eed490ea4f1a4a4,
+        0x88755d8828782828,
+        0x3186da315ce45c5c,
+        0x3f6b933ff815f8f8,
+        0xa4c244a486978686,
+    ],
+    [
+        0xd8c0781828181878,
+        0x2605af23652323af,
+        0xb87ef9c657c6c6f9,
+        0xfb136fe825e8e86f,
+        0xcb4ca187948787a1,
+        0x11a962b8d5b8b862,
+        0x0908050103010105,
+        0x0d426e4fd14f4f6e,
+        0x9badee365a3636ee,
+        0xff5904a6f7a6a604,
+        0x0cdebdd26bd2d2bd,
+        0x0efb06f502f5f506,
+        0x96ef80798b797980,
+        0x305fce6fb16f6fce,
+        0x6dfcef91ae9191ef,
+        0xf8aa0752f6525207,
+        0x4727fd60a06060fd,
+        0x358976bcd9bcbc76,
+        0x37accd9bb09b9bcd,
+        0x8a048c8e8f8e8e8c,
+        0xd27115a3f8a3a315,
+        0x6c603c0c140c0c3c,
+        0x84ff8a7b8d7b7b8a,
+        0x80b5e1355f3535e1,
+        0xf5e8691d271d1d69,
+        0xb35347e03de0e047,
+        0x21f6acd764d7d7ac,
+        0x9c5eedc25bc2c2ed,
+        0x436d962e722e2e96,
+        0x29627a4bdd4b4b7a,
+        0x5da321fe1ffefe21,
+        0xd5821657f9575716,
+        0xbda841153f151541,
+        0xe89fb677997777b6,
+        0x92a5eb37593737eb,
+        0x9e7b56e532e5e556,
+        0x138cd99fbc9f9fd9,
+        0x23d317f00df0f017,
+        0x206a7f4ade4a4a7f,
+        0x449e95da73dada95,
+        0xa2fa2558e8585825,
+        0xcf06cac946c9c9ca,
+        0x7c558d297b29298d,
+        0x5a50220a1e0a0a22,
+        0x50e14fb1ceb1b14f,
+        0xc9691aa0fda0a01a,
+        0x147fda6bbd6b6bda,
+        0xd95cab85928585ab,
+        0x3c8173bddabdbd73,
+        0x8fd2345de75d5d34,
+        0x9080501030101050,
+        0x07f303f401f4f403,
+        0xdd16c0cb40cbcbc0,
+        0xd3edc63e423e3ec6,
+        0x2d2811050f050511,
+        0x781fe667a96767e6,
+        0x977353e431e4e453,
+        0x0225bb27692727bb,
+        0x73325841c3414158,
+        0xa72c9d8b808b8b9d,
+        0xf65101a7f4a7a701,
+        0xb2cf947d877d7d94,
+        0x49dcfb95a29595fb,
+        0x568e9fd875d8d89f,
+        0x708b30fb10fbfb30,
+        0xcd2371ee2feeee71,
+        0xbbc7917c847c7c91,
+        0x7117e366aa6666e3,
+        0x7ba68edd7adddd8e,
+        0xafb84b173917174b,
+        0x45024647c9474746,
+        0x1a84dc9ebf9e9edc,
+        0xd41ec5ca43cacac5,
+        0x5875992d772d2d99,
+        0x2e9179bfdcbfbf79,
+        0x3f381b070907071b,
+        0xac0123adeaadad23,
+        0xb0ea2f5aee5a5a2f,
+        0xef6cb583988383b5,
+        0xb685ff33553333ff,
+        0x5c3ff263a56363f2,
+        0x12100a020602020a,
+        0x933938aae3aaaa38,
+        0xdeafa871937171a8,
+        0xc60ecfc845c8c8cf,
+        0xd1c87d192b19197d,
+        0x3b727049db494970,
+        0x5f869ad976d9d99a,
+        0x31c31df20bf2f21d,
+        0xa84b48e338e3e348,
+        0xb9e22a5bed5b5b2a,
+        0xbc34928885888892,
+        0x3ea4c89ab39a9ac8,
+        0x0b2dbe266a2626be,
+        0xbf8dfa32563232fa,
+        0x59e94ab0cdb0b04a,
+        0xf21b6ae926e9e96a,
+        0x7778330f110f0f33,
+        0x33e6a6d562d5d5a6,
+        0xf474ba809d8080ba,
+        0x27997cbedfbebe7c,
+        0xeb26decd4acdcdde,
+        0x89bde4345c3434e4,
+        0x327a7548d8484875,
+        0x54ab24ff1cffff24,
+        0x8df78f7a8e7a7a8f,
+        0x64f4ea90ad9090ea,
+        0x9dc23e5fe15f5f3e,
+        0x3d1da020602020a0,
+        0x0f67d568b86868d5,
+        0xcad0721a2e1a1a72,
+        0xb7192caeefaeae2c,
+        0x7dc95eb4c1b4b45e,
+        0xce9a1954fc545419,
+        0x7fece593a89393e5,
+        0x2f0daa22662222aa,
+        0x6307e964ac6464e9,
+        0x2adb12f10ef1f112,
+        0xccbfa273957373a2,
+        0x82905a123612125a,
+        0x7a3a5d40c040405d,
+        0x4840280818080828,
+        0x9556e8c358c3c3e8,
+        0xdf337bec29ecec7b,
+        0x4d9690db70dbdb90,
+        0xc0611fa1fea1a11f,
+        0x911c838d8a8d8d83,
+        0xc8f5c93d473d3dc9,
+        0x5bccf197a49797f1,
+        0x0000000000000000,
+        0xf936d4cf4ccfcfd4,
+        0x6e45872b7d2b2b87,
+        0xe197b3769a7676b3,
+        0xe664b0829b8282b0,
+        0x28fea9d667d6d6a9,
+        0xc3d8771b2d1b1b77,
+        0x74c15bb5c2b5b55b,
+        0xbe1129afecafaf29,
+        0x1d77df6abe6a6adf,
+        0xeaba0d50f050500d,
+        0x57124c45cf45454c,
+        0x38cb18f308f3f318,
+        0xad9df030503030f0,
+        0xc42b74ef2cefef74,
+        0xdae5c33f413f3fc3,
+        0xc7921c55ff55551c,
+        0xdb7910a2fba2a210,
+        0xe90365ea23eaea65,
+        0x6a0fec65af6565ec,
+        0x03b968bad3baba68,
+        0x4a65932f712f2f93,
+        0x8e4ee7c05dc0c0e7,
+        0x60be81de7fdede81,
+        0xfce06c1c241c1c6c,
+        0x46bb2efd1afdfd2e,
+        0x1f52644dd74d4d64,
+        0x76e4e092ab9292e0,
+        0xfa8fbc759f7575bc,
+        0x36301e060a06061e,
+        0xae24988a838a8a98,
+        0x4bf940b2cbb2b240,
+        0x856359e637e6e659,
+        0x7e70360e120e0e36,
+        0xe7f8631f211f1f63,
+        0x5537f762a66262f7,
+        0x3aeea3d461d4d4a3,
+        0x812932a8e5a8a832,
+        0x52c4f496a79696f4,
+        0x629b3af916f9f93a,
+        0xa366f6c552c5c5f6,
+        0x1035b1256f2525b1,
+        0xabf22059eb595920,
+        0xd054ae84918484ae,
+        0xc5b7a772967272a7,
+        0xecd5dd394b3939dd,
+        0x165a614cd44c4c61,
+        0x94ca3b5ee25e5e3b,
+        0x9fe7857888787885,
+        0xe5ddd838483838d8,
+        0x9814868c898c8c86,
+        0x17c6b2d16ed1d1b2,
+        0xe4410ba5f2a5a50b,
+        0xa1434de23be2e24d,
+        0x4e2ff861a36161f8,
+        0x42f145b3c8b3b345,
+        0x3415a521632121a5,
+        0x0894d69cb99c9cd6,
+        0xeef0661e221e1e66,
+        0x61225243c5434352,
+        0xb176fcc754c7c7fc,
+        0x4fb32bfc19fcfc2b,
+        0x242014040c040414,
+        0xe3b20851f3515108,
+        0x25bcc799b69999c7,
+        0x224fc46db76d6dc4,
+        0x6568390d170d0d39,
+        0x798335fa13fafa35,
+        0x69b684df7cdfdf84,
+        0xa9d79b7e827e7e9b,
+        0x193db4246c2424b4,
+        0xfec5d73b4d3b3bd7,
+        0x9a313dabe0abab3d,
+        0xf03ed1ce4fceced1,
+        0x9988551133111155,
+        0x830c898f8c8f8f89,
+        0x044a6b4ed24e4e6b,
+        0x66d151b7c4b7b751,
+        0xe00b60eb20ebeb60,
+        0xc1fdcc3c443c3ccc,
+        0xfd7cbf819e8181bf,
+        0x40d4fe94a19494fe,
+        0x1ceb0cf704f7f70c,
+        0x18a167b9d6b9b967,
+        0x8b985f133513135f,
+        0x517d9c2c742c2c9c,
+        0x05d6b8d368d3d3b8,
+        0x8c6b5ce734e7e75c,
+        0x3957cb6eb26e6ecb,
+        0xaa6ef3c451c4c4f3,
+        0x1b180f030503030f,
+        0xdc8a1356fa565613,
+        0x5e1a4944cc444449,
+        0xa0df9e7f817f7f9e,
+        0x882137a9e6a9a937,
+        0x674d822a7e2a2a82,
+        0x0ab16dbbd0bbbb6d,
+        0x8746e2c15ec1c1e2,
+        0xf1a20253f5535302,
+        0x72ae8bdc79dcdc8b,
+        0x5358270b1d0b0b27,
+        0x019cd39dba9d9dd3,
+        0x2b47c16cb46c6cc1,
+        0xa495f531533131f5,
+        0xf387b9749c7474b9,
+        0x15e309f607f6f609,
+        0x4c0a4346ca464643,
+        0xa50926ace9acac26,
+        0xb53c978986898997,
+        0xb4a044143c141444,
+        0xba5b42e13ee1e142,
+        0xa6b04e163a16164e,
+        0xf7cdd23a4e3a3ad2,
+        0x066fd069bb6969d0,
+        0x41482d091b09092d,
+        0xd7a7ad70907070ad,
+        0x6fd954b6c7b6b654,
+        0x1eceb7d06dd0d0b7,
+        0xd63b7eed2aeded7e,
+        0xe22edbcc49ccccdb,
+        0x682a5742c6424257,
+        0x2cb4c298b59898c2,
+        0xed490ea4f1a4a40e,
+        0x755d882878282888,
+        0x86da315ce45c5c31,
+        0x6b933ff815f8f83f,
+        0xc244a486978686a4,
+    ],
+    [
+        0xc0781828181878d8,
+        0x05af23652323af26,
+        0x7ef9c657c6c6f9b8,
+        0x136fe825e8e86ffb,
+        0x4ca187948787a1cb,
+        0xa962b8d5b8b86211,
+        0x0805010301010509,
+        0x426e4fd14f4f6e0d,
+        0xadee365a3636ee9b,
+        0x5904a6f7a6a604ff,
+        0xdebdd26bd2d2bd0c,
+        0xfb06f502f5f5060e,
+        0xef80798b79798096,
+        0x5fce6fb16f6fce30,
+        0xfcef91ae9191ef6d,
+        0xaa0752f6525207f8,
+        0x27fd60a06060fd47,
+        0x8976bcd9bcbc7635,
+        0xaccd9bb09b9bcd37,
+        0x048c8e8f8e8e8c8a,
+        0x7115a3f8a3a315d2,
+        0x603c0c140c0c3c6c,
+        0xff8a7b8d7b7b8a84,
+        0xb5e1355f3535e180,
+        0xe8691d271d1d69f5,
+        0x5347e03de0e047b3,
+        0xf6acd764d7d7ac21,
+        0x5eedc25bc2c2ed9c,
+        0x6d962e722e2e9643,
+        0x627a4bdd4b4b7a29,
+        0xa321fe1ffefe215d,
+        0x821657f9575716d5,
+        0xa841153f151541bd,
+        0x9fb677997777b6e8,
+        0xa5eb37593737eb92,
+        0x7b56e532e5e5569e,
+        0x8cd99fbc9f9fd913,
+        0xd317f00df0f01723,
+        0x6a7f4ade4a4a7f20,
+        0x9e95da73dada9544,
+        0xfa2558e8585825a2,
+        0x06cac946c9c9cacf,
+        0x558d297b29298d7c,
+        0x50220a1e0a0a225a,
+        0xe14fb1ceb1b14f50,
+        0x691aa0fda0a01ac9,
+        0x7fda6bbd6b6bda14,
+        0x5cab85928585abd9,
+        0x8173bddabdbd733c,
+        0xd2345de75d5d348f,
+        0x8050103010105090,
+        0xf303f401f4f40307,
+        0x16c0cb40cbcbc0dd,
+        0xedc63e423e3ec6d3,
+        0x2811050f0505112d,
+        0x1fe667a96767e678,
+        0x7353e431e4e45397,
+        0x25bb27692727bb02,
+        0x325841c341415873,
+        0x2c9d8b808b8b9da7,
+        0x5101a7f4a7a701f6,
+        0xcf947d877d7d94b2,
+        0xdcfb95a29595fb49,
+        0x8e9fd875d8d89f56,
+        0x8b30fb10fbfb3070,
+        0x2371ee2feeee71cd,
+        0xc7917c847c7c91bb,
+        0x17e366aa6666e371,
+        0xa68edd7adddd8e7b,
+        0xb84b173917174baf,
+        0x024647c947474645,
+        0x84dc9ebf9e9edc1a,
+        0x1ec5ca43cacac5d4,
+        0x75992d772d2d9958,
+        0x9179bfdcbfbf792e,
+        0x381b070907071b3f,
+        0x0123adeaadad23ac,
+        0xea2f5aee5a5a2fb0,
+        0x6cb583988383b5ef,
+        0x85ff33553333ffb6,
+        0x3ff263a56363f25c,
+        0x100a020602020a12,
+        0x3938aae3aaaa3893,
+        0xafa871937171a8de,
+        0x0ecfc845c8c8cfc6,
+        0xc87d192b19197dd1,
+        0x727049db4949703b,
+        0x869ad976d9d99a5f,
+        0xc31df20bf2f21d31,
+        0x4b48e338e3e348a8,
+        0xe22a5bed5b5b2ab9,
+        0x34928885888892bc,
+        0xa4c89ab39a9ac83e,
+        0x2dbe266a2626be0b,
+        0x8dfa32563232fabf,
+        0xe94ab0cdb0b04a59,
+        0x1b6ae926e9e96af2,
+        0x78330f110f0f3377,
+        0xe6a6d562d5d5a633,
+        0x74ba809d8080baf4,
+        0x997cbedfbebe7c27,
+        0x26decd4acdcddeeb,
+        0xbde4345c3434e489,
+        0x7a7548d848487532,
+        0xab24ff1cffff2454,
+        0xf78f7a8e7a7a8f8d,
+        0xf4ea90ad9090ea64,
+        0xc23e5fe15f5f3e9d,
+        0x1da020602020a03d,
+        0x67d568b86868d50f,
+        0xd0721a2e1a1a72ca,
+        0x192caeefaeae2cb7,
+        0xc95eb4c1b4b45e7d,
+        0x9a1954fc545419ce,
+        0xece593a89393e57f,
+        0x0daa22662222aa2f,
+        0x07e964ac6464e963,
+        0xdb12f10ef1f1122a,
+        0xbfa273957373a2cc,
+        0x905a123612125a82,
+        0x3a5d40c040405d7a,
+        0x4028081808082848,
+        0x56e8c358c3c3e895,
+        0x337bec29ecec7bdf,
+        0x9690db70dbdb904d,
+        0x611fa1fea1a11fc0,
+        0x1c838d8a8d8d8391,
+        0xf5c93d473d3dc9c8,
+        0xccf197a49797f15b,
+        0x0000000000000000,
+        0x36d4cf4ccfcfd4f9,
+        0x45872b7d2b2b876e,
+        0x97b3769a7676b3e1,
+        0x64b0829b8282b0e6,
+        0xfea9d667d6d6a928,
+        0xd8771b2d1b1b77c3,
+        0xc15bb5c2b5b55b74,
+        0x1129afecafaf29be,
+        0x77df6abe6a6adf1d,
+        0xba0d50f050500dea,
+        0x124c45cf45454c57,
+        0xcb18f308f3f31838,
+        0x9df030503030f0ad,
+        0x2b74ef2cefef74c4,
+        0xe5c33f413f3fc3da,
+        0x921c55ff55551cc7,
+        0x7910a2fba2a210db,
+        0x0365ea23eaea65e9,
+        0x0fec65af6565ec6a,
+        0xb968bad3baba6803,
+        0x65932f712f2f934a,
+        0x4ee7c05dc0c0e78e,
+        0xbe81de7fdede8160,
+        0xe06c1c241c1c6cfc,
+        0xbb2efd1afdfd2e46,
+        0x52644dd74d4d641f,
+        0xe4e092ab9292e076,
+        0x8fbc759f7575bcfa,
+        0x301e060a06061e36,
+        0x24988a838a8a98ae,
+        0xf940b2cbb2b2404b,
+        0x6359e637e6e65985,
+        0x70360e120e0e367e,
+        0xf8631f211f1f63e7,
+        0x37f762a66262f755,
+        0xeea3d461d4d4a33a,
+        0x2932a8e5a8a83281,
+        0xc4f496a79696f452,
+        0x9b3af916f9f93a62,
+        0x66f6c552c5c5f6a3,
+        0x35b1256f2525b110,
+        0xf22059eb595920ab,
+        0x54ae84918484aed0,
+        0xb7a772967272a7c5,
+        0xd5dd394b3939ddec,
+        0x5a614cd44c4c6116,
+        0xca3b5ee25e5e3b94,
+        0xe78578887878859f,
+        0xddd838483838d8e5,
+        0x14868c898c8c8698,
+        0xc6b2d16ed1d1b217,
+        0x410ba5f2a5a50be4,
+        0x434de23be2e24da1,
+        0x2ff861a36161f84e,
+        0xf145b3c8b3b34542,
+        0x15a521632121a534,
+        0x94d69cb99c9cd608,
+        0xf0661e221e1e66ee,
+        0x225243c543435261,
+        0x76fcc754c7c7fcb1,
+        0xb32bfc19fcfc2b4f,
+        0x2014040c04041424,
+        0xb20851f3515108e3,
+        0xbcc799b69999c725,
+        0x4fc46db76d6dc422,
+        0x68390d170d0d3965,
+        0x8335fa13fafa3579,
+        0xb684df7cdfdf8469,
+        0xd79b7e827e7e9ba9,
+        0x3db4246c2424b419,
+        0xc5d73b4d3b3bd7fe,
+        0x313dabe0abab3d9a,
+        0x3ed1ce4fceced1f0,
+        0x8855113311115599,
+        0x0c898f8c8f8f8983,
+        0x4a6b4ed24e4e6b04,
+        0xd151b7c4b7b75166,
+        0x0b60eb20ebeb60e0,
+        0xfdcc3c443c3cccc1,
+        0x7cbf819e8181bffd,
+        0xd4fe94a19494fe40,
+        0xeb0cf704f7f70c1c,
+        0xa167b9d6b9b96718,
+        0x985f133513135f8b,
+        0x7d9c2c742c2c9c51,
+        0xd6b8d368d3d3b805,
+        0x6b5ce734e7e75c8c,
+        0x57cb6eb26e6ecb39,
+        0x6ef3c451c4c4f3aa,
+        0x180f030503030f1b,
+        0x8a1356fa565613dc,
+        0x1a4944cc4444495e,
+        0xdf9e7f817f7f9ea0,
+        0x2137a9e6a9a93788,
+        0x4d822a7e2a2a8267,
+        0xb16dbbd0bbbb6d0a,
+        0x46e2c15ec1c1e287,
+        0xa20253f5535302f1,
+        0xae8bdc79dcdc8b72,
+        0x58270b1d0b0b2753,
+        0x9cd39dba9d9dd301,
+        0x47c16cb46c6cc12b,
+        0x95f531533131f5a4,
+        0x87b9749c7474b9f3,
+        0xe309f607f6f60915,
+        0x0a4346ca4646434c,
+        0x0926ace9acac26a5,
+        0x3c978986898997b5,
+        0xa044143c141444b4,
+        0x5b42e13ee1e142ba,
+        0xb04e163a16164ea6,
+        0xcdd23a4e3a3ad2f7,
+        0x6fd069bb6969d006,
+        0x482d091b09092d41,
+        0xa7ad70907070add7,
+        0xd954b6c7b6b6546f,
+        0xceb7d06dd0d0b71e,
+        0x3b7eed2aeded7ed6,
+        0x2edbcc49ccccdbe2,
+        0x2a5742c642425768,
+        0xb4c298b59898c22c,
+        0x490ea4f1a4a40eed,
+        0x5d88287828288875,
+        0xda315ce45c5c3186,
+        0x933ff815f8f83f6b,
+        0x44a486978686a4c2,
+    ],
+    [
+        0x781828181878d8c0,
+        0xaf23652323af2605,
+        0xf9c657c6c6f9b87e,
+        0x6fe825e8e86ffb13,
+        0xa187948787a1cb4c,
+        0x62b8d5b8b86211a9,
+        0x0501030101050908,
+        0x6e4fd14f4f6e0d42,
+        0xee365a3636ee9bad,
+        0x04a6f7a6a604ff59,
+        0xbdd26bd2d2bd0cde,
+        0x06f502f5f5060efb,
+        0x80798b79798096ef,
+        0xce6fb16f6fce305f,
+        0xef91ae9191ef6dfc,
+        0x0752f6525207f8aa,
+        0xfd60a06060fd4727,
+        0x76bcd9bcbc763589,
+        0xcd9bb09b9bcd37ac,
+        0x8c8e8f8e8e8c8a04,
+        0x15a3f8a3a315d271,
+        0x3c0c140c0c3c6c60,
+        0x8a7b8d7b7b8a84ff,
+        0xe1355f3535e180b5,
+        0x691d271d1d69f5e8,
+        0x47e03de0e047b353,
+        0xacd764d7d7ac21f6,
+        0xedc25bc2c2ed9c5e,
+        0x962e722e2e96436d,
+        0x7a4bdd4b4b7a2962,
+        0x21fe1ffefe215da3,
+        0x1657f9575716d582,
+        0x41153f151541bda8,
+        0xb677997777b6e89f,
+        0xeb37593737eb92a5,
+        0x56e532e5e5569e7b,
+        0xd99fbc9f9fd9138c,
+        0x17f00df0f01723d3,
+        0x7f4ade4a4a7f206a,
+        0x95da73dada95449e,
+        0x2558e8585825a2fa,
+        0xcac946c9c9cacf06,
+        0x8d297b29298d7c55,
+        0x220a1e0a0a225a50,
+        0x4fb1ceb1b14f50e1,
+        0x1aa0fda0a01ac969,
+        0xda6bbd6b6bda147f,
+        0xab85928585abd95c,
+        0x73bddabdbd733c81,
+        0x345de75d5d348fd2,
+        0x5010301010509080,
+        0x03f401f4f40307f3,
+        0xc0cb40cbcbc0dd16,
+        0xc63e423e3ec6d3ed,
+        0x11050f0505112d28,
+        0xe667a96767e6781f,
+        0x53e431e4e4539773,
+        0xbb27692727bb0225,
+        0x5841c34141587332,
+        0x9d8b808b8b9da72c,
+        0x01a7f4a7a701f651,
+        0x947d877d7d94b2cf,
+        0xfb95a29595fb49dc,
+        0x9fd875d8d89f568e,
+        0x30fb10fbfb30708b,
+        0x71ee2feeee71cd23,
+        0x917c847c7c91bbc7,
+        0xe366aa6666e37117,
+        0x8edd7adddd8e7ba6,
+        0x4b173917174bafb8,
+        0x4647c94747464502,
+        0xdc9ebf9e9edc1a84,
+        0xc5ca43cacac5d41e,
+        0x992d772d2d995875,
+        0x79bfdcbfbf792e91,
+        0x1b070907071b3f38,
+        0x23adeaadad23ac01,
+        0x2f5aee5a5a2fb0ea,
+        0xb583988383b5ef6c,
+        0xff33553333ffb685,
+        0xf263a56363f25c3f,
+        0x0a020602020a1210,
+        0x38aae3aaaa389339,
+        0xa871937171a8deaf,
+        0xcfc845c8c8cfc60e,
+        0x7d192b19197dd1c8,
+        0x7049db4949703b72,
+        0x9ad976d9d99a5f86,
+        0x1df20bf2f21d31c3,
+        0x48e338e3e348a84b,
+        0x2a5bed5b5b2ab9e2,
+        0x928885888892bc34,
+        0xc89ab39a9ac83ea4,
+        0xbe266a2626be0b2d,
+        0xfa32563232fabf8d,
+        0x4ab0cdb0b04a59e9,
+        0x6ae926e9e96af21b,
+        0x330f110f0f337778,
+        0xa6d562d5d5a633e6,
+        0xba809d8080baf474,
+        0x7cbedfbebe7c2799,
+        0xdecd4acdcddeeb26,
+        0xe4345c3434e489bd,
+        0x7548d8484875327a,
+        0x24ff1cffff2454ab,
+        0x8f7a8e7a7a8f8df7,
+        0xea90ad9090ea64f4,
+        0x3e5fe15f5f3e9dc2,
+        0xa020602020a03d1d,
+        0xd568b86868d50f67,
+        0x721a2e1a1a72cad0,
+        0x2caeefaeae2cb719,
+        0x5eb4c1b4b45e7dc9,
+        0x1954fc545419ce9a,
+        0xe593a89393e57fec,
+        0xaa22662222aa2f0d,
+        0xe964ac6464e96307,
+        0x12f10ef1f1122adb,
+        0xa273957373a2ccbf,
+        0x5a123612125a8290,
+        0x5d40c040405d7a3a,
+        0x2808180808284840,
+        0xe8c358c3c3e89556,
+        0x7bec29ecec7bdf33,
+        0x90db70dbdb904d96,
+        0x1fa1fea1a11fc061,
+        0x838d8a8d8d83911c,
+        0xc93d473d3dc9c8f5,
+        0xf197a49797f15bcc,
+        0x0000000000000000,
+        0xd4cf4ccfcfd4f936,
+        0x872b7d2b2b876e45,
+        0xb3769a7676b3e197,
+        0xb0829b8282b0e664,
+        0xa9d667d6d6a928fe,
+        0x771b2d1b1b77c3d8,
+        0x5bb5c2b5b55b74c1,
+        0x29afecafaf29be11,
+        0xdf6abe6a6adf1d77,
+        0x0d50f050500deaba,
+        0x4c45cf45454c5712,
+        0x18f308f3f31838cb,
+        0xf030503030f0ad9d,
+        0x74ef2cefef74c42b,
+        0xc33f413f3fc3dae5,
+        0x1c55ff55551cc792,
+        0x10a2fba2a210db79,
+        0x65ea23eaea65e903,
+        0xec65af6565ec6a0f,
+        0x68bad3baba6803b9,
+        0x932f712f2f934a65,
+        0xe7c05dc0c0e78e4e,
+        0x81de7fdede8160be,
+        0x6c1c241c1c6cfce0,
+        0x2efd1afdfd2e46bb,
+        0x644dd74d4d641f52,
+        0xe092ab9292e076e4,
+        0xbc759f7575bcfa8f,
+        0x1e060a06061e3630,
+        0x988a838a8a98ae24,
+        0x40b2cbb2b2404bf9,
+        0x59e637e6e6598563,
+        0x360e120e0e367e70,
+        0x631f211f1f63e7f8,
+        0xf762a66262f75537,
+        0xa3d461d4d4a33aee,
+        0x32a8e5a8a8328129,
+        0xf496a79696f452c4,
+        0x3af916f9f93a629b,
+        0xf6c552c5c5f6a366,
+        0xb1256f2525b11035,
+        0x2059eb595920abf2,
+        0xae84918484aed054,
+        0xa772967272a7c5b7,
+        0xdd394b3939ddecd5,
+        0x614cd44c4c61165a,
+        0x3b5ee25e5e3b94ca,
+        0x8578887878859fe7,
+        0xd838483838d8e5dd,
+        0x868c898c8c869814,
+        0xb2d16ed1d1b217c6,
+        0x0ba5f2a5a50be441,
+        0x4de23be2e24da143,
+        0xf861a36161f84e2f,
+        0x45b3c8b3b34542f1,
+        0xa521632121a53415,
+        0xd69cb99c9cd60894,
+        0x661e221e1e66eef0,
+        0x5243c54343526122,
+        0xfcc754c7c7fcb176,
+        0x2bfc19fcfc2b4fb3,
+        0x14040c0404142420,
+        0x0851f3515108e3b2,
+        0xc799b69999c725bc,
+        0xc46db76d6dc4224f,
+        0x390d170d0d396568,
+        0x35fa13fafa357983,
+        0x84df7cdfdf8469b6,
+        0x9b7e827e7e9ba9d7,
+        0xb4246c2424b4193d,
+        0xd73b4d3b3bd7fec5,
+        0x3dabe0abab3d9a31,
+        0xd1ce4fceced1f03e,
+        0x5511331111559988,
+        0x898f8c8f8f89830c,
+        0x6b4ed24e4e6b044a,
+        0x51b7c4b7b75166d1,
+        0x60eb20ebeb60e00b,
+        0xcc3c443c3cccc1fd,
+        0xbf819e8181bffd7c,
+        0xfe94a19494fe40d4,
+        0x0cf704f7f70c1ceb,
+        0x67b9d6b9b96718a1,
+        0x5f133513135f8b98,
+        0x9c2c742c2c9c517d,
+        0xb8d368d3d3b805d6,
+        0x5ce734e7e75c8c6b,
+        0xcb6eb26e6ecb3957,
+        0xf3c451c4c4f3aa6e,
+        0x0f030503030f1b18,
+        0x1356fa565613dc8a,
+        0x4944cc4444495e1a,
+        0x9e7f817f7f9ea0df,
+        0x37a9e6a9a9378821,
+        0x822a7e2a2a82674d,
+        0x6dbbd0bbbb6d0ab1,
+        0xe2c15ec1c1e28746,
+        0x0253f5535302f1a2,
+        0x8bdc79dcdc8b72ae,
+        0x270b1d0b0b275358,
+        0xd39dba9d9dd3019c,
+        0xc16cb46c6cc12b47,
+        0xf531533131f5a495,
+        0xb9749c7474b9f387,
+        0x09f607f6f60915e3,
+        0x4346ca4646434c0a,
+        0x26ace9acac26a509,
+        0x978986898997b53c,
+        0x44143c141444b4a0,
+        0x42e13ee1e142ba5b,
+        0x4e163a16164ea6b0,
+        0xd23a4e3a3ad2f7cd,
+        0xd069bb6969d0066f,
+        0x2d091b09092d4148,
+        0xad70907070add7a7,
+        0x54b6c7b6b6546fd9,
+        0xb7d06dd0d0b71ece,
+        0x7eed2aeded7ed63b,
+        0xdbcc49ccccdbe22e,
+        0x5742c6424257682a,
+        0xc298b59898c22cb4,
+        0x0ea4f1a4a40eed49,
+        0x882878282888755d,
+        0x315ce45c5c3186da,
+        0x3ff815f8f83f6b93,
+        0xa486978686a4c244,
+    ],
+    [
+        0x1828181878d8c078,
+        0x23652323af2605af,
+        0xc657c6c6f9b87ef9,
+        0xe825e8e86ffb136f,
+        0x87948787a1cb4ca1,
+        0xb8d5b8b86211a962,
+        0x0103010105090805,
+        0x4fd14f4f6e0d426e,
+        0x365a3636ee9badee,
+        0xa6f7a6a604ff5904,
+        0xd26bd2d2bd0cdebd,
+        0xf502f5f5060efb06,
+        0x798b79798096ef80,
+        0x6fb16f6fce305fce,
+        0x91ae9191ef6dfcef,
+        0x52f6525207f8aa07,
+        0x60a06060fd4727fd,
+        0xbcd9bcbc76358976,
+        0x9bb09b9bcd37accd,
+        0x8e8f8e8e8c8a048c,
+        0xa3f8a3a315d27115,
+        0x0c140c0c3c6c603c,
+        0x7b8d7b7b8a84ff8a,
+        0x355f3535e180b5e1,
+        0x1d271d1d69f5e869,
+        0xe03de0e047b35347,
+        0xd764d7d7ac21f6ac,
+        0xc25bc2c2ed9c5eed,
+        0x2e722e2e96436d96,
+        0x4bdd4b4b7a29627a,
+        0xfe1ffefe215da321,
+        0x57f9575716d58216,
+        0x153f151541bda841,
+        0x77997777b6e89fb6,
+        0x37593737eb92a5eb,
+        0xe532e5e5569e7b56,
+        0x9fbc9f9fd9138cd9,
+        0xf00df0f01723d317,
+        0x4ade4a4a7f206a7f,
+        0xda73dada95449e95,
+        0x58e8585825a2fa25,
+        0xc946c9c9cacf06ca,
+        0x297b29298d7c558d,
+        0x0a1e0a0a225a5022,
+        0xb1ceb1b14f50e14f,
+        0xa0fda0a01ac9691a,
+        0x6bbd6b6bda147fda,
+        0x85928585abd95cab,
+        0xbddabdbd733c8173,
+        0x5de75d5d348fd234,
+        0x1030101050908050,
+        0xf401f4f40307f303,
+        0xcb40cbcbc0dd16c0,
+        0x3e423e3ec6d3edc6,
+        0x050f0505112d2811,
+        0x67a96767e6781fe6,
+        0xe431e4e453977353,
+        0x27692727bb0225bb,
+        0x41c3414158733258,
+        0x8b808b8b9da72c9d,
+        0xa7f4a7a701f65101,
+        0x7d877d7d94b2cf94,
+        0x95a29595fb49dcfb,
+        0xd875d8d89f568e9f,
+        0xfb10fbfb30708b30,
+        0xee2feeee71cd2371,
+        0x7c847c7c91bbc791,
+        0x66aa6666e37117e3,
+        0xdd7adddd8e7ba68e,
+        0x173917174bafb84b,
+        0x47c9474746450246,
+        0x9ebf9e9edc1a84dc,
+        0xca43cacac5d41ec5,
+        0x2d772d2d99587599,
+        0xbfdcbfbf792e9179,
+        0x070907071b3f381b,
+        0xadeaadad23ac0123,
+        0x5aee5a5a2fb0ea2f,
+        0x83988383b5ef6cb5,
+        0x33553333ffb685ff,
+        0x63a56363f25c3ff2,
+        0x020602020a12100a,
+        0xaae3aaaa38933938,
+        0x71937171a8deafa8,
+        0xc845c8c8cfc60ecf,
+        0x192b19197dd1c87d,
+        0x49db4949703b7270,
+        0xd976d9d99a5f869a,
+        0xf20bf2f21d31c31d,
+        0xe338e3e348a84b48,
+        0x5bed5b5b2ab9e22a,
+        0x8885888892bc3492,
+        0x9ab39a9ac83ea4c8,
+        0x266a2626be0b2dbe,
+        0x32563232fabf8dfa,
+        0xb0cdb0b04a59e94a,
+        0xe926e9e96af21b6a,
+        0x0f110f0f33777833,
+        0xd562d5d5a633e6a6,
+        0x809d8080baf474ba,
+        0xbedfbebe7c27997c,
+        0xcd4acdcddeeb26de,
+        0x345c3434e489bde4,
+        0x48d8484875327a75,
+        0xff1cffff2454ab24,
+        0x7a8e7a7a8f8df78f,
+        0x90ad9090ea64f4ea,
+        0x5fe15f5f3e9dc23e,
+        0x20602020a03d1da0,
+        0x68b86868d50f67d5,
+        0x1a2e1a1a72cad072,
+        0xaeefaeae2cb7192c,
+        0xb4c1b4b45e7dc95e,
+        0x54fc545419ce9a19,
+        0x93a89393e57fece5,
+        0x22662222aa2f0daa,
+        0x64ac6464e96307e9,
+        0xf10ef1f1122adb12,
+        0x73957373a2ccbfa2,
+        0x123612125a82905a,
+        0x40c040405d7a3a5d,
+        0x0818080828484028,
+        0xc358c3c3e89556e8,
+        0xec29ecec7bdf337b,
+        0xdb70dbdb904d9690,
+        0xa1fea1a11fc0611f,
+        0x8d8a8d8d83911c83,
+        0x3d473d3dc9c8f5c9,
+        0x97a49797f15bccf1,
+        0x0000000000000000,
+        0xcf4ccfcfd4f936d4,
+        0x2b7d2b2b876e4587,
+        0x769a7676b3e197b3,
+        0x829b8282b0e664b0,
+        0xd667d6d6a928fea9,
+        0x1b2d1b1b77c3d877,
+        0xb5c2b5b55b74c15b,
+        0xafecafaf29be1129,
+        0x6abe6a6adf1d77df,
+        0x50f050500deaba0d,
+        0x45cf45454c57124c,
+        0xf308f3f31838cb18,
+        0x30503030f0ad9df0,
+        0xef2cefef74c42b74,
+        0x3f413f3fc3dae5c3,
+        0x55ff55551cc7921c,
+        0xa2fba2a210db7910,
+        0xea23eaea65e90365,
+        0x65af6565ec6a0fec,
+        0xbad3baba6803b968,
+        0x2f712f2f934a6593,
+        0xc05dc0c0e78e4ee7,
+        0xde7fdede8160be81,
+        0x1c241c1c6cfce06c,
+        0xfd1afdfd2e46bb2e,
+        0x4dd74d4d641f5264,
+        0x92ab9292e076e4e0,
+        0x759f7575bcfa8fbc,
+        0x060a06061e36301e,
+        0x8a838a8a98ae2498,
+        0xb2cbb2b2404bf940,
+        0xe637e6e659856359,
+        0x0e120e0e367e7036,
+        0x1f211f1f63e7f863,
+        0x62a66262f75537f7,
+        0xd461d4d4a33aeea3,
+        0xa8e5a8a832812932,
+        0x96a79696f452c4f4,
+        0xf916f9f93a629b3a,
+        0xc552c5c5f6a366f6,
+        0x256f2525b11035b1,
+        0x59eb595920abf220,
+        0x84918484aed054ae,
+        0x72967272a7c5b7a7,
+        0x394b3939ddecd5dd,
+        0x4cd44c4c61165a61,
+        0x5ee25e5e3b94ca3b,
+        0x78887878859fe785,
+        0x38483838d8e5ddd8,
+        0x8c898c8c86981486,
+        0xd16ed1d1b217c6b2,
+        0xa5f2a5a50be4410b,
+        0xe23be2e24da1434d,
+        0x61a36161f84e2ff8,
+        0xb3c8b3b34542f145,
+        0x21632121a53415a5,
+        0x9cb99c9cd60894d6,
+        0x1e221e1e66eef066,
+        0x43c5434352612252,
+        0xc754c7c7fcb176fc,
+        0xfc19fcfc2b4fb32b,
+        0x040c040414242014,
+        0x51f3515108e3b208,
+        0x99b69999c725bcc7,
+        0x6db76d6dc4224fc4,
+        0x0d170d0d39656839,
+        0xfa13fafa35798335,
+        0xdf7cdfdf8469b684,
+        0x7e827e7e9ba9d79b,
+        0x246c2424b4193db4,
+        0x3b4d3b3bd7fec5d7,
+        0xabe0abab3d9a313d,
+        0xce4fceced1f03ed1,
+        0x1133111155998855,
+        0x8f8c8f8f89830c89,
+        0x4ed24e4e6b044a6b,
+        0xb7c4b7b75166d151,
+        0xeb20ebeb60e00b60,
+        0x3c443c3cccc1fdcc,
+        0x819e8181bffd7cbf,
+        0x94a19494fe40d4fe,
+        0xf704f7f70c1ceb0c,
+        0xb9d6b9b96718a167,
+        0x133513135f8b985f,
+        0x2c742c2c9c517d9c,
+        0xd368d3d3b805d6b8,
+        0xe734e7e75c8c6b5c,
+        0x6eb26e6ecb3957cb,
+        0xc451c4c4f3aa6ef3,
+        0x030503030f1b180f,
+        0x56fa565613dc8a13,
+        0x44cc4444495e1a49,
+        0x7f817f7f9ea0df9e,
+        0xa9e6a9a937882137,
+        0x2a7e2a2a82674d82,
+        0xbbd0bbbb6d0ab16d,
+        0xc15ec1c1e28746e2,
+        0x53f5535302f1a202,
+        0xdc79dcdc8b72ae8b,
+        0x0b1d0b0b27535827,
+        0x9dba9d9dd3019cd3,
+        0x6cb46c6cc12b47c1,
+        0x31533131f5a495f5,
+        0x749c7474b9f387b9,
+        0xf607f6f60915e309,
+        0x46ca4646434c0a43,
+        0xace9acac26a50926,
+        0x8986898997b53c97,
+        0x143c141444b4a044,
+        0xe13ee1e142ba5b42,
+        0x163a16164ea6b04e,
+        0x3a4e3a3ad2f7cdd2,
+        0x69bb6969d0066fd0,
+        0x091b09092d41482d,
+        0x70907070add7a7ad,
+        0xb6c7b6b6546fd954,
+        0xd06dd0d0b71eceb7,
+        0xed2aeded7ed63b7e,
+        0xcc49ccccdbe22edb,
+        0x42c6424257682a57,
+        0x98b59898c22cb4c2,
+        0xa4f1a4a40eed490e,
+        0x2878282888755d88,
+        0x5ce45c5c3186da31,
+        0xf815f8f83f6b933f,
+        0x86978686a4c244a4,
+    ],
+    [
+        0x28181878d8c07818,
+        0x652323af2605af23,
+        0x57c6c6f9b87ef9c6,
+        0x25e8e86ffb136fe8,
+        0x948787a1cb4ca187,
+        0xd5b8b86211a962b8,
+        0x0301010509080501,
+        0xd14f4f6e0d426e4f,
+        0x5a3636ee9badee36,
+        0xf7a6a604ff5904a6,
+        0x6bd2d2bd0cdebdd2,
+        0x02f5f5060efb06f5,
+        0x8b79798096ef8079,
+        0xb16f6fce305fce6f,
+        0xae9191ef6dfcef91,
+        0xf6525207f8aa0752,
+        0xa06060fd4727fd60,
+        0xd9bcbc76358976bc,
+        0xb09b9bcd37accd9b,
+        0x8f8e8e8c8a048c8e,
+        0xf8a3a315d27115a3,
+        0x140c0c3c6c603c0c,
+        0x8d7b7b8a84ff8a7b,
+        0x5f3535e180b5e135,
+        0x271d1d69f5e8691d,
+        0x3de0e047b35347e0,
+        0x64d7d7ac21f6acd7,
+        0x5bc2c2ed9c5eedc2,
+        0x722e2e96436d962e,
+        0xdd4b4b7a29627a4b,
+        0x1ffefe215da321fe,
+        0xf9575716d5821657,
+        0x3f151541bda84115,
+        0x997777b6e89fb677,
+        0x593737eb92a5eb37,
+        0x32e5e5569e7b56e5,
+        0xbc9f9fd9138cd99f,
+        0x0df0f01723d317f0,
+        0xde4a4a7f206a7f4a,
+        0x73dada95449e95da,
+        0xe8585825a2fa2558,
+        0x46c9c9cacf06cac9,
+        0x7b29298d7c558d29,
+        0x1e0a0a225a50220a,
+        0xceb1b14f50e14fb1,
+        0xfda0a01ac9691aa0,
+        0xbd6b6bda147fda6b,
+        0x928585abd95cab85,
+        0xdabdbd733c8173bd,
+        0xe75d5d348fd2345d,
+        0x3010105090805010,
+        0x01f4f40307f303f4,
+        0x40cbcbc0dd16c0cb,
+        0x423e3ec6d3edc63e,
+        0x0f0505112d281105,
+        0xa96767e6781fe667,
+        0x31e4e453977353e4,
+        0x692727bb0225bb27,
+        0xc341415873325841,
+        0x808b8b9da72c9d8b,
+        0xf4a7a701f65101a7,
+        0x877d7d94b2cf947d,
+        0xa29595fb49dcfb95,
+        0x75d8d89f568e9fd8,
+        0x10fbfb30708b30fb,
+        0x2feeee71cd2371ee,
+        0x847c7c91bbc7917c,
+        0xaa6666e37117e366,
+        0x7adddd8e7ba68edd,
+        0x3917174bafb84b17,
+        0xc947474645024647,
+        0xbf9e9edc1a84dc9e,
+        0x43cacac5d41ec5ca,
+        0x772d2d995875992d,
+        0xdcbfbf792e9179bf,
+        0x0907071b3f381b07,
+        0xeaadad23ac0123ad,
+        0xee5a5a2fb0ea2f5a,
+        0x988383b5ef6cb583,
+        0x553333ffb685ff33,
+        0xa56363f25c3ff263,
+        0x0602020a12100a02,
+        0xe3aaaa38933938aa,
+        0x937171a8deafa871,
+        0x45c8c8cfc60ecfc8,
+        0x2b19197dd1c87d19,
+        0xdb4949703b727049,
+        0x76d9d99a5f869ad9,
+        0x0bf2f21d31c31df2,
+        0x38e3e348a84b48e3,
+        0xed5b5b2ab9e22a5b,
+        0x85888892bc349288,
+        0xb39a9ac83ea4c89a,
+        0x6a2626be0b2dbe26,
+        0x563232fabf8dfa32,
+        0xcdb0b04a59e94ab0,
+        0x26e9e96af21b6ae9,
+        0x110f0f337778330f,
+        0x62d5d5a633e6a6d5,
+        0x9d8080baf474ba80,
+        0xdfbebe7c27997cbe,
+        0x4acdcddeeb26decd,
+        0x5c3434e489bde434,
+        0xd8484875327a7548,
+        0x1cffff2454ab24ff,
+        0x8e7a7a8f8df78f7a,
+        0xad9090ea64f4ea90,
+        0xe15f5f3e9dc23e5f,
+        0x602020a03d1da020,
+        0xb86868d50f67d568,
+        0x2e1a1a72cad0721a,
+        0xefaeae2cb7192cae,
+        0xc1b4b45e7dc95eb4,
+        0xfc545419ce9a1954,
+        0xa89393e57fece593,
+        0x662222aa2f0daa22,
+        0xac6464e96307e964,
+        0x0ef1f1122adb12f1,
+        0x957373a2ccbfa273,
+        0x3612125a82905a12,
+        0xc040405d7a3a5d40,
+        0x1808082848402808,
+        0x58c3c3e89556e8c3,
+        0x29ecec7bdf337bec,
+        0x70dbdb904d9690db,
+        0xfea1a11fc0611fa1,
+        0x8a8d8d83911c838d,
+        0x473d3dc9c8f5c93d,
+        0xa49797f15bccf197,
+        0x0000000000000000,
+        0x4ccfcfd4f936d4cf,
+        0x7d2b2b876e45872b,
+        0x9a7676b3e197b376,
+        0x9b8282b0e664b082,
+        0x67d6d6a928fea9d6,
+        0x2d1b1b77c3d8771b,
+        0xc2b5b55b74c15bb5,
+        0xecafaf29be1129af,
+        0xbe6a6adf1d77df6a,
+        0xf050500deaba0d50,
+        0xcf45454c57124c45,
+        0x08f3f31838cb18f3,
+        0x503030f0ad9df030,
+        0x2cefef74c42b74ef,
+        0x413f3fc3dae5c33f,
+        0xff55551cc7921c55,
+        0xfba2a210db7910a2,
+        0x23eaea65e90365ea,
+        0xaf6565ec6a0fec65,
+        0xd3baba6803b968ba,
+        0x712f2f934a65932f,
+        0x5dc0c0e78e4ee7c0,
+        0x7fdede8160be81de,
+        0x241c1c6cfce06c1c,
+        0x1afdfd2e46bb2efd,
+        0xd74d4d641f52644d,
+        0xab9292e076e4e092,
+        0x9f7575bcfa8fbc75,
+        0x0a06061e36301e06,
+        0x838a8a98ae24988a,
+        0xcbb2b2404bf940b2,
+        0x37e6e659856359e6,
+        0x120e0e367e70360e,
+        0x211f1f63e7f8631f,
+        0xa66262f75537f762,
+        0x61d4d4a33aeea3d4,
+        0xe5a8a832812932a8,
+        0xa79696f452c4f496,
+        0x16f9f93a629b3af9,
+        0x52c5c5f6a366f6c5,
+        0x6f2525b11035b125,
+        0xeb595920abf22059,
+        0x918484aed054ae84,
+        0x967272a7c5b7a772,
+        0x4b3939ddecd5dd39,
+        0xd44c4c61165a614c,
+        0xe25e5e3b94ca3b5e,
+        0x887878859fe78578,
+        0x483838d8e5ddd838,
+        0x898c8c869814868c,
+        0x6ed1d1b217c6b2d1,
+        0xf2a5a50be4410ba5,
+        0x3be2e24da1434de2,
+        0xa36161f84e2ff861,
+        0xc8b3b34542f145b3,
+        0x632121a53415a521,
+        0xb99c9cd60894d69c,
+        0x221e1e66eef0661e,
+        0xc543435261225243,
+        0x54c7c7fcb176fcc7,
+        0x19fcfc2b4fb32bfc,
+        0x0c04041424201404,
+        0xf3515108e3b20851,
+        0xb69999c725bcc799,
+        0xb76d6dc4224fc46d,
+        0x170d0d396568390d,
+        0x13fafa35798335fa,
+        0x7cdfdf8469b684df,
+        0x827e7e9ba9d79b7e,
+        0x6c2424b4193db424,
+        0x4d3b3bd7fec5d73b,
+        0xe0abab3d9a313dab,
+        0x4fceced1f03ed1ce,
+        0x3311115599885511,
+        0x8c8f8f89830c898f,
+        0xd24e4e6b044a6b4e,
+        0xc4b7b75166d151b7,
+        0x20ebeb60e00b60eb,
+        0x443c3cccc1fdcc3c,
+        0x9e8181bffd7cbf81,
+        0xa19494fe40d4fe94,
+        0x04f7f70c1ceb0cf7,
+        0xd6b9b96718a167b9,
+        0x3513135f8b985f13,
+        0x742c2c9c517d9c2c,
+        0x68d3d3b805d6b8d3,
+        0x34e7e75c8c6b5ce7,
+        0xb26e6ecb3957cb6e,
+        0x51c4c4f3aa6ef3c4,
+        0x0503030f1b180f03,
+        0xfa565613dc8a1356,
+        0xcc4444495e1a4944,
+        0x817f7f9ea0df9e7f,
+        0xe6a9a937882137a9,
+        0x7e2a2a82674d822a,
+        0xd0bbbb6d0ab16dbb,
+        0x5ec1c1e28746e2c1,
+        0xf5535302f1a20253,
+        0x79dcdc8b72ae8bdc,
+        0x1d0b0b275358270b,
+        0xba9d9dd3019cd39d,
+        0xb46c6cc12b47c16c,
+        0x533131f5a495f531,
+        0x9c7474b9f387b974,
+        0x07f6f60915e309f6,
+        0xca4646434c0a4346,
+        0xe9acac26a50926ac,
+        0x86898997b53c9789,
+        0x3c141444b4a04414,
+        0x3ee1e142ba5b42e1,
+        0x3a16164ea6b04e16,
+        0x4e3a3ad2f7cdd23a,
+        0xbb6969d0066fd069,
+        0x1b09092d41482d09,
+        0x907070add7a7ad70,
+        0xc7b6b6546fd954b6,
+        0x6dd0d0b71eceb7d0,
+        0x2aeded7ed63b7eed,
+        0x49ccccdbe22edbcc,
+        0xc6424257682a5742,
+        0xb59898c22cb4c298,
+        0xf1a4a40eed490ea4,
+        0x78282888755d8828,
+        0xe45c5c3186da315c,
+        0x15f8f83f6b933ff8,
+        0x978686a4c244a486,
+    ],
+    [
+        0x181878d8c0781828,
+        0x2323af2605af2365,
+        0xc6c6f9b87ef9c657,
+        0xe8e86ffb136fe825,
+        0x8787a1cb4ca18794,
+        0xb8b86211a962b8d5,
+        0x0101050908050103,
+        0x4f4f6e0d426e4fd1,
+        0x3636ee9badee365a,
+        0xa6a604ff5904a6f7,
+        0xd2d2bd0cdebdd26b,
+        0xf5f5060efb06f502,
+        0x79798096ef80798b,
+        0x6f6fce305fce6fb1,
+        0x9191ef6dfcef91ae,
+        0x525207f8aa0752f6,
+        0x6060fd4727fd60a0,
+        0xbcbc76358976bcd9,
+        0x9b9bcd37accd9bb0,
+        0x8e8e8c8a048c8e8f,
+        0xa3a315d27115a3f8,
+        0x0c0c3c6c603c0c14,
+        0x7b7b8a84ff8a7b8d,
+        0x3535e180b5e1355f,
+        0x1d1d69f5e8691d27,
+        0xe0e047b35347e03d,
+        0xd7d7ac21f6acd764,
+        0xc2c2ed9c5eedc25b,
+        0x2e2e96436d962e72,
+        0x4b4b7a29627a4bdd,
+        0xfefe215da321fe1f,
+        0x575716d5821657f9,
+        0x151541bda841153f,
+        0x7777b6e89fb67799,
+        0x3737eb92a5eb3759,
+        0xe5e5569e7b56e532,
+        0x9f9fd9138cd99fbc,
+        0xf0f01723d317f00d,
+        0x4a4a7f206a7f4ade,
+        0xdada95449e95da73,
+        0x585825a2fa2558e8,
+        0xc9c9cacf06cac946,
+        0x29298d7c558d297b,
+        0x0a0a225a50220a1e,
+        0xb1b14f50e14fb1ce,
+        0xa0a01ac9691aa0fd,
+        0x6b6bda147fda6bbd,
+        0x8585abd95cab8592,
+        0xbdbd733c8173bdda,
+        0x5d5d348fd2345de7,
+        0x1010509080501030,
+        0xf4f40307f303f401,
+        0xcbcbc0dd16c0cb40,
+        0x3e3ec6d3edc63e42,
+        0x0505112d2811050f,
+        0x6767e6781fe667a9,
+        0xe4e453977353e431,
+        0x2727bb0225bb2769,
+        0x41415873325841c3,
+        0x8b8b9da72c9d8b80,
+        0xa7a701f65101a7f4,
+        0x7d7d94b2cf947d87,
+        0x9595fb49dcfb95a2,
+        0xd8d89f568e9fd875,
+        0xfbfb30708b30fb10,
+        0xeeee71cd2371ee2f,
+        0x7c7c91bbc7917c84,
+        0x6666e37117e366aa,
+        0xdddd8e7ba68edd7a,
+        0x17174bafb84b1739,
+        0x47474645024647c9,
+        0x9e9edc1a84dc9ebf,
+        0xcacac5d41ec5ca43,
+        0x2d2d995875992d77,
+        0xbfbf792e9179bfdc,
+        0x07071b3f381b0709,
+        0xadad23ac0123adea,
+        0x5a5a2fb0ea2f5aee,
+        0x8383b5ef6cb58398,
+        0x3333ffb685ff3355,
+        0x6363f25c3ff263a5,
+        0x02020a12100a0206,
+        0xaaaa38933938aae3,
+        0x7171a8deafa87193,
+        0xc8c8cfc60ecfc845,
+        0x19197dd1c87d192b,
+        0x4949703b727049db,
+        0xd9d99a5f869ad976,
+        0xf2f21d31c31df20b,
+        0xe3e348a84b48e338,
+        0x5b5b2ab9e22a5bed,
+        0x888892bc34928885,
+        0x9a9ac83ea4c89ab3,
+        0x2626be0b2dbe266a,
+        0x3232fabf8dfa3256,
+        0xb0b04a59e94ab0cd,
+        0xe9e96af21b6ae926,
+        0x0f0f337778330f11,
+        0xd5d5a633e6a6d562,
+        0x8080baf474ba809d,
+        0xbebe7c27997cbedf,
+        0xcdcddeeb26decd4a,
+        0x3434e489bde4345c,
+        0x484875327a7548d8,
+        0xffff2454ab24ff1c,
+        0x7a7a8f8df78f7a8e,
+        0x9090ea64f4ea90ad,
+        0x5f5f3e9dc23e5fe1,
+        0x2020a03d1da02060,
+        0x6868d50f67d568b8,
+        0x1a1a72cad0721a2e,
+        0xaeae2cb7192caeef,
+        0xb4b45e7dc95eb4c1,
+        0x545419ce9a1954fc,
+        0x9393e57fece593a8,
+        0x2222aa2f0daa2266,
+        0x6464e96307e964ac,
+        0xf1f1122adb12f10e,
+        0x7373a2ccbfa27395,
+        0x12125a82905a1236,
+        0x40405d7a3a5d40c0,
+        0x0808284840280818,
+        0xc3c3e89556e8c358,
+        0xecec7bdf337bec29,
+        0xdbdb904d9690db70,
+        0xa1a11fc0611fa1fe,
+        0x8d8d83911c838d8a,
+        0x3d3dc9c8f5c93d47,
+        0x9797f15bccf197a4,
+        0x0000000000000000,
+        0xcfcfd4f936d4cf4c,
+        0x2b2b876e45872b7d,
+        0x7676b3e197b3769a,
+        0x8282b0e664b0829b,
+        0xd6d6a928fea9d667,
+        0x1b1b77c3d8771b2d,
+        0xb5b55b74c15bb5c2,
+        0xafaf29be1129afec,
+        0x6a6adf1d77df6abe,
+        0x50500deaba0d50f0,
+        0x45454c57124c45cf,
+        0xf3f31838cb18f308,
+        0x3030f0ad9df03050,
+        0xefef74c42b74ef2c,
+        0x3f3fc3dae5c33f41,
+        0x55551cc7921c55ff,
+        0xa2a210db7910a2fb,
+        0xeaea65e90365ea23,
+        0x6565ec6a0fec65af,
+        0xbaba6803b968bad3,
+        0x2f2f934a65932f71,
+        0xc0c0e78e4ee7c05d,
+        0xdede8160be81de7f,
+        0x1c1c6cfce06c1c24,
+        0xfdfd2e46bb2efd1a,
+        0x4d4d641f52644dd7,
+        0x9292e076e4e092ab,
+        0x7575bcfa8fbc759f,
+        0x06061e36301e060a,
+        0x8a8a98ae24988a83,
+        0xb2b2404bf940b2cb,
+        0xe6e659856359e637,
+        0x0e0e367e70360e12,
+        0x1f1f63e7f8631f21,
+        0x6262f75537f762a6,
+        0xd4d4a33aeea3d461,
+        0xa8a832812932a8e5,
+        0x9696f452c4f496a7,
+        0xf9f93a629b3af916,
+        0xc5c5f6a366f6c552,
+        0x2525b11035b1256f,
+        0x595920abf22059eb,
+        0x8484aed054ae8491,
+        0x7272a7c5b7a77296,
+        0x3939ddecd5dd394b,
+        0x4c4c61165a614cd4,
+        0x5e5e3b94ca3b5ee2,
+        0x7878859fe7857888,
+        0x3838d8e5ddd83848,
+        0x8c8c869814868c89,
+        0xd1d1b217c6b2d16e,
+        0xa5a50be4410ba5f2,
+        0xe2e24da1434de23b,
+        0x6161f84e2ff861a3,
+        0xb3b34542f145b3c8,
+        0x2121a53415a52163,
+        0x9c9cd60894d69cb9,
+        0x1e1e66eef0661e22,
+        0x43435261225243c5,
+        0xc7c7fcb176fcc754,
+        0xfcfc2b4fb32bfc19,
+        0x040414242014040c,
+        0x515108e3b20851f3,
+        0x9999c725bcc799b6,
+        0x6d6dc4224fc46db7,
+        0x0d0d396568390d17,
+        0xfafa35798335fa13,
+        0xdfdf8469b684df7c,
+        0x7e7e9ba9d79b7e82,
+        0x2424b4193db4246c,
+        0x3b3bd7fec5d73b4d,
+        0xabab3d9a313dabe0,
+        0xceced1f03ed1ce4f,
+        0x1111559988551133,
+        0x8f8f89830c898f8c,
+        0x4e4e6b044a6b4ed2,
+        0xb7b75166d151b7c4,
+        0xebeb60e00b60eb20,
+        0x3c3cccc1fdcc3c44,
+        0x8181bffd7cbf819e,
+        0x9494fe40d4fe94a1,
+        0xf7f70c1ceb0cf704,
+        0xb9b96718a167b9d6,
+        0x13135f8b985f1335,
+        0x2c2c9c517d9c2c74,
+        0xd3d3b805d6b8d368,
+        0xe7e75c8c6b5ce734,
+        0x6e6ecb3957cb6eb2,
+        0xc4c4f3aa6ef3c451,
+        0x03030f1b180f0305,
+        0x565613dc8a1356fa,
+        0x4444495e1a4944cc,
+        0x7f7f9ea0df9e7f81,
+        0xa9a937882137a9e6,
+        0x2a2a82674d822a7e,
+        0xbbbb6d0ab16dbbd0,
+        0xc1c1e28746e2c15e,
+        0x535302f1a20253f5,
+        0xdcdc8b72ae8bdc79,
+        0x0b0b275358270b1d,
+        0x9d9dd3019cd39dba,
+        0x6c6cc12b47c16cb4,
+        0x3131f5a495f53153,
+        0x7474b9f387b9749c,
+        0xf6f60915e309f607,
+        0x4646434c0a4346ca,
+        0xacac26a50926ace9,
+        0x898997b53c978986,
+        0x141444b4a044143c,
+        0xe1e142ba5b42e13e,
+        0x16164ea6b04e163a,
+        0x3a3ad2f7cdd23a4e,
+        0x6969d0066fd069bb,
+        0x09092d41482d091b,
+        0x7070add7a7ad7090,
+        0xb6b6546fd954b6c7,
+        0xd0d0b71eceb7d06d,
+        0xeded7ed63b7eed2a,
+        0xccccdbe22edbcc49,
+        0x424257682a5742c6,
+        0x9898c22cb4c298b5,
+        0xa4a40eed490ea4f1,
+        0x282888755d882878,
+        0x5c5c3186da315ce4,
+        0xf8f83f6b933ff815,
+        0x8686a4c244a48697,
+    ],
+    [
+        0x1878d8c078182818,
+        0x23af2605af236523,
+        0xc6f9b87ef9c657c6,
+        0xe86ffb136fe825e8,
+        0x87a1cb4ca1879487,
+        0xb86211a962b8d5b8,
+        0x0105090805010301,
+        0x4f6e0d426e4fd14f,
+        0x36ee9badee365a36,
+        0xa604ff5904a6f7a6,
+        0xd2bd0cdebdd26bd2,
+        0xf5060efb06f502f5,
+        0x798096ef80798b79,
+        0x6fce305fce6fb16f,
+        0x91ef6dfcef91ae91,
+        0x5207f8aa0752f652,
+        0x60fd4727fd60a060,
+        0xbc76358976bcd9bc,
+        0x9bcd37accd9bb09b,
+        0x8e8c8a048c8e8f8e,
+        0xa315d27115a3f8a3,
+        0x0c3c6c603c0c140c,
+        0x7b8a84ff8a7b8d7b,
+        0x35e180b5e1355f35,
+        0x1d69f5e8691d271d,
+        0xe047b35347e03de0,
+        0xd7ac21f6acd764d7,
+        0xc2ed9c5eedc25bc2,
+        0x2e96436d962e722e,
+        0x4b7a29627a4bdd4b,
+        0xfe215da321fe1ffe,
+        0x5716d5821657f957,
+        0x1541bda841153f15,
+        0x77b6e89fb6779977,
+        0x37eb92a5eb375937,
+        0xe5569e7b56e532e5,
+        0x9fd9138cd99fbc9f,
+        0xf01723d317f00df0,
+        0x4a7f206a7f4ade4a,
+        0xda95449e95da73da,
+        0x5825a2fa2558e858,
+        0xc9cacf06cac946c9,
+        0x298d7c558d297b29,
+        0x0a225a50220a1e0a,
+        0xb14f50e14fb1ceb1,
+        0xa01ac9691aa0fda0,
+        0x6bda147fda6bbd6b,
+        0x85abd95cab859285,
+        0xbd733c8173bddabd,
+        0x5d348fd2345de75d,
+        0x1050908050103010,
+        0xf40307f303f401f4,
+        0xcbc0dd16c0cb40cb,
+        0x3ec6d3edc63e423e,
+        0x05112d2811050f05,
+        0x67e6781fe667a967,
+        0xe453977353e431e4,
+        0x27bb0225bb276927,
+        0x415873325841c341,
+        0x8b9da72c9d8b808b,
+        0xa701f65101a7f4a7,
+        0x7d94b2cf947d877d,
+        0x95fb49dcfb95a295,
+        0xd89f568e9fd875d8,
+        0xfb30708b30fb10fb,
+        0xee71cd2371ee2fee,
+        0x7c91bbc7917c847c,
+        0x66e37117e366aa66,
+        0xdd8e7ba68edd7add,
+        0x174bafb84b173917,
+        0x474645024647c947,
+        0x9edc1a84dc9ebf9e,
+        0xcac5d41ec5ca43ca,
+        0x2d995875992d772d,
+        0xbf792e9179bfdcbf,
+        0x071b3f381b070907,
+        0xad23ac0123adeaad,
+        0x5a2fb0ea2f5aee5a,
+        0x83b5ef6cb5839883,
+        0x33ffb685ff335533,
+        0x63f25c3ff263a563,
+        0x020a12100a020602,
+        0xaa38933938aae3aa,
+        0x71a8deafa8719371,
+        0xc8cfc60ecfc845c8,
+        0x197dd1c87d192b19,
+        0x49703b727049db49,
+        0xd99a5f869ad976d9,
+        0xf21d31c31df20bf2,
+        0xe348a84b48e338e3,
+        0x5b2ab9e22a5bed5b,
+        0x8892bc3492888588,
+        0x9ac83ea4c89ab39a,
+        0x26be0b2dbe266a26,
+        0x32fabf8dfa325632,
+        0xb04a59e94ab0cdb0,
+        0xe96af21b6ae926e9,
+        0x0f337778330f110f,
+        0xd5a633e6a6d562d5,
+        0x80baf474ba809d80,
+        0xbe7c27997cbedfbe,
+        0xcddeeb26decd4acd,
+        0x34e489bde4345c34,
+        0x4875327a7548d848,
+        0xff2454ab24ff1cff,
+        0x7a8f8df78f7a8e7a,
+        0x90ea64f4ea90ad90,
+        0x5f3e9dc23e5fe15f,
+        0x20a03d1da0206020,
+        0x68d50f67d568b868,
+        0x1a72cad0721a2e1a,
+        0xae2cb7192caeefae,
+        0xb45e7dc95eb4c1b4,
+        0x5419ce9a1954fc54,
+        0x93e57fece593a893,
+        0x22aa2f0daa226622,
+        0x64e96307e964ac64,
+        0xf1122adb12f10ef1,
+        0x73a2ccbfa2739573,
+        0x125a82905a123612,
+        0x405d7a3a5d40c040,
+        0x0828484028081808,
+        0xc3e89556e8c358c3,
+        0xec7bdf337bec29ec,
+        0xdb904d9690db70db,
+        0xa11fc0611fa1fea1,
+        0x8d83911c838d8a8d,
+        0x3dc9c8f5c93d473d,
+        0x97f15bccf197a497,
+        0x0000000000000000,
+        0xcfd4f936d4cf4ccf,
+        0x2b876e45872b7d2b,
+        0x76b3e197b3769a76,
+        0x82b0e664b0829b82,
+        0xd6a928fea9d667d6,
+        0x1b77c3d8771b2d1b,
+        0xb55b74c15bb5c2b5,
+        0xaf29be1129afecaf,
+        0x6adf1d77df6abe6a,
+        0x500deaba0d50f050,
+        0x454c57124c45cf45,
+        0xf31838cb18f308f3,
+        0x30f0ad9df0305030,
+        0xef74c42b74ef2cef,
+        0x3fc3dae5c33f413f,
+        0x551cc7921c55ff55,
+        0xa210db7910a2fba2,
+        0xea65e90365ea23ea,
+        0x65ec6a0fec65af65,
+        0xba6803b968bad3ba,
+        0x2f934a65932f712f,
+        0xc0e78e4ee7c05dc0,
+        0xde8160be81de7fde,
+        0x1c6cfce06c1c241c,
+        0xfd2e46bb2efd1afd,
+        0x4d641f52644dd74d,
+        0x92e076e4e092ab92,
+        0x75bcfa8fbc759f75,
+        0x061e36301e060a06,
+        0x8a98ae24988a838a,
+        0xb2404bf940b2cbb2,
+        0xe659856359e637e6,
+        0x0e367e70360e120e,
+        0x1f63e7f8631f211f,
+        0x62f75537f762a662,
+        0xd4a33aeea3d461d4,
+        0xa832812932a8e5a8,
+        0x96f452c4f496a796,
+        0xf93a629b3af916f9,
+        0xc5f6a366f6c552c5,
+        0x25b11035b1256f25,
+        0x5920abf22059eb59,
+        0x84aed054ae849184,
+        0x72a7c5b7a7729672,
+        0x39ddecd5dd394b39,
+        0x4c61165a614cd44c,
+        0x5e3b94ca3b5ee25e,
+        0x78859fe785788878,
+        0x38d8e5ddd8384838,
+        0x8c869814868c898c,
+        0xd1b217c6b2d16ed1,
+        0xa50be4410ba5f2a5,
+        0xe24da1434de23be2,
+        0x61f84e2ff861a361,
+        0xb34542f145b3c8b3,
+        0x21a53415a5216321,
+        0x9cd60894d69cb99c,
+        0x1e66eef0661e221e,
+        0x435261225243c543,
+        0xc7fcb176fcc754c7,
+        0xfc2b4fb32bfc19fc,
+        0x0414242014040c04,
+        0x5108e3b20851f351,
+        0x99c725bcc799b699,
+        0x6dc4224fc46db76d,
+        0x0d396568390d170d,
+        0xfa35798335fa13fa,
+        0xdf8469b684df7cdf,
+        0x7e9ba9d79b7e827e,
+        0x24b4193db4246c24,
+        0x3bd7fec5d73b4d3b,
+        0xab3d9a313dabe0ab,
+        0xced1f03ed1ce4fce,
+        0x1155998855113311,
+        0x8f89830c898f8c8f,
+        0x4e6b044a6b4ed24e,
+        0xb75166d151b7c4b7,
+        0xeb60e00b60eb20eb,
+        0x3cccc1fdcc3c443c,
+        0x81bffd7cbf819e81,
+        0x94fe40d4fe94a194,
+        0xf70c1ceb0cf704f7,
+        0xb96718a167b9d6b9,
+        0x135f8b985f133513,
+        0x2c9c517d9c2c742c,
+        0xd3b805d6b8d368d3,
+        0xe75c8c6b5ce734e7,
+        0x6ecb3957cb6eb26e,
+        0xc4f3aa6ef3c451c4,
+        0x030f1b180f030503,
+        0x5613dc8a1356fa56,
+        0x44495e1a4944cc44,
+        0x7f9ea0df9e7f817f,
+        0xa937882137a9e6a9,
+        0x2a82674d822a7e2a,
+        0xbb6d0ab16dbbd0bb,
+        0xc1e28746e2c15ec1,
+        0x5302f1a20253f553,
+        0xdc8b72ae8bdc79dc,
+        0x0b275358270b1d0b,
+        0x9dd3019cd39dba9d,
+        0x6cc12b47c16cb46c,
+        0x31f5a495f5315331,
+        0x74b9f387b9749c74,
+        0xf60915e309f607f6,
+        0x46434c0a4346ca46,
+        0xac26a50926ace9ac,
+        0x8997b53c97898689,
+        0x1444b4a044143c14,
+        0xe142ba5b42e13ee1,
+        0x164ea6b04e163a16,
+        0x3ad2f7cdd23a4e3a,
+        0x69d0066fd069bb69,
+        0x092d41482d091b09,
+        0x70add7a7ad709070,
+        0xb6546fd954b6c7b6,
+        0xd0b71eceb7d06dd0,
+        0xed7ed63b7eed2aed,
+        0xccdbe22edbcc49cc,
+        0x4257682a5742c642,
+        0x98c22cb4c298b598,
+        0xa40eed490ea4f1a4,
+        0x2888755d88287828,
+        0x5c3186da315ce45c,
+        0xf83f6b933ff815f8,
+        0x86a4c244a4869786,
+    ],
+];
@@ -3,11 +3,17 @@
 //! This is the algorithm recommended by NESSIE (New European Schemes for
 //! Signatures, Integrity and Encryption; an European research project).
 //!
-//! The constants used by Whirlpool were changed twice (2001 and 2003) - this
-//! crate only implements the most recent standard. The two older Whirlpool
-//! implementations (sometimes called Whirlpool-0 (pre 2001) and Whirlpool-T
-//! (pre 2003)) were not used much anyway (both have never been recommended
-//! by NESSIE).
+//! The constants used by Whirlpool were changed twice (2001 and 2003).
+//! [`Whirlpool`] implements the most recent standard. The two older versions,
+//! Whirlpool-0 (pre 2001) and Whirlpool-T (pre 2003), were never recommended
+//! by NESSIE and are only provided as [`Whirlpool0`] and [`WhirlpoolT`] for
+//! compatibility with legacy data.
+//!
+//! The default implementation uses lookup tables indexed by the processed
+//! data, which may leak information through cache timing. When hashing
+//! secrets, e.g. in HMAC or PBKDF2, consider enabling the `constant-time`
+//! feature, which selects a slower table-free backend for [`Whirlpool`] and
+//! [`WhirlpoolT`].
 //!
 //! For details see [http://www.larc.usp.br/~pbarreto/WhirlpoolPage.html](https://web.archive.org/web/20171129084214/http://www.larc.usp.br/~pbarreto/WhirlpoolPage.html).
 //!
@@ -44,13 +50,14 @@
 
 pub use digest::{self, Digest};
 
-#[cfg(not(all(feature = "asm", any(target_arch = "x86", target_arch = "x86_64"))))]
 mod compress;
 
-#[cfg(all(feature = "asm", any(target_arch = "x86", target_arch = "x86_64")))]
-use whirlpool_asm as compress;
+use compress::{compress, compress_0, compress_t};
 
-use compress::compress;
+/// Compression function backends compiled for the target, exposed for the
+/// equivalence tests in `tests/`. Not part of the public API.
+#[doc(hidden)]
+pub use compress::backends;
 
 use core::fmt;
 use digest::{
@@ -63,101 +70,128 @@ use digest::{
     HashMarker, Output,
 };
 
-/// Core Whirlpool hasher state.
-#[derive(Clone)]
-pub struct WhirlpoolCore {
-    bit_len: [u64; 4],
-    state: [u64; 8],
-}
-
-impl HashMarker for WhirlpoolCore {}
+macro_rules! impl_core {
+    ($name:ident, $compress:ident, $alg_name:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            bit_len: [u64; 4],
+            state: [u64; 8],
+        }
 
-impl BlockSizeUser for WhirlpoolCore {
-    type BlockSize = U64;
-}
+        impl HashMarker for $name {}
 
-impl BufferKindUser for WhirlpoolCore {
-    type BufferKind = Eager;
-}
-
-impl OutputSizeUser for WhirlpoolCore {
-    type OutputSize = U64;
-}
+        impl BlockSizeUser for $name {
+            type BlockSize = U64;
+        }
 
-impl UpdateCore for WhirlpoolCore {
-    #[inline]
-    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
-        let block_bits = 8 * BLOCK_SIZE as u64;
-        self.update_len(block_bits * (blocks.len() as u64));
-        compress(&mut self.state, convert(blocks));
-    }
-}
+        impl BufferKindUser for $name {
+            type BufferKind = Eager;
+        }
 
-impl FixedOutputCore for WhirlpoolCore {
-    #[inline]
-    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
-        let pos = buffer.get_pos();
-        self.update_len(8 * pos as u64);
+        impl OutputSizeUser for $name {
+            type OutputSize = U64;
+        }
 
-        let mut buf = [0u8; 4 * 8];
-        for (chunk, v) in buf.chunks_exact_mut(8).zip(self.bit_len.iter()) {
-            chunk.copy_from_slice(&v.to_be_bytes());
+        impl UpdateCore for $name {
+            #[inline]
+            fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+                let block_bits = 8 * BLOCK_SIZE as u64;
+                self.update_len(block_bits * (blocks.len() as u64));
+                $compress(&mut self.state, convert(blocks));
+            }
         }
 
-        let mut state = self.state;
-        buffer.digest_pad(0x80, &buf, |block| {
-            compress(&mut state, convert(core::slice::from_ref(block)));
-        });
+        impl FixedOutputCore for $name {
+            #[inline]
+            fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+                let pos = buffer.get_pos();
+                self.update_len(8 * pos as u64);
+
+                let mut buf = [0u8; 4 * 8];
+                for (chunk, v) in buf.chunks_exact_mut(8).zip(self.bit_len.iter()) {
+                    chunk.copy_from_slice(&v.to_be_bytes());
+                }
+
+                let mut state = self.state;
+                buffer.digest_pad(0x80, &buf, |block| {
+                    $compress(&mut state, convert(core::slice::from_ref(block)));
+                });
+
+                for (chunk, v) in out.chunks_exact_mut(8).zip(state.iter()) {
+                    chunk.copy_from_slice(&v.to_le_bytes());
+                }
+            }
+        }
 
-        for (chunk, v) in out.chunks_exact_mut(8).zip(state.iter()) {
-            chunk.copy_from_slice(&v.to_le_bytes());
+        impl $name {
+            fn update_len(&mut self, len: u64) {
+                let mut carry = 0;
+                adc(&mut self.bit_len[3], len, &mut carry);
+                adc(&mut self.bit_len[2], 0, &mut carry);
+                adc(&mut self.bit_len[1], 0, &mut carry);
+                adc(&mut self.bit_len[0], 0, &mut carry);
+            }
         }
-    }
-}
 
-impl WhirlpoolCore {
-    fn update_len(&mut self, len: u64) {
-        let mut carry = 0;
-        adc(&mut self.bit_len[3], len, &mut carry);
-        adc(&mut self.bit_len[2], 0, &mut carry);
-        adc(&mut self.bit_len[1], 0, &mut carry);
-        adc(&mut self.bit_len[0], 0, &mut carry);
-    }
-}
+        // derivable impl does not inline
+        #[allow(clippy::derivable_impls)]
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self {
+                    bit_len: Default::default(),
+                    state: [0u64; 8],
+                }
+            }
+        }
 
-// derivable impl does not inline
-#[allow(clippy::derivable_impls)]
-impl Default for WhirlpoolCore {
-    #[inline]
-    fn default() -> Self {
-        Self {
-            bit_len: Default::default(),
-            state: [0u64; 8],
+        impl Reset for $name {
+            #[inline]
+            fn reset(&mut self) {
+                *self = Default::default();
+            }
         }
-    }
-}
 
-impl Reset for WhirlpoolCore {
-    #[inline]
-    fn reset(&mut self) {
-        *self = Default::default();
-    }
-}
+        impl AlgorithmName for $name {
+            fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str($alg_name)
+            }
+        }
 
-impl AlgorithmName for WhirlpoolCore {
-    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("Whirlpool")
-    }
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), " { ... }"))
+            }
+        }
+    };
 }
 
-impl fmt::Debug for WhirlpoolCore {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("WhirlpoolCore { ... }")
-    }
-}
+impl_core!(
+    WhirlpoolCore,
+    compress,
+    "Whirlpool",
+    "Core Whirlpool hasher state."
+);
+impl_core!(
+    WhirlpoolTCore,
+    compress_t,
+    "WhirlpoolT",
+    "Core Whirlpool-T hasher state."
+);
+impl_core!(
+    Whirlpool0Core,
+    compress_0,
+    "Whirlpool0",
+    "Core Whirlpool-0 hasher state."
+);
 
 /// Whirlpool hasher state.
 pub type Whirlpool = CoreWrapper<WhirlpoolCore>;
+/// Whirlpool-T hasher state.
+pub type WhirlpoolT = CoreWrapper<WhirlpoolTCore>;
+/// Whirlpool-0 hasher state.
+pub type Whirlpool0 = CoreWrapper<Whirlpool0Core>;
 
 #[inline(always)]
 fn adc(a: &mut u64, b: u64, carry: &mut u64) {
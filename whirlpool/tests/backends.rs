@@ -0,0 +1,58 @@
+//! Equivalence tests of the compression function backends against the
+//! table-based implementation.
+#![cfg(any(
+    feature = "constant-time",
+    all(feature = "asm", target_arch = "x86_64")
+))]
+
+use whirlpool::backends;
+
+fn rand_u64(seed: &mut u64) -> u64 {
+    // xorshift64
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed
+}
+
+type Compress = fn(&mut [u64; 8], &[u8; 64]);
+
+fn check(f: Compress, table: Compress) {
+    let mut seed = 0x0123_4567_89ab_cdef;
+    let mut state = [0u64; 8];
+    for v in state.iter_mut() {
+        *v = rand_u64(&mut seed);
+    }
+    let mut expected = state;
+    for _ in 0..64 {
+        let mut block = [0u8; 64];
+        for b in block.iter_mut() {
+            *b = rand_u64(&mut seed) as u8;
+        }
+        table(&mut expected, &block);
+        f(&mut state, &block);
+        assert_eq!(state, expected);
+    }
+}
+
+#[cfg(all(
+    feature = "asm",
+    target_arch = "x86_64",
+    not(feature = "constant-time")
+))]
+#[test]
+fn asm_matches_table() {
+    check(backends::asm, backends::table);
+}
+
+#[cfg(feature = "constant-time")]
+#[test]
+fn ct_matches_table() {
+    check(backends::ct, backends::table);
+}
+
+#[cfg(feature = "constant-time")]
+#[test]
+fn ct_t_matches_table_t() {
+    check(backends::ct_t, backends::table_t);
+}
@@ -1,6 +1,6 @@
 use digest::dev::{feed_rand_16mib, fixed_reset_test};
 use hex_literal::hex;
-use whirlpool::{Digest, Whirlpool};
+use whirlpool::{Digest, Whirlpool, Whirlpool0, WhirlpoolT};
 
 digest::new_test!(whirlpool_main, "whirlpool", Whirlpool, fixed_reset_test);
 
@@ -17,3 +17,42 @@ fn whirlpool_rand() {
         ")[..]
     );
 }
+
+// NESSIE test vectors
+#[test]
+#[rustfmt::skip]
+fn whirlpool_t() {
+    assert_eq!(
+        WhirlpoolT::digest(b"")[..],
+        hex!("
+            470f0409abaa446e49667d4ebe12a14387cedbd10dd17b8243cad550a089dc0f
+            eea7aa40f6c2aaab71c6ebd076e43c7cfca0ad32567897dcb5969861049a0f5a
+        ")[..]
+    );
+    assert_eq!(
+        WhirlpoolT::digest(b"The quick brown fox jumps over the lazy dog")[..],
+        hex!("
+            3ccf8252d8bbb258460d9aa999c06ee38e67cb546cffcf48e91f700f6fc7c183
+            ac8cc3d3096dd30a35b01f4620a1e3a20d79cd5168544d9e1b7cdf49970e87f1
+        ")[..]
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn whirlpool_0() {
+    assert_eq!(
+        Whirlpool0::digest(b"")[..],
+        hex!("
+            b3e1ab6eaf640a34f784593f2074416accd3b8e62c620175fca0997b1ba23473
+            39aa0d79e754c308209ea36811dfa40c1c32f1a2b9004725d987d3635165d3c8
+        ")[..]
+    );
+    assert_eq!(
+        Whirlpool0::digest(b"The quick brown fox jumps over the lazy dog")[..],
+        hex!("
+            4f8f5cb531e3d49a61cf417cd133792ccfa501fd8da53ee368fed20e5fe0248c
+            3a0b64f98a6533cee1da614c3a8ddec791ff05fee6d971d57c1348320f4eb42d
+        ")[..]
+    );
+}